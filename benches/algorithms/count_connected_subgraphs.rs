@@ -41,4 +41,17 @@ pub fn count_connected_subgraphs(c: &mut Criterion) {
         }
         group.finish();
     }
+    let mut group = c.benchmark_group("count_connected_components_union_find");
+    for file in &files {
+        let file_name = std::path::Path::new(file)
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+
+        group.bench_function(file_name, |b| {
+            let graph = create_test_graph(file);
+            b.iter(|| black_box(&graph).count_connected_components_uf());
+        });
+    }
+    group.finish();
 }
@@ -0,0 +1,74 @@
+use criterion::Criterion;
+use graph_library::{
+    graph::{GraphBase, MatrixGraph},
+    Undirected,
+};
+use std::hint::black_box;
+
+use crate::{TestEdge, TestVertex};
+
+/// Create a matrix graph from a file for benchmarking purposes
+fn create_test_graph(file: &str) -> MatrixGraph<TestVertex, TestEdge, Undirected> {
+    MatrixGraph::<_, _, Undirected>::from_hoever_file(file, TestVertex, |remaining| {
+        TestEdge(
+            remaining[0]
+                .parse()
+                .expect("Graph file value must be a float"),
+        )
+    })
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e))
+}
+
+/// Counts the triangles by checking every triple of vertices, so the edge lookups dominate the runtime
+fn count_triangles_naive(vertex_count: usize, edge_exists: impl Fn(usize, usize) -> bool) -> usize {
+    let mut count = 0;
+    for u in 0..vertex_count {
+        for v in u + 1..vertex_count {
+            if !edge_exists(u, v) {
+                continue;
+            }
+            for w in v + 1..vertex_count {
+                if edge_exists(u, w) && edge_exists(v, w) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+pub fn count_triangles(c: &mut Criterion) {
+    // Complete graphs are the densest inputs for the edge lookups
+    let files = [
+        "resources/test_graphs/complete_undirected_weighted/K_50.txt",
+        "resources/test_graphs/complete_undirected_weighted/K_100.txt",
+    ];
+
+    let mut group = c.benchmark_group("count_triangles_matrix");
+    for file in &files {
+        let file_name = std::path::Path::new(file)
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+        let graph = create_test_graph(file);
+
+        group.bench_function(format!("{}/count_triangles", file_name), |b| {
+            b.iter(|| black_box(&graph).count_triangles());
+        });
+        group.bench_function(format!("{}/has_edge", file_name), |b| {
+            b.iter(|| {
+                count_triangles_naive(graph.vertex_count(), |from, to| {
+                    black_box(&graph).has_edge(from, to)
+                })
+            });
+        });
+        group.bench_function(format!("{}/get_edge_is_some", file_name), |b| {
+            b.iter(|| {
+                count_triangles_naive(graph.vertex_count(), |from, to| {
+                    black_box(&graph).get_edge(from, to).is_some()
+                })
+            });
+        });
+    }
+    group.finish();
+}
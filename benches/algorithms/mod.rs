@@ -1,4 +1,5 @@
 pub mod count_connected_subgraphs;
+pub mod count_triangles;
 pub mod maximum_flow;
 pub mod mst;
 pub mod shortest_path;
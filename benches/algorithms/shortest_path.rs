@@ -2,7 +2,7 @@ use std::hint::black_box;
 
 use criterion::Criterion;
 use graph_library::{
-    graph::{EdgeWithWeight, IntoDirected, ListGraph, Vertex},
+    graph::{EdgeWithWeight, GraphBase, IntoDirected, ListGraph, Vertex},
     Directed, Undirected,
 };
 
@@ -18,8 +18,8 @@ fn create_directed_graph(file: &str) -> ListGraph<Vertex, EdgeWithWeight, Direct
     .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e))
 }
 
-/// Create an undirected graph from a file and convert to directed in order to run the algorithm
-fn create_undirected_graph_as_directed(file: &str) -> ListGraph<Vertex, EdgeWithWeight, Directed> {
+/// Create an undirected graph from a file for benchmarking purposes
+fn create_undirected_graph(file: &str) -> ListGraph<Vertex, EdgeWithWeight, Undirected> {
     ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(file, |remaining| {
         EdgeWithWeight::new(
             remaining[0]
@@ -28,7 +28,24 @@ fn create_undirected_graph_as_directed(file: &str) -> ListGraph<Vertex, EdgeWith
         )
     })
     .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e))
-    .into_directed()
+}
+
+/// Create an undirected graph from a file and convert to directed in order to run the algorithm
+fn create_undirected_graph_as_directed(file: &str) -> ListGraph<Vertex, EdgeWithWeight, Directed> {
+    create_undirected_graph(file).into_directed()
+}
+
+/// Create a DAG from an undirected graph file by orienting every edge from the lower to the higher vertex ID
+fn create_dag(file: &str) -> ListGraph<Vertex, EdgeWithWeight, Directed> {
+    let graph = create_undirected_graph(file);
+    ListGraph::from_vertices_and_edges(
+        graph.get_all_vertices().cloned().collect(),
+        graph
+            .get_all_edges()
+            .map(|(from, to, edge)| (from.min(to), from.max(to), edge.clone()))
+            .collect(),
+    )
+    .unwrap_or_else(|e| panic!("DAG could not be constructed from file: {:?}", e))
 }
 
 pub fn shortest_path(c: &mut Criterion) {
@@ -111,4 +128,67 @@ pub fn shortest_path(c: &mut Criterion) {
         }
         group.finish();
     }
+
+    // DAG shortest paths (topological order) vs. Dijkstra on the same DAG.
+    // The topological sort covers the whole graph, while Dijkstra only visits the vertices reachable from 0
+    {
+        let mut group = c.benchmark_group("shortest_path_dag");
+        for file in &undirected_positive_files {
+            let file_name = std::path::Path::new(file)
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            let graph = create_dag(file);
+            group.bench_function(format!("{file_name} (dag shortest paths)"), |b| {
+                b.iter(|| {
+                    black_box(graph.dag_shortest_paths(black_box(0)).unwrap());
+                });
+            });
+            group.bench_function(format!("{file_name} (dijkstra)"), |b| {
+                b.iter(|| {
+                    black_box(graph.dijkstra(black_box(0), None));
+                });
+            });
+        }
+        group.finish();
+    }
+
+    // Repeated point-to-point queries: plain Dijkstra (with early abort) vs. ALT
+    {
+        let file = "resources/test_graphs/undirected_weighted/G_100_200.txt";
+        let queries = [(0, 99_999), (12_345, 67_890), (42, 4_242), (50_000, 99)];
+        let graph = create_undirected_graph(file);
+
+        let mut group = c.benchmark_group("shortest_path_queries");
+        group.bench_function("G_100_200.txt (dijkstra)", |b| {
+            b.iter(|| {
+                for (start, goal) in queries {
+                    black_box(graph.dijkstra(black_box(start), Some(goal)));
+                }
+            });
+        });
+        for n_landmarks in [4, 16] {
+            let alt_index = graph.alt_index(n_landmarks);
+            group.bench_function(
+                format!("G_100_200.txt (alt, {n_landmarks} landmarks)"),
+                |b| {
+                    b.iter(|| {
+                        for (start, goal) in queries {
+                            black_box(alt_index.shortest_path(black_box(start), goal));
+                        }
+                    });
+                },
+            );
+        }
+        // G_100_200 is a random graph without a road-network-like hierarchy, so a large part ends up in the core
+        let hierarchy = graph.contraction_hierarchies();
+        group.bench_function("G_100_200.txt (contraction hierarchies)", |b| {
+            b.iter(|| {
+                for (start, goal) in queries {
+                    black_box(hierarchy.ch_query(black_box(start), goal));
+                }
+            });
+        });
+        group.finish();
+    }
 }
@@ -115,6 +115,27 @@ pub fn tsp(c: &mut Criterion) {
         group.finish();
     }
 
+    // Branch & Bound with the 1-tree lower bound, on the same instances as above
+    {
+        let mut group = c.benchmark_group("tsp_branch_and_bound_one_tree");
+        for file in files[0..7].iter() {
+            let file_name = std::path::Path::new(file)
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+
+            group.bench_function(file_name, |b| {
+                let graph = create_test_graph(file);
+                b.iter(|| {
+                    graph
+                        .tsp_branch_and_bound_one_tree(black_box(None))
+                        .unwrap_or_else(|e| panic!("Could not compute TSP: {:?}", e));
+                });
+            });
+        }
+        group.finish();
+    }
+
     // Nearest Neighbor benchmarks (heuristic algorithm on larger instances)
     {
         let mut group = c.benchmark_group("tsp_nearest_neighbor");
@@ -1,5 +1,8 @@
 use criterion::{BenchmarkId, Criterion};
-use graph_library::{graph::MatrixGraph, ListGraph, Undirected};
+use graph_library::{
+    graph::{BitMatrixGraph, MatrixGraph},
+    ListGraph, Undirected,
+};
 use std::{hint::black_box, path::Path};
 
 use crate::{TestEdge, TestVertex};
@@ -64,5 +67,53 @@ pub fn graph_creation(c: &mut Criterion) {
         });
     }
 
+    // Benchmark the unweighted matrix backends against each other
+    let unweighted_graph_files = [
+        "resources/test_graphs/undirected/Graph1.txt",
+        "resources/test_graphs/undirected/Graph2.txt",
+        "resources/test_graphs/undirected/Graph3.txt",
+    ];
+
+    for file in unweighted_graph_files {
+        let file_name = Path::new(file)
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy();
+
+        // Criterion only measures time, so the matrix sizes are part of the benchmark IDs
+        let matrix_size =
+            MatrixGraph::<TestVertex, (), Undirected>::from_hoever_file(file, TestVertex, |_| ())
+                .unwrap_or_else(|e| panic!("Failed to create graph: {:?}", e))
+                .matrix_size_in_bytes();
+        let bit_matrix_size =
+            BitMatrixGraph::<TestVertex, Undirected>::from_hoever_file(file, TestVertex, |_| ())
+                .unwrap_or_else(|e| panic!("Failed to create graph: {:?}", e))
+                .matrix_size_in_bytes();
+
+        let matrix_id = format!("{file_name} ({matrix_size} bytes)");
+        group.bench_function(BenchmarkId::new("unit_matrix_graph", matrix_id), |b| {
+            b.iter(|| {
+                MatrixGraph::<TestVertex, (), Undirected>::from_hoever_file(
+                    black_box(file),
+                    TestVertex,
+                    |_| (),
+                )
+                .unwrap_or_else(|e| panic!("Failed to create graph: {:?}", e))
+            });
+        });
+
+        let bit_matrix_id = format!("{file_name} ({bit_matrix_size} bytes)");
+        group.bench_function(BenchmarkId::new("bit_matrix_graph", bit_matrix_id), |b| {
+            b.iter(|| {
+                BitMatrixGraph::<TestVertex, Undirected>::from_hoever_file(
+                    black_box(file),
+                    TestVertex,
+                    |_| (),
+                )
+                .unwrap_or_else(|e| panic!("Failed to create graph: {:?}", e))
+            });
+        });
+    }
+
     group.finish();
 }
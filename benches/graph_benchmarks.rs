@@ -21,6 +21,7 @@ criterion_group!(
     targets =
         creation::graph_creation,
         count_connected_subgraphs::count_connected_subgraphs,
+        count_triangles::count_triangles,
         mst::mst,
         tsp::tsp,
         shortest_path::shortest_path,
@@ -0,0 +1,242 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, GraphError,
+};
+
+/// Iterator over all simple (loopless) paths between two vertices.
+///
+/// Created by [`Graph::all_simple_paths`].
+pub struct AllSimplePathsIter<'a, Backend>
+where
+    Backend: GraphBase,
+{
+    graph: &'a Graph<Backend>,
+    goal: <Backend::Vertex as WithID>::IDType,
+    max_len: Option<usize>,
+    // The path that is currently explored (starting at the start vertex)
+    path: Vec<<Backend::Vertex as WithID>::IDType>,
+    on_path: FxHashSet<<Backend::Vertex as WithID>::IDType>,
+    // For each vertex on the path, the neighbors that still have to be explored
+    stack: Vec<Vec<<Backend::Vertex as WithID>::IDType>>,
+}
+
+impl<'a, Backend> AllSimplePathsIter<'a, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    fn new(
+        graph: &'a Graph<Backend>,
+        start: <Backend::Vertex as WithID>::IDType,
+        goal: <Backend::Vertex as WithID>::IDType,
+        max_len: Option<usize>,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        graph
+            .get_vertex_by_id(start)
+            .ok_or(GraphError::VertexNotFound(start))?;
+        graph
+            .get_vertex_by_id(goal)
+            .ok_or(GraphError::VertexNotFound(goal))?;
+
+        let mut iter = AllSimplePathsIter {
+            graph,
+            goal,
+            max_len,
+            path: vec![],
+            on_path: FxHashSet::default(),
+            stack: vec![],
+        };
+        iter.enter(start);
+
+        Ok(iter)
+    }
+
+    /// Adds `vertex` to the current path and remembers its neighbors for exploration
+    fn enter(&mut self, vertex: <Backend::Vertex as WithID>::IDType) {
+        let mut neighbors = self
+            .graph
+            .get_adjacent_vertices(vertex)
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        // Neighbors are popped from the back, so reverse them to explore them in adjacency order
+        neighbors.reverse();
+
+        self.path.push(vertex);
+        self.on_path.insert(vertex);
+        self.stack.push(neighbors);
+    }
+}
+
+impl<Backend> Iterator for AllSimplePathsIter<'_, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    type Item = Vec<<Backend::Vertex as WithID>::IDType>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(neighbors) = self.stack.last_mut() {
+            let Some(next) = neighbors.pop() else {
+                // All neighbors have been explored -> backtrack
+                self.stack.pop();
+                if let Some(vertex) = self.path.pop() {
+                    self.on_path.remove(&vertex);
+                }
+                continue;
+            };
+
+            // Skip vertices that would close a loop
+            if self.on_path.contains(&next) {
+                continue;
+            }
+
+            // The current path has `path.len() - 1` edges, so going to `next` adds one more
+            if next == self.goal {
+                if self
+                    .max_len
+                    .is_none_or(|max_len| self.path.len() <= max_len)
+                {
+                    let mut path = self.path.clone();
+                    path.push(next);
+                    return Some(path);
+                }
+                continue;
+            }
+
+            // Only go deeper if the goal can still be reached within `max_len` edges
+            if self.max_len.is_none_or(|max_len| self.path.len() < max_len) {
+                self.enter(next);
+            }
+        }
+
+        None
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    /// Creates an iterator over all simple paths (paths without repeated vertices) from `start` to `goal`.
+    ///
+    /// Each path is yielded as the sequence of vertex IDs, beginning with `start` and ending with `goal`.
+    /// If `max_len` is given, only paths with at most `max_len` edges are yielded.
+    /// If `start == goal`, no paths are yielded.
+    ///
+    /// The paths are enumerated lazily using a DFS with backtracking.
+    ///
+    /// # Warning
+    /// The number of simple paths can grow exponentially with the size of the graph
+    /// (e.g. in complete graphs), so consider bounding the search with `max_len`.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: when either `start` or `goal` does not exist
+    pub fn all_simple_paths(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        goal: <Backend::Vertex as WithID>::IDType,
+        max_len: Option<usize>,
+    ) -> Result<AllSimplePathsIter<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    {
+        AllSimplePathsIter::new(self, start, goal, max_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::GraphBase, test_utils::MockVertex, Directed, GraphError, ListGraph, Undirected,
+    };
+
+    fn create_test_graph() -> ListGraph<MockVertex, (), Undirected> {
+        // 0 - 1 - 3 - 4
+        //  \     /
+        //   - 2 -
+        ListGraph::from_vertices_and_edges(
+            (0..5).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 3, ()), (0, 2, ()), (2, 3, ()), (3, 4, ())],
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_all_simple_paths() {
+        let graph = create_test_graph();
+
+        let mut paths = graph
+            .all_simple_paths(0, 4, None)
+            .unwrap()
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        assert_eq!(paths, vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+    }
+
+    #[rstest]
+    #[case(Some(1), vec![])]
+    #[case(Some(2), vec![vec![0, 1, 3]])]
+    #[case(Some(3), vec![vec![0, 1, 3], vec![0, 2, 5, 3]])]
+    #[case(None, vec![vec![0, 1, 3], vec![0, 2, 5, 3]])]
+    fn test_all_simple_paths_max_len(
+        #[case] max_len: Option<usize>,
+        #[case] expected: Vec<Vec<usize>>,
+    ) {
+        // 0 - 1 - 3
+        //  \     /
+        //   2 - 5
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..6).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 3, ()), (0, 2, ()), (2, 5, ()), (5, 3, ())],
+        )
+        .unwrap();
+
+        let mut paths = graph
+            .all_simple_paths(0, 3, max_len)
+            .unwrap()
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        assert_eq!(paths, expected);
+    }
+
+    #[rstest]
+    fn test_all_simple_paths_respects_direction() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 0, ())],
+        )
+        .unwrap();
+
+        assert_eq!(
+            graph
+                .all_simple_paths(0, 2, None)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            vec![vec![0, 1, 2]]
+        );
+        assert_eq!(
+            graph
+                .all_simple_paths(2, 1, None)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            vec![vec![2, 0, 1]]
+        );
+    }
+
+    #[rstest]
+    fn test_all_simple_paths_invalid_vertex() {
+        let graph = create_test_graph();
+
+        assert!(matches!(
+            graph.all_simple_paths(0, 42, None),
+            Err(GraphError::VertexNotFound(42))
+        ));
+    }
+}
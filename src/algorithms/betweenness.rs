@@ -0,0 +1,157 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Direction, Graph,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Computes the betweenness of every edge: the sum over all pairs of vertices `(s, t)` of the fraction
+    /// of shortest `s`-`t` paths (by hop count) that use the edge.
+    ///
+    /// Uses Brandes' algorithm with the dependencies accumulated on the edges (O(n * m) time).
+    /// For undirected graphs, every edge is keyed as `(smaller ID, larger ID)` and the values are halved,
+    /// since each unordered pair of vertices is otherwise counted from both ends.
+    /// For directed graphs, the keys are `(from, to)`.
+    #[allow(clippy::type_complexity)]
+    pub fn edge_betweenness(
+        &self,
+    ) -> FxHashMap<
+        (
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        ),
+        f64,
+    > {
+        let edge_key = |from, to| {
+            if Backend::Direction::IS_DIRECTED {
+                (from, to)
+            } else {
+                (Ord::min(from, to), Ord::max(from, to))
+            }
+        };
+
+        let mut betweenness = self
+            .get_all_edges()
+            .map(|(from, to, _)| (edge_key(from, to), 0.0))
+            .collect::<FxHashMap<_, _>>();
+
+        for start in self.get_all_vertices().map(|v| v.get_id()) {
+            // BFS counting the shortest paths to each vertex
+            let mut order = Vec::new();
+            let mut predecessors = FxHashMap::<_, Vec<_>>::default();
+            let mut path_counts = FxHashMap::default();
+            let mut distances = FxHashMap::default();
+            path_counts.insert(start, 1.0);
+            distances.insert(start, 0usize);
+            let mut queue = VecDeque::from([start]);
+
+            while let Some(current) = queue.pop_front() {
+                order.push(current);
+                let distance = distances[&current];
+                let current_count: f64 = path_counts[&current];
+                for neighbor in self.get_adjacent_vertices(current).map(|v| v.get_id()) {
+                    let neighbor_distance = *distances.entry(neighbor).or_insert_with(|| {
+                        queue.push_back(neighbor);
+                        distance + 1
+                    });
+                    if neighbor_distance == distance + 1 {
+                        *path_counts.entry(neighbor).or_insert(0.0) += current_count;
+                        predecessors.entry(neighbor).or_default().push(current);
+                    }
+                }
+            }
+
+            // Accumulate the dependencies in order of decreasing distance
+            let mut dependencies = FxHashMap::<_, f64>::default();
+            for target in order.into_iter().rev() {
+                let Some(target_predecessors) = predecessors.get(&target) else {
+                    continue;
+                };
+                let target_dependency = dependencies.get(&target).copied().unwrap_or(0.0);
+                for &predecessor in target_predecessors {
+                    let share = path_counts[&predecessor] / path_counts[&target]
+                        * (1.0 + target_dependency);
+                    *betweenness
+                        .get_mut(&edge_key(predecessor, target))
+                        .expect("Shortest paths only use existing edges") += share;
+                    *dependencies.entry(predecessor).or_default() += share;
+                }
+            }
+        }
+
+        if !Backend::Direction::IS_DIRECTED {
+            betweenness.values_mut().for_each(|value| *value /= 2.0);
+        }
+
+        betweenness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, ListGraph, Undirected,
+    };
+
+    #[rstest]
+    fn test_edge_betweenness_barbell(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        // Two triangles {0, 1, 2} and {3, 4, 5} joined by the bridge 2 - 3
+        for id in 0..6 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let betweenness = graph.edge_betweenness();
+
+        assert_eq!(betweenness.len(), 7);
+        // All 3 * 3 pairs between the triangles cross the bridge
+        assert_eq!(betweenness[&(2, 3)], 9.0);
+        // 0 - 1 is only used by the pair (0, 1) itself
+        assert_eq!(betweenness[&(0, 1)], 1.0);
+        // 0 - 2 is used by (0, 2) and the paths from 0 to the other triangle
+        assert_eq!(betweenness[&(0, 2)], 4.0);
+        let (max_edge, _) = betweenness
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap();
+        assert_eq!(*max_edge, (2, 3));
+    }
+
+    #[test]
+    fn test_edge_betweenness_directed_splits_between_shortest_paths() {
+        // Two shortest paths from 0 to 3
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (0, 2, ()), (1, 3, ()), (2, 3, ())],
+        )
+        .unwrap();
+
+        let betweenness = graph.edge_betweenness();
+
+        assert_eq!(betweenness[&(0, 1)], 1.5);
+        assert_eq!(betweenness[&(0, 2)], 1.5);
+        assert_eq!(betweenness[&(1, 3)], 1.5);
+        assert_eq!(betweenness[&(2, 3)], 1.5);
+    }
+}
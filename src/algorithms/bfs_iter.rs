@@ -1,12 +1,16 @@
 use std::{collections::VecDeque, hash::Hash, marker::PhantomData};
 
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     graph::{GraphBase, WithID},
-    Graph, GraphError,
+    Directed, Graph, GraphError,
 };
 
+/// Iterator over the vertices of a graph in breadth-first order.
+///
+/// The iterator can be cloned to fork a traversal. Cloning duplicates the queue and the visited set,
+/// so both copies continue independently from the state at the time of cloning.
 pub struct BfsIter<'a, Backend>
 where
     Backend: GraphBase,
@@ -43,6 +47,20 @@ where
     }
 }
 
+impl<Backend> Clone for BfsIter<'_, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Clone,
+{
+    fn clone(&self) -> Self {
+        BfsIter {
+            graph: self.graph,
+            queue: self.queue.clone(),
+            visited: self.visited.clone(),
+        }
+    }
+}
+
 impl<'a, Backend> Iterator for BfsIter<'a, Backend>
 where
     Backend: GraphBase,
@@ -145,6 +163,92 @@ where
     }
 }
 
+/// Iterator over the vertices of a directed graph in breadth-first order, following the edges backwards.
+///
+/// Yields every vertex that can reach the start vertex, ordered by the length of their shortest path to it.
+pub struct ReverseBfsIter<'a, Backend>
+where
+    Backend: GraphBase,
+{
+    graph: &'a Graph<Backend>,
+    predecessors:
+        FxHashMap<<Backend::Vertex as WithID>::IDType, Vec<<Backend::Vertex as WithID>::IDType>>,
+    queue: VecDeque<<Backend::Vertex as WithID>::IDType>,
+    visited: FxHashSet<<Backend::Vertex as WithID>::IDType>,
+}
+
+impl<'a, Backend> ReverseBfsIter<'a, Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    fn new(
+        graph: &'a Graph<Backend>,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        graph
+            .get_vertex_by_id(start_vertex)
+            .ok_or(GraphError::VertexNotFound(start_vertex))?;
+
+        // The backends only store outgoing edges, so collect the incoming ones first
+        let mut predecessors = FxHashMap::<_, Vec<_>>::default();
+        for (from, to, _) in graph.get_all_edges() {
+            predecessors.entry(to).or_default().push(from);
+        }
+
+        Ok(ReverseBfsIter {
+            graph,
+            predecessors,
+            queue: VecDeque::from([start_vertex]),
+            visited: FxHashSet::from_iter([start_vertex]),
+        })
+    }
+}
+
+impl<'a, Backend> Iterator for ReverseBfsIter<'a, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    type Item = &'a Backend::Vertex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_id = self.queue.pop_front()?;
+
+        for &vid in self.predecessors.get(&next_id).into_iter().flatten() {
+            if self.visited.insert(vid) {
+                self.queue.push_back(vid);
+            }
+        }
+
+        Some(
+            self.graph.get_vertex_by_id(next_id).expect(
+                "get_vertex_by_id should not error as the vertices in the queue must exist",
+            ),
+        )
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    /// Traverses the graph breadth-first from `start_vertex` along the incoming edges,
+    /// i.e. visits all vertices that can reach `start_vertex`, starting with `start_vertex` itself.
+    ///
+    /// Collecting the incoming edges takes O(m) when the iterator is created.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: if `start_vertex` does not exist.
+    pub fn reverse_bfs_iter(
+        &self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<ReverseBfsIter<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        ReverseBfsIter::new(self, start_vertex)
+    }
+}
+
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
@@ -168,4 +272,103 @@ where
     {
         BfsIterMut::new(self, start_vertex)
     }
+
+    /// Returns all vertices that can be reached from `start` using at most `max_hops` edges (its ego network).
+    ///
+    /// `start` itself is only part of the result if `include_start` is `true`.
+    /// Returns an empty set if `start` does not exist.
+    pub fn neighbors_within(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        max_hops: usize,
+        include_start: bool,
+    ) -> FxHashSet<<Backend::Vertex as WithID>::IDType> {
+        let mut visited = FxHashSet::default();
+        if self.get_vertex_by_id(start).is_none() {
+            return visited;
+        }
+
+        visited.insert(start);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((current, hops)) = queue.pop_front() {
+            // Do not expand vertices at the boundary of the neighborhood
+            if hops == max_hops {
+                continue;
+            }
+            for neighbor in self.get_adjacent_vertices(current) {
+                if visited.insert(neighbor.get_id()) {
+                    queue.push_back((neighbor.get_id(), hops + 1));
+                }
+            }
+        }
+
+        if !include_start {
+            visited.remove(&start);
+        }
+        visited
+    }
+
+    /// Returns whether `to` can be reached from `from` (following the edge directions in directed graphs).
+    ///
+    /// Runs a BFS that stops as soon as `to` is found, without tracking distances or weights.
+    /// A vertex always reaches itself, so `from == to` is `true` if the vertex exists.
+    /// Returns `false` if one of the vertices does not exist.
+    pub fn has_path(
+        &self,
+        from: <Backend::Vertex as WithID>::IDType,
+        to: <Backend::Vertex as WithID>::IDType,
+    ) -> bool {
+        if self.get_vertex_by_id(from).is_none() || self.get_vertex_by_id(to).is_none() {
+            return false;
+        }
+        if from == to {
+            return true;
+        }
+
+        let mut visited = FxHashSet::from_iter([from]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(current) = queue.pop_front() {
+            for neighbor in self.get_adjacent_vertices(current) {
+                let neighbor_id = neighbor.get_id();
+                if neighbor_id == to {
+                    return true;
+                }
+                if visited.insert(neighbor_id) {
+                    queue.push_back(neighbor_id);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Returns the hop distance of every reachable vertex to its closest source (multi-source BFS).
+    ///
+    /// All sources start at distance 0 in the same queue, so every vertex is reached first from its nearest source.
+    /// Sources that do not exist are ignored.
+    pub fn bfs_multi_source(
+        &self,
+        sources: &[<Backend::Vertex as WithID>::IDType],
+    ) -> FxHashMap<<Backend::Vertex as WithID>::IDType, usize> {
+        let mut distances = FxHashMap::default();
+        let mut queue = VecDeque::new();
+        for &source in sources {
+            if self.get_vertex_by_id(source).is_some() && !distances.contains_key(&source) {
+                distances.insert(source, 0);
+                queue.push_back(source);
+            }
+        }
+
+        while let Some(current) = queue.pop_front() {
+            let next_distance = distances[&current] + 1;
+            for neighbor in self.get_adjacent_vertices(current) {
+                distances.entry(neighbor.get_id()).or_insert_with(|| {
+                    queue.push_back(neighbor.get_id());
+                    next_distance
+                });
+            }
+        }
+
+        distances
+    }
 }
@@ -0,0 +1,140 @@
+use std::{
+    collections::{hash_map::Entry, VecDeque},
+    hash::Hash,
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Returns the edges that prevent a 2-coloring of the graph, i.e. the graph is bipartite if the result is empty.
+    ///
+    /// Every component is 2-colored by a BFS, afterwards all edges between vertices of the same color are collected.
+    /// Each of these edges closes an odd cycle with the BFS tree, so they witness why the graph is not bipartite.
+    /// Which edges are reported depends on the BFS, but removing all of them always leaves a bipartite graph.
+    ///
+    /// The edges are returned as `(smaller ID, larger ID)` in ascending order.
+    #[allow(clippy::type_complexity)]
+    pub fn bipartite_conflicts(
+        &self,
+    ) -> Vec<(
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Vertex as WithID>::IDType,
+    )> {
+        let mut vertices = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        vertices.sort_unstable();
+
+        let mut color = FxHashMap::default();
+        for start in vertices {
+            if color.contains_key(&start) {
+                continue;
+            }
+
+            color.insert(start, false);
+            let mut queue = VecDeque::from([start]);
+            while let Some(current) = queue.pop_front() {
+                let next_color = !color[&current];
+                for neighbor in self.get_adjacent_vertices(current) {
+                    if let Entry::Vacant(entry) = color.entry(neighbor.get_id()) {
+                        entry.insert(next_color);
+                        queue.push_back(neighbor.get_id());
+                    }
+                }
+            }
+        }
+
+        let mut conflicts = self
+            .get_all_edges()
+            .filter(|(from, to, _)| color[from] == color[to])
+            .map(|(from, to, _)| (from.min(to), from.max(to)))
+            .collect::<Vec<_>>();
+        conflicts.sort_unstable();
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Graph, ListGraph, Undirected,
+    };
+
+    fn cycle_edges(n_vertices: usize) -> Vec<(usize, usize)> {
+        (0..n_vertices)
+            .map(|id| (id, (id + 1) % n_vertices))
+            .collect()
+    }
+
+    #[rstest]
+    fn test_bipartite_conflicts(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+        #[values(3, 4, 5, 6)] n_vertices: usize,
+    ) {
+        for id in 0..n_vertices {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in cycle_edges(n_vertices) {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let conflicts = graph.bipartite_conflicts();
+
+        if n_vertices.is_multiple_of(2) {
+            assert!(conflicts.is_empty());
+        } else {
+            assert!(!conflicts.is_empty());
+            for (from, to) in &conflicts {
+                assert!(graph.get_edge(*from, *to).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_bipartite_conflicts_removal_leaves_bipartite_graph() {
+        // A triangle attached to a 5-cycle, plus an isolated vertex
+        let mut graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..9).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, ()),
+                (1, 2, ()),
+                (2, 0, ()),
+                (2, 3, ()),
+                (3, 4, ()),
+                (4, 5, ()),
+                (5, 6, ()),
+                (6, 7, ()),
+                (7, 3, ()),
+            ],
+        )
+        .unwrap();
+
+        let conflicts = graph.bipartite_conflicts();
+        assert_eq!(conflicts.len(), 2);
+
+        for (from, to) in conflicts {
+            graph.remove_edge(from, to);
+        }
+        assert!(graph.bipartite_conflicts().is_empty());
+    }
+}
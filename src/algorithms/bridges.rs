@@ -0,0 +1,523 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, GraphError, Undirected,
+};
+
+/// A node of the block-cut tree, see [`Graph::block_cut_tree`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BlockCutNode<VId> {
+    /// The block at this index in the order of [`Graph::biconnected_components`]
+    Block(usize),
+    /// An articulation point of the original graph
+    CutVertex(VId),
+}
+
+/// The results of a low-link DFS over all components
+struct LowLink<VId> {
+    bridges: Vec<(VId, VId)>,
+    articulation_points: Vec<VId>,
+    blocks: Vec<Vec<(VId, VId)>>,
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Splits the graph into its 2-edge-connected components and returns them together with the bridges between them.
+    ///
+    /// A bridge is an edge whose removal disconnects its endpoints. Removing all bridges leaves the
+    /// 2-edge-connected components, in which every pair of vertices is connected by two edge-disjoint paths.
+    /// Contracting each component into a single vertex turns the graph into a forest whose edges are the bridges,
+    /// so the two endpoints of every bridge always lie in different components.
+    ///
+    /// The bridges are found with Tarjan's low-link values in a single (iterative) DFS, so this runs in O(n + m).
+    /// The vertices of each component are sorted and the components are ordered by their smallest vertex ID.
+    /// Bridges are returned as `(smaller ID, larger ID)` in ascending order.
+    #[allow(clippy::type_complexity)]
+    pub fn two_edge_connected_components(
+        &self,
+    ) -> (
+        Vec<Vec<<Backend::Vertex as WithID>::IDType>>,
+        Vec<(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        )>,
+    ) {
+        let bridges = self.low_link().bridges;
+        let bridge_set = bridges.iter().copied().collect::<FxHashSet<_>>();
+
+        let mut vertices = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        vertices.sort_unstable();
+
+        // Every component is the set of vertices reachable without crossing a bridge
+        let mut visited = FxHashSet::default();
+        let mut components = Vec::new();
+        for start in vertices {
+            if !visited.insert(start) {
+                continue;
+            }
+
+            let mut component = vec![start];
+            let mut queue = VecDeque::from([start]);
+            while let Some(current) = queue.pop_front() {
+                for neighbor in self.get_adjacent_vertices(current) {
+                    let neighbor = neighbor.get_id();
+                    if !bridge_set.contains(&(current.min(neighbor), current.max(neighbor)))
+                        && visited.insert(neighbor)
+                    {
+                        component.push(neighbor);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        (components, bridges)
+    }
+
+    /// Returns the biconnected components (blocks) of the graph, each as its edges.
+    ///
+    /// A block is a maximal subgraph that stays connected after removing any single vertex.
+    /// Blocks only share articulation points (see [`Graph::articulation_points`]) and every edge belongs to exactly one block,
+    /// so a bridge forms a block of a single edge. Isolated vertices are not part of any block.
+    ///
+    /// The blocks are computed in the same DFS low-link pass as the articulation points and bridges (O(n + m)).
+    /// The edges of each block are returned as `(smaller ID, larger ID)` in ascending order,
+    /// the blocks are ordered by their smallest edge.
+    #[allow(clippy::type_complexity)]
+    pub fn biconnected_components(
+        &self,
+    ) -> Vec<
+        Vec<(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        )>,
+    > {
+        self.low_link().blocks
+    }
+
+    /// Returns the articulation points (cut vertices) in ascending order,
+    /// i.e. the vertices whose removal increases the number of connected components.
+    pub fn articulation_points(&self) -> Vec<<Backend::Vertex as WithID>::IDType> {
+        self.low_link().articulation_points
+    }
+
+    /// Builds the block-cut tree of this graph.
+    ///
+    /// The tree has one node per block (see [`Graph::biconnected_components`]) and one per articulation point,
+    /// each created by `vertex_builder`. Every articulation point is connected to all blocks containing it.
+    /// For a connected graph the result is a tree, otherwise it is a forest with one tree per component
+    /// (isolated vertices are neither blocks nor articulation points and do not appear).
+    ///
+    /// The block nodes are inserted first in block order, followed by the articulation points in ascending order.
+    ///
+    /// # Errors
+    /// Returns the errors of building the output graph,
+    /// e.g. `GraphError::DuplicateVertex` if `vertex_builder` creates the same ID for two nodes.
+    #[allow(clippy::type_complexity)]
+    pub fn block_cut_tree<F, OutputBackend>(
+        &self,
+        vertex_builder: F,
+    ) -> Result<Graph<OutputBackend>, GraphError<<OutputBackend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(BlockCutNode<<Backend::Vertex as WithID>::IDType>) -> OutputBackend::Vertex,
+        OutputBackend: GraphBase<Edge = (), Direction = Undirected>,
+        <OutputBackend::Vertex as WithID>::IDType: Copy,
+    {
+        let LowLink {
+            articulation_points,
+            blocks,
+            ..
+        } = self.low_link();
+
+        let mut tree = Graph::<OutputBackend>::new();
+        let mut block_ids = Vec::with_capacity(blocks.len());
+        for index in 0..blocks.len() {
+            let vertex = vertex_builder(BlockCutNode::Block(index));
+            block_ids.push(vertex.get_id());
+            tree.push_vertex(vertex)?;
+        }
+        let mut cut_vertex_ids = FxHashMap::default();
+        for cut_vertex in articulation_points {
+            let vertex = vertex_builder(BlockCutNode::CutVertex(cut_vertex));
+            cut_vertex_ids.insert(cut_vertex, vertex.get_id());
+            tree.push_vertex(vertex)?;
+        }
+
+        for (block, block_id) in blocks.iter().zip(block_ids) {
+            let mut block_vertices = block
+                .iter()
+                .flat_map(|&(from, to)| [from, to])
+                .collect::<Vec<_>>();
+            block_vertices.sort_unstable();
+            block_vertices.dedup();
+
+            for vertex in block_vertices {
+                if let Some(&cut_vertex_id) = cut_vertex_ids.get(&vertex) {
+                    tree.push_edge(cut_vertex_id, block_id, ())?;
+                }
+            }
+        }
+
+        Ok(tree)
+    }
+
+    /// Runs Tarjan's low-link DFS (iteratively) and collects the bridges, articulation points and blocks
+    fn low_link(&self) -> LowLink<<Backend::Vertex as WithID>::IDType> {
+        let mut discovery = FxHashMap::default();
+        let mut low = FxHashMap::default();
+        let mut result = LowLink {
+            bridges: Vec::new(),
+            articulation_points: Vec::new(),
+            blocks: Vec::new(),
+        };
+        // The edges of the blocks that are not completed yet
+        let mut edge_stack = Vec::new();
+
+        for root in self.get_all_vertices().map(|v| v.get_id()) {
+            if discovery.contains_key(&root) {
+                continue;
+            }
+
+            discovery.insert(root, discovery.len());
+            low.insert(root, discovery[&root]);
+            let mut root_children = 0;
+            // The DFS stack holds (vertex, parent, unexplored neighbors)
+            let mut stack = vec![(root, None, self.neighbor_ids(root))];
+            let mut articulation_points = FxHashSet::default();
+
+            while let Some((current, parent, neighbors)) = stack.last_mut() {
+                let (current, parent) = (*current, *parent);
+                if let Some(neighbor) = neighbors.pop() {
+                    if Some(neighbor) == parent {
+                        // The tree edge back to the parent is no back edge (the backends store no parallel edges)
+                        continue;
+                    }
+                    if let Some(&neighbor_discovery) = discovery.get(&neighbor) {
+                        // Back edges are seen from both ends, only the one to the ancestor is new
+                        if neighbor_discovery < discovery[&current] {
+                            edge_stack.push((current, neighbor));
+                            let current_low = low.get_mut(&current).expect("Visited vertex");
+                            *current_low = (*current_low).min(neighbor_discovery);
+                        }
+                    } else {
+                        discovery.insert(neighbor, discovery.len());
+                        low.insert(neighbor, discovery[&neighbor]);
+                        edge_stack.push((current, neighbor));
+                        if current == root {
+                            root_children += 1;
+                        }
+                        stack.push((neighbor, Some(current), self.neighbor_ids(neighbor)));
+                    }
+                    continue;
+                }
+
+                // All neighbors are explored, so the low-link of `current` is final
+                stack.pop();
+                let Some(parent) = parent else {
+                    continue;
+                };
+                let current_low = low[&current];
+                let parent_low = low.get_mut(&parent).expect("Visited vertex");
+                *parent_low = (*parent_low).min(current_low);
+
+                // No vertex below `current` reaches above `parent`, so everything above the tree edge forms a block
+                if current_low >= discovery[&parent] {
+                    let mut block = Vec::new();
+                    while let Some((from, to)) = edge_stack.pop() {
+                        block.push((from.min(to), from.max(to)));
+                        if (from, to) == (parent, current) {
+                            break;
+                        }
+                    }
+                    block.sort_unstable();
+                    result.blocks.push(block);
+
+                    // The root is only a cut vertex if it has several DFS children, checked below
+                    if parent != root {
+                        articulation_points.insert(parent);
+                    }
+                }
+                // No vertex below `current` reaches `parent` or above, so the tree edge is the only connection
+                if current_low > discovery[&parent] {
+                    result
+                        .bridges
+                        .push((parent.min(current), parent.max(current)));
+                }
+            }
+
+            if root_children > 1 {
+                articulation_points.insert(root);
+            }
+            result.articulation_points.extend(articulation_points);
+        }
+
+        result.bridges.sort_unstable();
+        result.articulation_points.sort_unstable();
+        result.blocks.sort_unstable();
+        result
+    }
+
+    fn neighbor_ids(
+        &self,
+        vertex_id: <Backend::Vertex as WithID>::IDType,
+    ) -> Vec<<Backend::Vertex as WithID>::IDType> {
+        self.get_adjacent_vertices(vertex_id)
+            .map(|v| v.get_id())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, ListGraphBackend, MatrixGraph, WithID},
+        test_utils::MockVertex,
+        Graph, ListGraph, Undirected,
+    };
+
+    use super::BlockCutNode;
+
+    #[rstest]
+    fn test_two_triangles_joined_by_bridge(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..6 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let (components, bridges) = graph.two_edge_connected_components();
+
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+        assert_eq!(bridges, vec![(2, 3)]);
+    }
+
+    #[test]
+    fn test_two_edge_connected_components_of_tree_and_cycle() {
+        // The path 0 - 1 - 2 hangs off the cycle 2 - 3 - 4, vertex 5 is isolated
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..6).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 3, ()), (3, 4, ()), (4, 2, ())],
+        )
+        .unwrap();
+
+        let (components, bridges) = graph.two_edge_connected_components();
+
+        assert_eq!(components, vec![vec![0], vec![1], vec![2, 3, 4], vec![5]]);
+        assert_eq!(bridges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[rstest]
+    fn test_biconnected_components_of_bowtie(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        // The triangles {0, 1, 2} and {2, 3, 4} share the articulation point 2
+        for id in 0..5 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let blocks = graph.biconnected_components();
+
+        assert_eq!(
+            blocks,
+            vec![vec![(0, 1), (0, 2), (1, 2)], vec![(2, 3), (2, 4), (3, 4)]]
+        );
+        assert_eq!(graph.articulation_points(), vec![2]);
+        assert!(graph.two_edge_connected_components().1.is_empty());
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct TreeVertex {
+        node: BlockCutNode<usize>,
+    }
+
+    impl WithID for TreeVertex {
+        type IDType = BlockCutNode<usize>;
+
+        fn get_id(&self) -> BlockCutNode<usize> {
+            self.node
+        }
+    }
+
+    #[test]
+    fn test_block_cut_tree_of_bowtie() {
+        // The triangles {0, 1, 2} and {2, 3, 4} share the articulation point 2
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..5).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, ()),
+                (1, 2, ()),
+                (2, 0, ()),
+                (2, 3, ()),
+                (3, 4, ()),
+                (4, 2, ()),
+            ],
+        )
+        .unwrap();
+
+        let tree = graph
+            .block_cut_tree::<_, ListGraphBackend<_, _, Undirected>>(|node| TreeVertex { node })
+            .unwrap();
+
+        assert_eq!(tree.vertex_count(), 3);
+        assert_eq!(tree.edge_count(), tree.vertex_count() - 1);
+        assert_eq!(tree.analyze_components().count(), 1);
+        let mut neighbors = tree
+            .get_adjacent_vertices(BlockCutNode::CutVertex(2))
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        assert_eq!(
+            neighbors,
+            vec![BlockCutNode::Block(0), BlockCutNode::Block(1)]
+        );
+    }
+
+    #[test]
+    fn test_block_cut_tree_with_bridges() {
+        // The square 0 - 1 - 2 - 3 with the pendant path 3 - 4 - 5 and the separate edge 6 - 7
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..8).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, ()),
+                (1, 2, ()),
+                (2, 3, ()),
+                (3, 0, ()),
+                (3, 4, ()),
+                (4, 5, ()),
+                (6, 7, ()),
+            ],
+        )
+        .unwrap();
+
+        let tree = graph
+            .block_cut_tree::<_, ListGraphBackend<_, _, Undirected>>(|node| TreeVertex { node })
+            .unwrap();
+
+        // 4 blocks and the articulation points 3 and 4, split into two trees
+        assert_eq!(tree.vertex_count(), 6);
+        assert_eq!(tree.edge_count(), tree.vertex_count() - 2);
+        assert_eq!(tree.analyze_components().count(), 2);
+        assert_eq!(
+            tree.get_adjacent_vertices(BlockCutNode::Block(3)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_biconnected_components_with_bridges() {
+        // The square 0 - 1 - 2 - 3 with the pendant path 3 - 4 - 5 and the separate edge 6 - 7
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..8).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, ()),
+                (1, 2, ()),
+                (2, 3, ()),
+                (3, 0, ()),
+                (3, 4, ()),
+                (4, 5, ()),
+                (6, 7, ()),
+            ],
+        )
+        .unwrap();
+
+        let blocks = graph.biconnected_components();
+
+        assert_eq!(
+            blocks,
+            vec![
+                vec![(0, 1), (0, 3), (1, 2), (2, 3)],
+                vec![(3, 4)],
+                vec![(4, 5)],
+                vec![(6, 7)]
+            ]
+        );
+        assert_eq!(graph.articulation_points(), vec![3, 4]);
+        // Every edge is in exactly one block
+        assert_eq!(
+            blocks.iter().map(Vec::len).sum::<usize>(),
+            graph.edge_count()
+        );
+    }
+
+    #[test]
+    fn test_low_link_matches_brute_force_on_random_graphs() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let mut graph = ListGraph::<MockVertex, (), Undirected>::new();
+            for id in 0..12 {
+                graph.push_vertex(MockVertex { id }).unwrap();
+            }
+            for from in 0..12 {
+                for to in from + 1..12 {
+                    if rng.random_bool(0.2) {
+                        graph.push_edge(from, to, ()).unwrap();
+                    }
+                }
+            }
+            let components = graph.analyze_components().count();
+
+            let expected_articulation_points = (0..12)
+                .filter(|&vid| {
+                    let mut without_vertex = graph.clone();
+                    let isolated = graph.get_adjacent_vertices(vid).next().is_none();
+                    without_vertex.remove_vertex(vid).unwrap();
+                    // Removing an isolated vertex removes its component
+                    without_vertex.analyze_components().count() + usize::from(isolated) > components
+                })
+                .collect::<Vec<_>>();
+            let mut expected_bridges = graph
+                .get_all_edges()
+                .map(|(from, to, _)| (from.min(to), from.max(to)))
+                .filter(|&(from, to)| {
+                    let mut without_edge = graph.clone();
+                    without_edge.remove_edge(from, to);
+                    without_edge.analyze_components().count() > components
+                })
+                .collect::<Vec<_>>();
+            expected_bridges.sort_unstable();
+
+            assert_eq!(graph.articulation_points(), expected_articulation_points);
+            assert_eq!(graph.two_edge_connected_components().1, expected_bridges);
+            assert_eq!(
+                graph
+                    .biconnected_components()
+                    .iter()
+                    .map(Vec::len)
+                    .sum::<usize>(),
+                graph.edge_count()
+            );
+        }
+    }
+}
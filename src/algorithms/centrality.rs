@@ -0,0 +1,234 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
+    Directed, Graph,
+};
+
+/// Closeness from the distances to all vertices reachable from a vertex (including the vertex itself at distance 0)
+fn closeness_from_distances(distances: impl Iterator<Item = f64>) -> f64 {
+    let (reachable, total_distance) = distances.fold((0usize, 0.0), |(count, total), distance| {
+        (count + 1, total + distance)
+    });
+
+    if total_distance > 0.0 {
+        (reachable - 1) as f64 / total_distance
+    } else {
+        0.0
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy + HeapWeight + Into<f64>,
+{
+    /// Computes the closeness centrality of every vertex, using the edge weights as distances.
+    ///
+    /// The closeness of `v` is `(r - 1) / Σ d(v, u)`, summing the shortest path costs to the `r - 1` other vertices
+    /// reachable from `v` (following the edge directions in directed graphs). Vertices that reach no other vertex
+    /// have a closeness of 0.
+    ///
+    /// Runs Dijkstra from every vertex, so the weights must not be negative.
+    /// For unweighted graphs, [`Graph::closeness_centrality_unweighted`] is much faster.
+    pub fn closeness_centrality(&self) -> FxHashMap<<Backend::Vertex as WithID>::IDType, f64> {
+        self.get_all_vertices()
+            .map(|v| {
+                let shortest_paths = self.dijkstra(v.get_id(), None);
+                let closeness = closeness_from_distances(
+                    self.get_all_vertices()
+                        .filter_map(|u| shortest_paths.get_cost(u.get_id()))
+                        .map(Into::into),
+                );
+                (v.get_id(), closeness)
+            })
+            .collect()
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Computes the closeness centrality of every vertex like [`Graph::closeness_centrality`],
+    /// but ignores the edge weights and uses the number of edges on a shortest path as distance.
+    ///
+    /// Runs a BFS from every vertex (O(n * (n + m))), which is much faster than Dijkstra and needs no weights.
+    pub fn closeness_centrality_unweighted(
+        &self,
+    ) -> FxHashMap<<Backend::Vertex as WithID>::IDType, f64> {
+        self.get_all_vertices()
+            .map(|v| {
+                let distances = self.bfs_multi_source(&[v.get_id()]);
+                let closeness =
+                    closeness_from_distances(distances.into_values().map(|hops| hops as f64));
+                (v.get_id(), closeness)
+            })
+            .collect()
+    }
+
+    /// Computes the degree centrality of every vertex: its [`degree`](Graph::degree) divided by `n - 1`.
+    ///
+    /// For undirected graphs the values lie in `[0, 1]`, where 1 means that the vertex is adjacent to all others.
+    /// For directed graphs the in- and out-degree are summed, so the values lie in `[0, 2]`;
+    /// see [`Graph::in_degree_centrality`] and [`Graph::out_degree_centrality`].
+    /// Graphs with a single vertex have a centrality of 0.
+    pub fn degree_centrality(&self) -> FxHashMap<<Backend::Vertex as WithID>::IDType, f64> {
+        self.normalized_degrees(true, true)
+    }
+
+    /// Counts the outgoing (`count_from`) and/or incoming (`count_to`) edges of every vertex, divided by `n - 1`
+    fn normalized_degrees(
+        &self,
+        count_from: bool,
+        count_to: bool,
+    ) -> FxHashMap<<Backend::Vertex as WithID>::IDType, f64> {
+        let mut degrees = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), 0usize))
+            .collect::<FxHashMap<_, _>>();
+        for (from, to, _) in self.get_all_edges() {
+            if count_from {
+                *degrees.entry(from).or_default() += 1;
+            }
+            if count_to {
+                *degrees.entry(to).or_default() += 1;
+            }
+        }
+
+        let other_vertices = self.vertex_count().saturating_sub(1).max(1) as f64;
+        degrees
+            .into_iter()
+            .map(|(vid, degree)| (vid, degree as f64 / other_vertices))
+            .collect()
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Computes the in-degree centrality of every vertex: its number of incoming edges divided by `n - 1`.
+    pub fn in_degree_centrality(&self) -> FxHashMap<<Backend::Vertex as WithID>::IDType, f64> {
+        self.normalized_degrees(false, true)
+    }
+
+    /// Computes the out-degree centrality of every vertex: its number of outgoing edges divided by `n - 1`.
+    pub fn out_degree_centrality(&self) -> FxHashMap<<Backend::Vertex as WithID>::IDType, f64> {
+        self.normalized_degrees(true, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::{graph::GraphBase, test_utils::MockVertex, Directed, ListGraph, Undirected};
+
+    #[test]
+    fn test_closeness_centrality_path() {
+        let graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0)],
+        )
+        .unwrap();
+
+        let closeness = graph.closeness_centrality_unweighted();
+
+        assert_eq!(closeness[&0], 3.0 / 6.0);
+        assert_eq!(closeness[&1], 3.0 / 4.0);
+        assert_eq!(closeness[&2], 3.0 / 4.0);
+        assert_eq!(closeness[&3], 3.0 / 6.0);
+    }
+
+    #[test]
+    fn test_closeness_centrality_weighted_matches_unweighted_for_unit_weights() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut graph = ListGraph::<MockVertex, f64, Directed>::new();
+        for id in 0..25 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for from in 0..25 {
+            for to in 0..25 {
+                if from != to && rng.random_bool(0.1) {
+                    graph.push_edge(from, to, 1.0).unwrap();
+                }
+            }
+        }
+
+        let weighted = graph.closeness_centrality();
+        let unweighted = graph.closeness_centrality_unweighted();
+
+        assert_eq!(weighted.len(), 25);
+        for (vid, closeness) in weighted {
+            assert!(
+                (closeness - unweighted[&vid]).abs() < 1e-12,
+                "closeness of {vid} differs"
+            );
+        }
+    }
+
+    #[test]
+    fn test_closeness_centrality_weighted() {
+        // 0 -> 1 costs 2, 1 -> 2 costs 3 and the sink 2 reaches nothing
+        let graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 2.0), (1, 2, 3.0)],
+        )
+        .unwrap();
+
+        let closeness = graph.closeness_centrality();
+
+        assert_eq!(closeness[&0], 2.0 / 7.0);
+        assert_eq!(closeness[&1], 1.0 / 3.0);
+        assert_eq!(closeness[&2], 0.0);
+    }
+
+    #[test]
+    fn test_degree_centrality_star() {
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..5).map(|id| MockVertex { id }).collect(),
+            (1..5).map(|leaf| (0, leaf, ())).collect(),
+        )
+        .unwrap();
+
+        let centrality = graph.degree_centrality();
+
+        assert_eq!(centrality.len(), 5);
+        assert_eq!(centrality[&0], 1.0);
+        for leaf in 1..5 {
+            assert_eq!(centrality[&leaf], 1.0 / 4.0);
+        }
+    }
+
+    #[test]
+    fn test_in_and_out_degree_centrality() {
+        // All leaves point to the center 0, which points back to leaf 1
+        let mut edges = (1..5).map(|leaf| (leaf, 0, ())).collect::<Vec<_>>();
+        edges.push((0, 1, ()));
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..5).map(|id| MockVertex { id }).collect(),
+            edges,
+        )
+        .unwrap();
+
+        let in_centrality = graph.in_degree_centrality();
+        let out_centrality = graph.out_degree_centrality();
+        let centrality = graph.degree_centrality();
+
+        assert_eq!(in_centrality[&0], 1.0);
+        assert_eq!(out_centrality[&0], 1.0 / 4.0);
+        assert_eq!(in_centrality[&1], 1.0 / 4.0);
+        assert_eq!(in_centrality[&2], 0.0);
+        assert_eq!(out_centrality[&2], 1.0 / 4.0);
+        for vid in 0..5 {
+            assert_eq!(centrality[&vid], in_centrality[&vid] + out_centrality[&vid]);
+        }
+    }
+}
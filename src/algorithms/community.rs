@@ -0,0 +1,328 @@
+use std::hash::Hash;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WeightedEdge, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected> + Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Detects communities with the Girvan-Newman algorithm: the edge with the highest betweenness is removed
+    /// (recomputing the betweenness after each removal) until the graph splits into `target_communities`
+    /// connected components. The graph itself is not modified, the edges are removed from a clone.
+    ///
+    /// Returns the vertex sets of the components, ordered by their smallest vertex ID.
+    /// If the graph already has at least `target_communities` components, these are returned unchanged.
+    /// If `target_communities` exceeds the number of vertices, every vertex ends up in its own community.
+    ///
+    /// Runs in O(m · m · (n + m)) time, as the edge betweenness is recomputed for each of the up to m removals,
+    /// so it is only meant for small graphs.
+    pub fn girvan_newman(
+        &self,
+        target_communities: usize,
+    ) -> Vec<FxHashSet<<Backend::Vertex as WithID>::IDType>> {
+        let mut graph = self.clone();
+
+        loop {
+            let communities = graph.connected_vertex_sets();
+            if communities.len() >= target_communities || graph.edge_count() == 0 {
+                return communities;
+            }
+
+            // Ties are broken by the smallest edge, so the result is deterministic
+            let (from, to) = graph
+                .edge_betweenness()
+                .into_iter()
+                .max_by(|(edge_a, value_a), (edge_b, value_b)| {
+                    value_a.total_cmp(value_b).then(edge_b.cmp(edge_a))
+                })
+                .map(|(edge, _)| edge)
+                .expect("The graph has at least one edge");
+            graph.remove_edge(from, to);
+        }
+    }
+
+    /// Returns the vertex sets of all connected components, ordered by their smallest vertex ID.
+    fn connected_vertex_sets(&self) -> Vec<FxHashSet<<Backend::Vertex as WithID>::IDType>> {
+        let mut vertices = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        vertices.sort_unstable();
+
+        let mut visited = FxHashSet::default();
+        let mut components = Vec::new();
+        for vid in vertices {
+            if visited.contains(&vid) {
+                continue;
+            }
+            let component = self.neighbors_within(vid, usize::MAX, true);
+            visited.extend(component.iter().copied());
+            components.push(component);
+        }
+
+        components
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Into<f64>,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Computes Newman's modularity Q of the partition given by `community_of`, using the edge weights.
+    ///
+    /// Q = Σ_c (w_c / m - (d_c / 2m)²), where m is the total edge weight, w_c the weight of the edges inside
+    /// community c and d_c the summed (weighted) degree of its vertices.
+    /// Values close to 0 indicate a partition that is no better than random,
+    /// higher values indicate dense communities with few edges between them.
+    ///
+    /// Returns 0.0 for a graph without edges (or with a total edge weight of zero).
+    pub fn modularity(
+        &self,
+        community_of: impl Fn(<Backend::Vertex as WithID>::IDType) -> usize,
+    ) -> f64 {
+        let mut total_weight = 0.0;
+        let mut inner_weights = FxHashMap::<usize, f64>::default();
+        let mut degree_sums = FxHashMap::<usize, f64>::default();
+        for (from, to, edge) in self.get_all_edges() {
+            let weight = edge.get_weight().into();
+            let (from_community, to_community) = (community_of(from), community_of(to));
+
+            total_weight += weight;
+            *degree_sums.entry(from_community).or_default() += weight;
+            *degree_sums.entry(to_community).or_default() += weight;
+            if from_community == to_community {
+                *inner_weights.entry(from_community).or_default() += weight;
+            }
+        }
+
+        if total_weight == 0.0 {
+            return 0.0;
+        }
+
+        degree_sums
+            .into_iter()
+            .map(|(community, degree_sum)| {
+                let inner_weight = inner_weights.get(&community).copied().unwrap_or(0.0);
+                inner_weight / total_weight - (degree_sum / (2.0 * total_weight)).powi(2)
+            })
+            .sum()
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Computes Newman's assortativity coefficient r of a categorical vertex attribute, i.e. whether vertices
+    /// with the same attribute value are connected more often than expected by chance.
+    ///
+    /// r = (Σ_i e_ii - Σ_i a_i b_i) / (1 - Σ_i a_i b_i), where e_ij is the fraction of edges from value i to value j,
+    /// and a_i and b_i are the fractions of edges starting and ending at value i.
+    /// Undirected edges are counted in both directions.
+    /// r is 1 if edges only connect equal values, close to 0 for random mixing and negative (down to -1)
+    /// if edges mostly connect different values.
+    ///
+    /// Returns 0.0 if the coefficient is undefined, i.e. for a graph without edges or if all edges connect
+    /// vertices of a single attribute value.
+    pub fn attribute_assortativity<A, F>(&self, attr: F) -> f64
+    where
+        A: Eq + Hash,
+        F: Fn(&Backend::Vertex) -> A,
+    {
+        // Map the attribute values to consecutive indices, to count the edges per value
+        let mut value_indices = FxHashMap::default();
+        let value_of = self
+            .get_all_vertices()
+            .map(|v| {
+                let next_index = value_indices.len();
+                (
+                    v.get_id(),
+                    *value_indices.entry(attr(v)).or_insert(next_index),
+                )
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut same_value_edges = 0usize;
+        let mut total_edges = 0usize;
+        let mut starting_edges = vec![0usize; value_indices.len()];
+        let mut ending_edges = vec![0usize; value_indices.len()];
+        let mut count_edge = |from_value: usize, to_value: usize| {
+            total_edges += 1;
+            starting_edges[from_value] += 1;
+            ending_edges[to_value] += 1;
+            if from_value == to_value {
+                same_value_edges += 1;
+            }
+        };
+        for (from, to, _) in self.get_all_edges() {
+            let (from_value, to_value) = (value_of[&from], value_of[&to]);
+            count_edge(from_value, to_value);
+            if !self.is_directed() {
+                count_edge(to_value, from_value);
+            }
+        }
+
+        if total_edges == 0 {
+            return 0.0;
+        }
+
+        let total_edges = total_edges as f64;
+        let expected_same_value = starting_edges
+            .iter()
+            .zip(&ending_edges)
+            .map(|(starting, ending)| {
+                (*starting as f64 / total_edges) * (*ending as f64 / total_edges)
+            })
+            .sum::<f64>();
+        if expected_same_value >= 1.0 {
+            return 0.0;
+        }
+
+        (same_value_edges as f64 / total_edges - expected_same_value) / (1.0 - expected_same_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+    use rustc_hash::FxHashSet;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Graph, ListGraph, Undirected,
+    };
+
+    /// Two 4-cliques {0, 1, 2, 3} and {4, 5, 6, 7}, joined by the edge 3 - 4
+    const TWO_CLUSTERS: [(usize, usize); 13] = [
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (4, 5),
+        (4, 6),
+        (4, 7),
+        (5, 6),
+        (5, 7),
+        (6, 7),
+        (3, 4),
+    ];
+
+    #[rstest]
+    fn test_girvan_newman_splits_clusters(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected> + Clone,
+        >,
+    ) {
+        for id in 0..8 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in TWO_CLUSTERS {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let communities = graph.girvan_newman(2);
+
+        assert_eq!(
+            communities,
+            vec![
+                FxHashSet::from_iter([0, 1, 2, 3]),
+                FxHashSet::from_iter([4, 5, 6, 7])
+            ]
+        );
+        // The original graph is left untouched
+        assert_eq!(graph.edge_count(), TWO_CLUSTERS.len());
+        assert_eq!(graph.girvan_newman(1).len(), 1);
+    }
+
+    #[test]
+    fn test_modularity_of_partitions() {
+        let graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..8).map(|id| MockVertex { id }).collect(),
+            TWO_CLUSTERS
+                .iter()
+                .map(|(from, to)| (*from, *to, 1.0))
+                .collect(),
+        )
+        .unwrap();
+
+        // 2 * (6 / 13 - (13 / 26)²)
+        let clustered = graph.modularity(|vid| vid / 4);
+        assert!(
+            (clustered - 0.4231).abs() < 1e-4,
+            "modularity was {clustered}"
+        );
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let random_partition = (0..8).map(|_| rng.random_range(0..2)).collect::<Vec<_>>();
+        let random = graph.modularity(|vid| random_partition[vid]);
+        assert!(random.abs() < 0.2, "modularity was {random}");
+
+        // A single community has a modularity of 0
+        assert!(graph.modularity(|_| 0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_modularity_without_edges() {
+        let graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![],
+        )
+        .unwrap();
+
+        assert_eq!(graph.modularity(|vid| vid), 0.0);
+    }
+
+    #[test]
+    fn test_attribute_assortativity() {
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..8).map(|id| MockVertex { id }).collect(),
+            TWO_CLUSTERS
+                .iter()
+                .map(|(from, to)| (*from, *to, ()))
+                .collect(),
+        )
+        .unwrap();
+
+        // (24 / 26 - 0.5) / (1 - 0.5)
+        let clustered = graph.attribute_assortativity(|v| v.id / 4);
+        assert!(
+            (clustered - 0.8462).abs() < 1e-4,
+            "assortativity was {clustered}"
+        );
+
+        // A single value is undefined
+        assert_eq!(graph.attribute_assortativity(|_| 0), 0.0);
+    }
+
+    #[test]
+    fn test_attribute_assortativity_disassortative_star() {
+        // The center only connects to leaves
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (0, 2, ()), (0, 3, ())],
+        )
+        .unwrap();
+
+        let star = graph.attribute_assortativity(|v| v.id == 0);
+
+        assert!((star + 1.0).abs() < 1e-9, "assortativity was {star}");
+    }
+}
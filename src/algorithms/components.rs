@@ -0,0 +1,192 @@
+use std::{fmt::Debug, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    algorithms::mst::union_find::UnionFind,
+    graph::{GraphBase, WithID},
+    Directed, Graph, GraphError,
+};
+
+/// The connected components of a graph, computed once by [`Graph::analyze_components`].
+///
+/// Answers repeated queries about the components without traversing the graph again.
+/// It is not updated when the graph changes.
+/// Components are indexed by their smallest vertex ID, the members of each component are sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentAnalysis<VId: Eq + Hash> {
+    component_of: FxHashMap<VId, usize>,
+    members: Vec<Vec<VId>>,
+}
+
+impl<VId: Copy + Eq + Hash> ComponentAnalysis<VId> {
+    /// Returns the number of components.
+    pub fn count(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns the index of the component containing `vertex_id`, or `None` if the vertex did not exist.
+    pub fn component_of(&self, vertex_id: VId) -> Option<usize> {
+        self.component_of.get(&vertex_id).copied()
+    }
+
+    /// Returns the vertices of the component with the given index (in ascending order).
+    ///
+    /// # Panics
+    /// Panics if `component_idx` is not smaller than [`ComponentAnalysis::count`].
+    pub fn members(&self, component_idx: usize) -> &[VId] {
+        &self.members[component_idx]
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    Backend::Vertex: Clone,
+    Backend::Edge: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash + Debug,
+{
+    /// Splits the graph into its weakly connected components, each returned as its own graph.
+    ///
+    /// Two vertices are in the same weak component if they are connected when ignoring edge directions.
+    /// The component graphs keep the original vertices, edge directions and edge data.
+    /// Components are ordered by their smallest vertex ID and vertices are inserted in ascending ID order.
+    ///
+    /// # Errors
+    /// Returns the error of the output backend if it cannot store a component
+    /// (e.g. an adjacency matrix for a component whose IDs do not start at 0).
+    #[allow(clippy::type_complexity)]
+    pub fn weakly_connected_components<OutputBackend>(
+        &self,
+    ) -> Result<Vec<Graph<OutputBackend>>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        let analysis = self.analyze_components();
+
+        let mut components = Vec::with_capacity(analysis.count());
+        for component_idx in 0..analysis.count() {
+            let mut component = Graph::<OutputBackend>::new();
+            for &vid in analysis.members(component_idx) {
+                let vertex = self
+                    .get_vertex_by_id(vid)
+                    .expect("The analysis only contains existing vertices");
+                component.push_vertex(vertex.clone())?;
+            }
+            components.push(component);
+        }
+
+        for (from, to, edge) in self.get_all_edges() {
+            let component_idx = analysis
+                .component_of(from)
+                .expect("All edges must connect to existing vertices");
+            components[component_idx].push_edge(from, to, edge.clone())?;
+        }
+
+        Ok(components)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash + Debug,
+{
+    /// Computes the connected components once and returns them as a reusable [`ComponentAnalysis`].
+    ///
+    /// In directed graphs, the weakly connected components are computed (edge directions are ignored).
+    pub fn analyze_components(&self) -> ComponentAnalysis<<Backend::Vertex as WithID>::IDType> {
+        let mut union_find = UnionFind::new();
+        for v in self.get_all_vertices() {
+            union_find
+                .make_set(v.get_id())
+                .expect("Vertex IDs must be unique");
+        }
+        for (from, to, _) in self.get_all_edges() {
+            union_find
+                .union(&from, &to)
+                .expect("All edges must connect to existing vertices");
+        }
+
+        let mut vertices = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        vertices.sort_unstable();
+
+        // Map the union-find root of each component to its index
+        let mut root_index = FxHashMap::default();
+        let mut component_of = FxHashMap::default();
+        let mut members = Vec::<Vec<_>>::new();
+        for vid in vertices {
+            let root = union_find
+                .find(&vid)
+                .expect("All vertices were added to the union-find structure");
+            let index = *root_index.entry(root).or_insert_with(|| {
+                members.push(Vec::new());
+                members.len() - 1
+            });
+            component_of.insert(vid, index);
+            members[index].push(vid);
+        }
+
+        ComponentAnalysis {
+            component_of,
+            members,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graph::GraphBase, test_utils::MockVertex, Directed, ListGraph};
+
+    #[test]
+    fn test_weakly_connected_components() {
+        // Two weak components: {0, 1, 2, 3} (not strongly connected) and {4, 5}, plus the isolated vertex 6
+        let graph = ListGraph::<MockVertex, i32, Directed>::from_vertices_and_edges(
+            (0..7).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1), (2, 1, 2), (3, 2, 3), (5, 4, 4), (4, 5, 5)],
+        )
+        .unwrap();
+
+        let components: Vec<ListGraph<MockVertex, i32, Directed>> =
+            graph.weakly_connected_components().unwrap();
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0].vertex_count(), 4);
+        assert_eq!(components[0].edge_count(), 3);
+        assert_eq!(components[0].get_edge(2, 1), Some(&2));
+        assert_eq!(components[0].get_edge(1, 2), None);
+
+        assert_eq!(components[1].vertex_count(), 2);
+        assert_eq!(components[1].edge_count(), 2);
+        assert_eq!(components[1].get_edge(5, 4), Some(&4));
+
+        assert_eq!(components[2].vertex_count(), 1);
+        assert_eq!(components[2].edge_count(), 0);
+    }
+
+    #[test]
+    fn test_analyze_components() {
+        let graph = ListGraph::<MockVertex, i32, Directed>::from_vertices_and_edges(
+            (0..7).map(|id| MockVertex { id }).collect(),
+            vec![(3, 0, 1), (5, 2, 2), (2, 3, 3), (6, 4, 4)],
+        )
+        .unwrap();
+
+        let analysis = graph.analyze_components();
+
+        assert_eq!(analysis.count(), 3);
+        assert_eq!(analysis.members(0), &[0, 2, 3, 5]);
+        assert_eq!(analysis.members(1), &[1]);
+        assert_eq!(analysis.members(2), &[4, 6]);
+        assert_eq!(analysis.component_of(5), Some(0));
+        assert_eq!(analysis.component_of(6), Some(2));
+        assert_eq!(analysis.component_of(42), None);
+    }
+}
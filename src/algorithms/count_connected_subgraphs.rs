@@ -1,13 +1,16 @@
-use std::{collections::VecDeque, hash::Hash};
+use std::{collections::VecDeque, hash::Hash, num::NonZero, thread};
 
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
-    algorithms::iter::TraversalType,
+    algorithms::{iter::TraversalType, mst::union_find::DenseUnionFind},
     graph::{GraphBase, WithID},
-    Graph, GraphError,
+    Graph, GraphError, Undirected,
 };
 
+/// Below this number of edges per thread, spawning threads costs more than it saves
+const MIN_EDGES_PER_THREAD: usize = 1 << 16;
+
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
@@ -59,3 +62,101 @@ where
         self.count_connected_subgraphs(None)
     }
 }
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Counts the connected components of an undirected graph with a union-find instead of traversals.
+    ///
+    /// The vertices are mapped to dense indices first. For large graphs, the edges are then split into chunks
+    /// that are merged into thread-local union-finds in parallel, whose results are combined afterwards.
+    /// Returns the same count as [`Graph::count_connected_subgraphs`].
+    pub fn count_connected_components_uf(&self) -> u32 {
+        let parallelism = thread::available_parallelism().map_or(1, NonZero::get);
+        let threads = (self.edge_count() / MIN_EDGES_PER_THREAD).clamp(1, parallelism);
+        self.count_connected_components_uf_with_threads(threads)
+    }
+
+    fn count_connected_components_uf_with_threads(&self, threads: usize) -> u32 {
+        let indices = self
+            .get_all_vertices()
+            .enumerate()
+            .map(|(index, v)| (v.get_id(), index))
+            .collect::<FxHashMap<_, _>>();
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, _)| (indices[&from], indices[&to]))
+            .collect::<Vec<_>>();
+        let vertex_count = indices.len();
+
+        let mut union_find = DenseUnionFind::new(vertex_count);
+        if threads <= 1 {
+            for (a, b) in edges {
+                union_find.union(a, b);
+            }
+        } else {
+            let chunk_size = edges.len().div_ceil(threads).max(1);
+            thread::scope(|scope| {
+                let handles = edges
+                    .chunks(chunk_size)
+                    .map(|chunk| {
+                        scope.spawn(move || {
+                            let mut local = DenseUnionFind::new(vertex_count);
+                            for &(a, b) in chunk {
+                                local.union(a, b);
+                            }
+                            // Only the links to the local roots have to be merged
+                            (0..vertex_count)
+                                .filter_map(|v| {
+                                    let root = local.find(v);
+                                    (root != v).then_some((v, root))
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                for handle in handles {
+                    for (v, root) in handle.join().expect("Union-find worker panicked") {
+                        union_find.union(v, root);
+                    }
+                }
+            });
+        }
+
+        union_find.set_count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{graph::GraphBase, test_utils::MockVertex, ListGraph, Undirected};
+
+    #[rstest]
+    fn test_count_connected_components_uf_in_parallel_chunks(#[values(1, 2, 3, 8)] threads: usize) {
+        // Components {0, 1, 2, 3}, {4, 5}, {6}, {7, 8, 9} with edges spread over all chunks
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..10).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, ()),
+                (4, 5, ()),
+                (7, 8, ()),
+                (2, 3, ()),
+                (9, 8, ()),
+                (1, 2, ()),
+                (3, 0, ()),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(graph.count_connected_components_uf_with_threads(threads), 4);
+        assert_eq!(
+            graph.count_connected_components_uf(),
+            graph.count_connected_subgraphs(None).unwrap()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Counts the number of triangles (cycles of length 3) in the graph.
+    ///
+    /// Uses the node-iterator method: every vertex only looks at neighbors with a higher rank,
+    /// where vertices are ranked by their degree (ties broken by ID).
+    /// This counts each triangle exactly once and keeps the number of `has_edge` checks small,
+    /// as high-degree vertices are only visited from their lower-ranked neighbors.
+    pub fn count_triangles(&self) -> usize {
+        let degrees = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), self.get_adjacent_vertices(v.get_id()).count()))
+            .collect::<FxHashMap<_, _>>();
+        let rank = |vid: <Backend::Vertex as WithID>::IDType| (degrees[&vid], vid);
+
+        let mut count = 0;
+        for &u in degrees.keys() {
+            // Only keep neighbors that are ranked higher than u
+            let mut higher_neighbors = self
+                .get_adjacent_vertices(u)
+                .map(|v| v.get_id())
+                .filter(|v| rank(*v) > rank(u))
+                .collect::<Vec<_>>();
+            higher_neighbors.sort_by_key(|v| rank(*v));
+
+            // Check every pair (v, w) with rank(u) < rank(v) < rank(w) for the closing edge
+            for (i, &v) in higher_neighbors.iter().enumerate() {
+                for &w in &higher_neighbors[i + 1..] {
+                    if self.has_edge(v, w) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::MatrixGraph,
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], 4)]
+    #[case::complete_bipartite(5, vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)], 0)]
+    #[case::two_triangles_sharing_an_edge(4, vec![(0, 1), (1, 2), (2, 0), (1, 3), (2, 3)], 2)]
+    #[case::empty(3, vec![], 0)]
+    fn test_count_triangles(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: usize,
+    ) {
+        let list_graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+        let matrix_graph: MatrixGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(list_graph.count_triangles(), expected);
+        assert_eq!(matrix_graph.count_triangles(), expected);
+    }
+}
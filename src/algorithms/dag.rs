@@ -0,0 +1,344 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Directed, Graph, GraphError,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Returns all vertices that can reach `vertex_id` (its transitive predecessors).
+    ///
+    /// `vertex_id` itself is not included, unless it lies on a cycle.
+    /// Returns an empty set if the vertex does not exist.
+    pub fn ancestors(
+        &self,
+        vertex_id: <Backend::Vertex as WithID>::IDType,
+    ) -> FxHashSet<<Backend::Vertex as WithID>::IDType> {
+        let Ok(reverse_bfs) = self.reverse_bfs_iter(vertex_id) else {
+            return FxHashSet::default();
+        };
+        let mut ancestors = reverse_bfs.map(|v| v.get_id()).collect::<FxHashSet<_>>();
+
+        // The traversal yields `vertex_id` first, it is only its own ancestor if one of its successors reaches it
+        if !self
+            .get_adjacent_vertices(vertex_id)
+            .any(|v| ancestors.contains(&v.get_id()))
+        {
+            ancestors.remove(&vertex_id);
+        }
+
+        ancestors
+    }
+
+    /// Returns all vertices that can be reached from `vertex_id` (its transitive successors).
+    ///
+    /// `vertex_id` itself is not included, unless it lies on a cycle.
+    /// Returns an empty set if the vertex does not exist.
+    pub fn descendants(
+        &self,
+        vertex_id: <Backend::Vertex as WithID>::IDType,
+    ) -> FxHashSet<<Backend::Vertex as WithID>::IDType> {
+        let mut descendants = FxHashSet::default();
+        if self.get_vertex_by_id(vertex_id).is_none() {
+            return descendants;
+        }
+
+        let mut queue = VecDeque::from([vertex_id]);
+        while let Some(current) = queue.pop_front() {
+            for neighbor in self.get_adjacent_vertices(current) {
+                if descendants.insert(neighbor.get_id()) {
+                    queue.push_back(neighbor.get_id());
+                }
+            }
+        }
+
+        descendants
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Groups the vertices into topological generations (layers) with Kahn's algorithm.
+    ///
+    /// Generation 0 contains all vertices without incoming edges, generation `k` all vertices
+    /// whose predecessors are all in generations `< k` (with at least one in generation `k - 1`).
+    /// The vertices of each generation are sorted by ID.
+    ///
+    /// # Errors
+    /// - `GraphError::CycleDetected`: if the graph contains a cycle (including self-loops).
+    #[allow(clippy::type_complexity)]
+    pub fn topological_generations(
+        &self,
+    ) -> Result<
+        Vec<Vec<<Backend::Vertex as WithID>::IDType>>,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        let mut generations = self.kahn_generations()?;
+        for generation in &mut generations {
+            generation.sort_unstable();
+        }
+
+        Ok(generations)
+    }
+
+    /// Orders the vertices topologically with Kahn's algorithm, i.e. every edge points from an earlier to a later vertex.
+    ///
+    /// The vertices without incoming edges are queued by ascending ID, and the vertices whose last incoming edge
+    /// is removed by placing a vertex are queued by ascending ID as well, so the order is the same for every backend.
+    ///
+    /// # Errors
+    /// - `GraphError::CycleDetected`: if the graph contains a cycle (including self-loops).
+    #[allow(clippy::type_complexity)]
+    pub fn topological_sort(
+        &self,
+    ) -> Result<
+        Vec<<Backend::Vertex as WithID>::IDType>,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        Ok(self.kahn_generations()?.into_iter().flatten().collect())
+    }
+
+    /// Returns whether the graph is a directed acyclic graph (DAG), e.g. to check the input of DAG-only methods
+    /// like [`Graph::topological_generations`] beforehand.
+    ///
+    /// Self-loops count as cycles.
+    pub fn is_dag(&self) -> bool {
+        // Kahn's algorithm only fails if a cycle keeps some vertices from reaching an in-degree of 0
+        self.kahn_generations().is_ok()
+    }
+
+    /// Runs Kahn's algorithm and returns the vertices grouped by generation.
+    ///
+    /// Each generation is in the order a FIFO queue would place its vertices: the sources by ascending ID,
+    /// then the vertices freed by each placed vertex, again by ascending ID. Flattened, this is the order
+    /// of [`Graph::topological_sort`].
+    #[allow(clippy::type_complexity)]
+    fn kahn_generations(
+        &self,
+    ) -> Result<
+        Vec<Vec<<Backend::Vertex as WithID>::IDType>>,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        let mut in_degrees = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), 0usize))
+            .collect::<FxHashMap<_, _>>();
+        for (_, to, _) in self.get_all_edges() {
+            *in_degrees
+                .get_mut(&to)
+                .expect("Edges only point to existing vertices") += 1;
+        }
+
+        let mut current = in_degrees
+            .iter()
+            .filter(|(_, in_degree)| **in_degree == 0)
+            .map(|(vid, _)| *vid)
+            .collect::<Vec<_>>();
+        current.sort_unstable();
+        let mut generations = Vec::new();
+        let mut visited_count = 0;
+
+        while !current.is_empty() {
+            visited_count += current.len();
+
+            // All predecessors of the next generation are now placed
+            let mut next = Vec::new();
+            for &vid in &current {
+                let freed_start = next.len();
+                for neighbor in self.get_adjacent_vertices(vid) {
+                    let in_degree = in_degrees
+                        .get_mut(&neighbor.get_id())
+                        .expect("Edges only point to existing vertices");
+                    *in_degree -= 1;
+                    if *in_degree == 0 {
+                        next.push(neighbor.get_id());
+                    }
+                }
+                next[freed_start..].sort_unstable();
+            }
+
+            generations.push(std::mem::replace(&mut current, next));
+        }
+
+        // Vertices on a cycle (or behind one) never reach an in-degree of 0
+        if visited_count != in_degrees.len() {
+            return Err(GraphError::CycleDetected);
+        }
+
+        Ok(generations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rustc_hash::FxHashSet;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, GraphError, ListGraph,
+    };
+
+    #[rstest]
+    fn test_ancestors_and_descendants(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+        >,
+    ) {
+        // 0 -> 1 -> 3 -> 5, 0 -> 2 -> 3, 2 -> 4 and the unrelated vertex 6
+        for id in 0..7 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 3), (3, 5), (0, 2), (2, 3), (2, 4)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let ancestors = graph.ancestors(3);
+        let descendants = graph.descendants(3);
+
+        assert_eq!(ancestors, FxHashSet::from_iter([0, 1, 2]));
+        assert_eq!(descendants, FxHashSet::from_iter([5]));
+        // In a DAG, a vertex is neither its own ancestor nor descendant,
+        // and no vertex is both an ancestor and a descendant
+        assert!(ancestors.is_disjoint(&descendants));
+
+        assert_eq!(graph.descendants(2), FxHashSet::from_iter([3, 4, 5]));
+        assert!(graph.ancestors(0).is_empty());
+        assert!(graph.ancestors(6).is_empty());
+        assert!(graph.descendants(6).is_empty());
+    }
+
+    #[rstest]
+    #[case::diamond(4, vec![(0, 1), (0, 2), (1, 3), (2, 3)], vec![vec![0], vec![1, 2], vec![3]])]
+    #[case::unequal_paths(4, vec![(0, 1), (1, 2), (0, 2), (3, 2)], vec![vec![0, 3], vec![1], vec![2]])]
+    #[case::no_edges(3, vec![], vec![vec![0, 1, 2]])]
+    #[case::empty(0, vec![], vec![])]
+    fn test_topological_generations(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: Vec<Vec<usize>>,
+    ) {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..n_vertices).map(|id| MockVertex { id }).collect(),
+            edges.into_iter().map(|(from, to)| (from, to, ())).collect(),
+        )
+        .unwrap();
+
+        assert_eq!(graph.topological_generations().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_topological_generations_rejects_cycles() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 1, ()), (2, 3, ())],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            graph.topological_generations(),
+            Err(GraphError::CycleDetected)
+        ));
+    }
+
+    #[rstest]
+    #[case::chain(vec![(2, 3), (1, 2), (0, 1)], vec![0, 1, 2, 3])]
+    #[case::diamond(vec![(0, 2), (0, 1), (1, 3), (2, 3)], vec![0, 1, 2, 3])]
+    #[case::two_sources(vec![(3, 0), (1, 0), (0, 2)], vec![1, 3, 0, 2])]
+    fn test_topological_sort(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+        >,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: Vec<usize>,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in edges {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let order = graph.topological_sort().unwrap();
+
+        assert_eq!(order, expected);
+        let position = |vid| order.iter().position(|&v| v == vid).unwrap();
+        assert!(graph
+            .get_all_edges()
+            .all(|(from, to, _)| position(from) < position(to)));
+    }
+
+    #[rstest]
+    #[case::cycle(vec![(0, 1), (1, 2), (2, 0), (2, 3)])]
+    #[case::self_loop(vec![(0, 1), (1, 1)])]
+    fn test_topological_sort_rejects_cycles(#[case] edges: Vec<(usize, usize)>) {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            edges.into_iter().map(|(from, to)| (from, to, ())).collect(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            graph.topological_sort(),
+            Err(GraphError::CycleDetected)
+        ));
+    }
+
+    #[rstest]
+    #[case::dag(vec![(0, 1), (0, 2), (1, 3), (2, 3)], true)]
+    #[case::no_edges(vec![], true)]
+    #[case::cycle(vec![(0, 1), (1, 2), (2, 0), (2, 3)], false)]
+    #[case::self_loop(vec![(0, 1), (1, 1)], false)]
+    fn test_is_dag(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+        >,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: bool,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in edges {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        assert_eq!(graph.is_dag(), expected);
+    }
+
+    #[test]
+    fn test_ancestors_on_cycle_include_vertex() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 1, ())],
+        )
+        .unwrap();
+
+        assert_eq!(graph.ancestors(1), FxHashSet::from_iter([0, 1, 2]));
+        assert!(graph.ancestors(0).is_empty());
+        assert_eq!(graph.descendants(1), FxHashSet::from_iter([1, 2]));
+        assert!(graph.ancestors(42).is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Returns the degeneracy of the graph, i.e. the smallest `k` such that every subgraph
+    /// contains a vertex with a degree of at most `k`.
+    ///
+    /// Trees have a degeneracy of 1 and the complete graph `K_n` has a degeneracy of `n - 1`.
+    pub fn degeneracy(&self) -> usize {
+        self.core_peeling().1
+    }
+
+    /// Returns a degeneracy ordering of the vertices.
+    ///
+    /// Every vertex has at most [`degeneracy`](Self::degeneracy) neighbors that come later in the ordering.
+    /// Processing vertices in this order keeps the candidate sets small in clique and coloring heuristics.
+    pub fn degeneracy_ordering(&self) -> Vec<<Backend::Vertex as WithID>::IDType> {
+        self.core_peeling().0
+    }
+
+    /// Repeatedly removes a vertex with the minimum degree (k-core peeling) in O(n + m) using bucket queues.
+    ///
+    /// Returns the removal order and the largest degree a vertex had at the time of its removal (the degeneracy).
+    fn core_peeling(&self) -> (Vec<<Backend::Vertex as WithID>::IDType>, usize) {
+        // Sorted IDs make the ordering deterministic
+        let mut ids = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        let index_of = ids
+            .iter()
+            .enumerate()
+            .map(|(idx, &vid)| (vid, idx))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut degrees = ids
+            .iter()
+            .map(|&vid| self.get_adjacent_vertices(vid).count())
+            .collect::<Vec<_>>();
+        let max_degree = degrees.iter().copied().max().unwrap_or_default();
+
+        // `buckets[d]` holds all vertices with degree `d`. Entries become stale when the degree of a vertex drops.
+        let mut buckets = vec![Vec::new(); max_degree + 1];
+        // Push in reverse, so that vertices with smaller IDs are popped first
+        for (idx, &degree) in degrees.iter().enumerate().rev() {
+            buckets[degree].push(idx);
+        }
+
+        let mut removed = vec![false; ids.len()];
+        let mut ordering = Vec::with_capacity(ids.len());
+        let mut degeneracy = 0;
+        let mut current_degree = 0;
+
+        for _ in 0..ids.len() {
+            let idx = loop {
+                match buckets[current_degree].pop() {
+                    Some(idx) if !removed[idx] && degrees[idx] == current_degree => break idx,
+                    // Stale entry, the vertex was already removed or moved to a lower bucket
+                    Some(_) => continue,
+                    None => current_degree += 1,
+                }
+            };
+
+            removed[idx] = true;
+            degeneracy = degeneracy.max(current_degree);
+            ordering.push(ids[idx]);
+
+            for neighbor in self.get_adjacent_vertices(ids[idx]) {
+                let neighbor_idx = index_of[&neighbor.get_id()];
+                if !removed[neighbor_idx] {
+                    degrees[neighbor_idx] -= 1;
+                    buckets[degrees[neighbor_idx]].push(neighbor_idx);
+                }
+            }
+
+            // Removing a vertex lowers the degree of its neighbors by at most one
+            current_degree = current_degree.saturating_sub(1);
+        }
+
+        (ordering, degeneracy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph, WithID},
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::tree(6, vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)], 1)]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], 3)]
+    #[case::cycle_with_tail(5, vec![(0, 1), (1, 2), (2, 3), (3, 0), (3, 4)], 2)]
+    #[case::no_edges(3, vec![], 0)]
+    fn test_degeneracy(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: usize,
+    ) {
+        let list_graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+        let matrix_graph: MatrixGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(list_graph.degeneracy(), expected);
+        assert_eq!(matrix_graph.degeneracy(), expected);
+    }
+
+    #[test]
+    fn test_degeneracy_ordering_bounds_later_neighbors() {
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+        ];
+        let graph: ListGraph<MockVertex, (), Undirected> = build_graph(6, &edges);
+
+        let ordering = graph.degeneracy_ordering();
+        assert_eq!(ordering.len(), 6);
+
+        let degeneracy = graph.degeneracy();
+        for (position, &vid) in ordering.iter().enumerate() {
+            let later_neighbors = graph
+                .get_adjacent_vertices(vid)
+                .filter(|v| ordering[position + 1..].contains(&v.get_id()))
+                .count();
+            assert!(later_neighbors <= degeneracy);
+        }
+    }
+}
@@ -1,11 +1,18 @@
 use rustc_hash::FxHashSet;
-use std::hash::Hash;
+use std::{hash::Hash, marker::PhantomData};
 
 use crate::{
     graph::{GraphBase, WithID},
     Graph, GraphError,
 };
 
+/// Iterator over the vertices of a graph in depth-first order.
+///
+/// Vertices are marked as visited when they are pushed onto the stack (not when they are popped),
+/// so every vertex is on the stack at most once and the stack never grows beyond the number of vertices.
+///
+/// The iterator can be cloned to fork a traversal. Cloning duplicates the stack and the visited set,
+/// so both copies continue independently from the state at the time of cloning.
 pub struct DfsIter<'a, Backend>
 where
     Backend: GraphBase,
@@ -13,6 +20,14 @@ where
     graph: &'a Graph<Backend>,
     stack: Vec<<Backend::Vertex as WithID>::IDType>,
     visited: FxHashSet<<Backend::Vertex as WithID>::IDType>,
+    /// Optionally reorders the neighbors of a vertex before they are pushed onto the stack
+    #[allow(clippy::type_complexity)]
+    order_neighbors: Option<fn(&mut [<Backend::Vertex as WithID>::IDType])>,
+}
+
+/// Sorts descending, so that the smallest ID ends up on top of the stack
+fn sort_descending<T: Ord>(ids: &mut [T]) {
+    ids.sort_unstable_by(|a, b| b.cmp(a));
 }
 
 impl<'a, Backend> DfsIter<'a, Backend>
@@ -37,8 +52,36 @@ where
             graph,
             stack,
             visited,
+            order_neighbors: None,
         })
     }
+
+    fn push_unvisited(
+        &mut self,
+        neighbors: impl IntoIterator<Item = <Backend::Vertex as WithID>::IDType>,
+    ) {
+        for vid in neighbors {
+            // Mark on push, so that no vertex is pushed twice
+            if self.visited.insert(vid) {
+                self.stack.push(vid);
+            }
+        }
+    }
+}
+
+impl<Backend> Clone for DfsIter<'_, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Clone,
+{
+    fn clone(&self) -> Self {
+        DfsIter {
+            graph: self.graph,
+            stack: self.stack.clone(),
+            visited: self.visited.clone(),
+            order_neighbors: self.order_neighbors,
+        }
+    }
 }
 
 impl<'a, Backend> Iterator for DfsIter<'a, Backend>
@@ -54,23 +97,191 @@ where
                 "get_vertex_by_id should not error as the vertices in the stack must exist",
             );
 
+            let neighbors = self
+                .graph
+                .get_adjacent_vertices(next_id)
+                .map(|v| v.get_id());
+
+            match self.order_neighbors {
+                Some(order_neighbors) => {
+                    let mut neighbors = neighbors.collect::<Vec<_>>();
+                    order_neighbors(&mut neighbors);
+                    self.push_unvisited(neighbors);
+                }
+                None => self.push_unvisited(neighbors),
+            }
+
+            Some(current_vertex)
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over mutable references to the vertices of a graph in depth-first order.
+///
+/// Visits the vertices in the same order as [`DfsIter`] without a neighbor ordering.
+pub struct DfsIterMut<'a, Backend>
+where
+    Backend: GraphBase,
+{
+    graph: &'a mut Graph<Backend>,
+    stack: Vec<<Backend::Vertex as WithID>::IDType>,
+    visited: FxHashSet<<Backend::Vertex as WithID>::IDType>,
+    _phantom: PhantomData<&'a Backend::Edge>,
+}
+
+impl<'a, Backend> DfsIterMut<'a, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    fn new(
+        graph: &'a mut Graph<Backend>,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        graph
+            .get_vertex_by_id(start_vertex)
+            .ok_or(GraphError::VertexNotFound(start_vertex))?;
+
+        let stack = vec![start_vertex];
+
+        let mut visited = FxHashSet::default();
+        visited.insert(start_vertex);
+
+        Ok(DfsIterMut {
+            graph,
+            stack,
+            visited,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'a, Backend> Iterator for DfsIterMut<'a, Backend>
+where
+    Backend: GraphBase,
+    Backend::Vertex: 'a + WithID,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    type Item = &'a mut Backend::Vertex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(next_id) = self.stack.pop() {
             let neighbors = self.graph.get_adjacent_vertices(next_id);
 
             for v in neighbors {
+                // Mark on push, so that no vertex is pushed twice
                 let vid = v.get_id();
-                if !self.visited.contains(&vid) {
-                    self.visited.insert(vid);
+                if self.visited.insert(vid) {
                     self.stack.push(vid);
                 }
             }
 
-            Some(current_vertex)
+            // SAFETY: This is safe because:
+            // 1. We only return one mutable reference at a time
+            // 2. Each vertex is pushed onto the stack at most once (tracked by the visited set)
+            // 3. The reference doesn't outlive the graph (tied to lifetime 'a)
+            unsafe {
+                let vertex_ptr = self.graph.get_vertex_by_id_mut(next_id).expect(
+                    "get_vertex_by_id_mut should not error as the vertices in the stack must exist",
+                ) as *mut Backend::Vertex;
+
+                Some(&mut *vertex_ptr)
+            }
         } else {
             None
         }
     }
 }
 
+/// Iterator over the vertices of a graph in depth-first post-order.
+///
+/// A vertex is yielded once all vertices reachable through it have been explored (its finish order),
+/// which is the order needed for topological sorting or strongly connected components.
+/// Neighbors are explored in the order they are returned by the graph.
+pub struct DfsPostOrderIter<'a, Backend>
+where
+    Backend: GraphBase,
+{
+    graph: &'a Graph<Backend>,
+    /// Vertices on the current DFS path, each with the neighbors that still have to be expanded (in reverse order)
+    #[allow(clippy::type_complexity)]
+    stack: Vec<(
+        <Backend::Vertex as WithID>::IDType,
+        Vec<<Backend::Vertex as WithID>::IDType>,
+    )>,
+    visited: FxHashSet<<Backend::Vertex as WithID>::IDType>,
+}
+
+impl<'a, Backend> DfsPostOrderIter<'a, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    fn new(
+        graph: &'a Graph<Backend>,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        graph
+            .get_vertex_by_id(start_vertex)
+            .ok_or(GraphError::VertexNotFound(start_vertex))?;
+
+        let mut iter = DfsPostOrderIter {
+            graph,
+            stack: Vec::new(),
+            visited: FxHashSet::default(),
+        };
+        iter.expand(start_vertex);
+
+        Ok(iter)
+    }
+
+    /// Marks the vertex as visited and puts it on the stack together with its (not yet expanded) neighbors
+    fn expand(&mut self, vertex_id: <Backend::Vertex as WithID>::IDType) {
+        self.visited.insert(vertex_id);
+        let mut neighbors = self
+            .graph
+            .get_adjacent_vertices(vertex_id)
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        // Reverse, so that popping yields the neighbors in their original order
+        neighbors.reverse();
+        self.stack.push((vertex_id, neighbors));
+    }
+}
+
+impl<'a, Backend> Iterator for DfsPostOrderIter<'a, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash + Copy,
+{
+    type Item = &'a Backend::Vertex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (vertex_id, neighbors) = self.stack.last_mut()?;
+
+            match neighbors.pop() {
+                // Descend into the next unvisited neighbor
+                Some(neighbor) => {
+                    if !self.visited.contains(&neighbor) {
+                        self.expand(neighbor);
+                    }
+                }
+                // All neighbors are explored, the vertex is finished
+                None => {
+                    let finished = *vertex_id;
+                    self.stack.pop();
+                    return Some(self.graph.get_vertex_by_id(finished).expect(
+                        "get_vertex_by_id should not error as the vertices in the stack must exist",
+                    ));
+                }
+            }
+        }
+    }
+}
+
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
@@ -82,4 +293,36 @@ where
     ) -> Result<DfsIter<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>> {
         DfsIter::new(self, start_vertex)
     }
+
+    pub fn dfs_iter_mut(
+        &mut self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<DfsIterMut<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        DfsIterMut::new(self, start_vertex)
+    }
+
+    /// Creates a DFS iterator that visits the neighbors of each vertex in ascending order of their IDs.
+    ///
+    /// Unlike [`Graph::dfs_iter`], the traversal order does not depend on the order in which the backend
+    /// stores the adjacent vertices, so it is the same for every backend and every edge insertion order.
+    pub fn dfs_iter_with_order(
+        &self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<DfsIter<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        <Backend::Vertex as WithID>::IDType: Ord,
+    {
+        let mut iter = DfsIter::new(self, start_vertex)?;
+        iter.order_neighbors = Some(sort_descending);
+        Ok(iter)
+    }
+
+    /// Creates an iterator that yields the vertices reachable from `start_vertex` in depth-first post-order.
+    pub fn dfs_postorder_iter(
+        &self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<DfsPostOrderIter<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    {
+        DfsPostOrderIter::new(self, start_vertex)
+    }
 }
@@ -0,0 +1,132 @@
+use std::hash::Hash;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
+    Graph,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy + HeapWeight,
+{
+    /// Estimates the (weighted) diameter, the largest shortest path cost between two vertices,
+    /// without computing the shortest paths between all pairs.
+    ///
+    /// Runs a double sweep from up to `samples` randomly chosen sources: a Dijkstra from the source finds the vertex
+    /// farthest away from it, and a second Dijkstra from that vertex finds the farthest vertex again.
+    /// The largest cost found by all sweeps is returned.
+    ///
+    /// The result is only an estimate: it is a lower bound of the diameter, but exact for trees (e.g. paths).
+    /// Unreachable pairs of vertices are ignored. Pass a seeded RNG (e.g. `StdRng::seed_from_u64`) for reproducible results.
+    ///
+    /// Returns `None` if the graph has no vertices or `samples` is 0.
+    pub fn approximate_diameter(
+        &self,
+        samples: usize,
+        mut rng: impl Rng,
+    ) -> Option<<Backend::Edge as WeightedEdge>::WeightType> {
+        let mut vertex_ids = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        let (sources, _) = vertex_ids.partial_shuffle(&mut rng, samples);
+
+        sources
+            .iter()
+            .map(|&source| {
+                let (farthest, _) = self.farthest_from(source);
+                self.farthest_from(farthest).1
+            })
+            .max_by(HeapWeight::heap_cmp)
+    }
+
+    /// Returns the vertex with the highest shortest path cost from `start` together with that cost
+    fn farthest_from(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+    ) -> (
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    ) {
+        let shortest_paths = self.dijkstra(start, None);
+        self.get_all_vertices()
+            .filter_map(|v| Some((v.get_id(), shortest_paths.get_cost(v.get_id())?)))
+            .max_by(|(_, cost_a), (_, cost_b)| cost_a.heap_cmp(cost_b))
+            .expect("The start vertex is always reachable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, HeapWeight},
+        test_utils::MockVertex,
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    fn test_approximate_diameter_of_path_is_exact(#[values(0, 1, 2, 3)] seed: u64) {
+        // 0 - 1 - ... - 9 with the weights 1, 2, ..., 9
+        let graph = ListGraph::<MockVertex, u32, Undirected>::from_vertices_and_edges(
+            (0..10).map(|id| MockVertex { id }).collect(),
+            (0..9).map(|id| (id, id + 1, id as u32 + 1)).collect(),
+        )
+        .unwrap();
+
+        let diameter = graph.approximate_diameter(1, StdRng::seed_from_u64(seed));
+
+        assert_eq!(diameter, Some(45));
+    }
+
+    #[test]
+    fn test_approximate_diameter_is_lower_bound() {
+        // A weighted cycle with a chord
+        let graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..6).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, 1.0),
+                (1, 2, 4.0),
+                (2, 3, 1.5),
+                (3, 4, 2.0),
+                (4, 5, 0.5),
+                (5, 0, 3.0),
+                (1, 4, 2.5),
+            ],
+        )
+        .unwrap();
+        let shortest_paths = graph.floyd_warshall();
+        let exact = (0..6)
+            .flat_map(|from| (0..6).map(move |to| (from, to)))
+            .filter_map(|(from, to)| shortest_paths.get_cost(from, to))
+            .max_by(HeapWeight::heap_cmp)
+            .unwrap();
+
+        let estimate = graph
+            .approximate_diameter(2, StdRng::seed_from_u64(42))
+            .unwrap();
+
+        assert!(estimate <= exact);
+        assert_eq!(
+            graph.approximate_diameter(6, StdRng::seed_from_u64(42)),
+            Some(exact)
+        );
+    }
+
+    #[test]
+    fn test_approximate_diameter_without_vertices() {
+        let graph = ListGraph::<MockVertex, u32, Undirected>::new();
+
+        assert_eq!(
+            graph.approximate_diameter(3, StdRng::seed_from_u64(42)),
+            None
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::{fmt::Debug, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    algorithms::mst::union_find::UnionFind,
+    graph::{GraphBase, WithID},
+    Direction, Graph,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash + Debug,
+{
+    /// Returns whether the graph has an Eulerian circuit (a closed walk using every edge exactly once).
+    ///
+    /// - Undirected: all vertices with edges are connected and every vertex has an even degree.
+    /// - Directed: all vertices with edges are weakly connected and every vertex has the same in- and out-degree.
+    ///
+    /// A graph without edges is considered Eulerian.
+    pub fn is_eulerian(&self) -> bool {
+        self.edges_are_connected() && self.unbalanced_vertices().is_empty()
+    }
+
+    /// Returns whether the graph has an Eulerian trail, but no Eulerian circuit
+    /// (a walk using every edge exactly once, that starts and ends at different vertices).
+    ///
+    /// - Undirected: all vertices with edges are connected and exactly two vertices have an odd degree.
+    /// - Directed: all vertices with edges are weakly connected, exactly one vertex has one more outgoing
+    ///   than incoming edge (the start), exactly one vertex has one more incoming than outgoing edge (the end),
+    ///   and all other vertices are balanced.
+    pub fn is_semi_eulerian(&self) -> bool {
+        let unbalanced = self.unbalanced_vertices();
+        let is_trail = if Backend::Direction::IS_DIRECTED {
+            let mut imbalances = unbalanced
+                .iter()
+                .map(|(_, imbalance)| *imbalance)
+                .collect::<Vec<_>>();
+            imbalances.sort_unstable();
+            imbalances == [-1, 1]
+        } else {
+            unbalanced.len() == 2
+        };
+
+        is_trail && self.edges_are_connected()
+    }
+
+    /// Returns all vertices that prevent an Eulerian circuit together with their imbalance:
+    /// the vertices with an odd degree (undirected, imbalance 1) or with `out-degree - in-degree != 0` (directed).
+    fn unbalanced_vertices(&self) -> Vec<(<Backend::Vertex as WithID>::IDType, isize)> {
+        if !Backend::Direction::IS_DIRECTED {
+            return self
+                .get_all_vertices()
+                .map(|v| v.get_id())
+                .filter(|vid| self.degree(*vid) % 2 == 1)
+                .map(|vid| (vid, 1))
+                .collect();
+        }
+
+        // Computing all in-degrees in a single pass is cheaper than calling `degree` for each vertex
+        let mut imbalances = FxHashMap::<_, isize>::default();
+        for (from, to, _) in self.get_all_edges() {
+            *imbalances.entry(from).or_default() += 1;
+            *imbalances.entry(to).or_default() -= 1;
+        }
+        imbalances
+            .into_iter()
+            .filter(|(_, imbalance)| *imbalance != 0)
+            .collect()
+    }
+
+    /// Returns whether all vertices with at least one edge are in the same (weakly) connected component.
+    fn edges_are_connected(&self) -> bool {
+        let mut union_find = UnionFind::new();
+        for v in self.get_all_vertices() {
+            union_find
+                .make_set(v.get_id())
+                .expect("Vertex IDs must be unique");
+        }
+
+        let mut root = None;
+        for (from, to, _) in self.get_all_edges() {
+            union_find
+                .union(&from, &to)
+                .expect("All edges must connect to existing vertices");
+            root.get_or_insert(from);
+        }
+
+        let Some(root) = root else {
+            return true;
+        };
+        let root = union_find.find(&root).expect("Root must be a vertex");
+
+        self.get_all_edges().all(|(from, _, _)| {
+            union_find
+                .find(&from)
+                .expect("All edges must connect to existing vertices")
+                == root
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{BitMatrixGraph, MatrixGraph},
+        test_utils::{build_graph, MockVertex},
+        Directed, ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::square(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)], true, false)]
+    #[case::path(4, vec![(0, 1), (1, 2), (2, 3)], false, true)]
+    #[case::star(4, vec![(0, 1), (0, 2), (0, 3)], false, false)]
+    #[case::two_triangles(6, vec![(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)], false, false)]
+    #[case::isolated_vertex(5, vec![(0, 1), (1, 2), (2, 3), (3, 0)], true, false)]
+    #[case::no_edges(3, vec![], true, false)]
+    fn test_eulerian_undirected(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] eulerian: bool,
+        #[case] semi_eulerian: bool,
+    ) {
+        let list_graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+        let matrix_graph: MatrixGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(list_graph.is_eulerian(), eulerian);
+        assert_eq!(list_graph.is_semi_eulerian(), semi_eulerian);
+        assert_eq!(matrix_graph.is_eulerian(), eulerian);
+        assert_eq!(matrix_graph.is_semi_eulerian(), semi_eulerian);
+    }
+
+    #[test]
+    fn test_eulerian_undirected_self_loop_keeps_parity() {
+        // A self-loop adds 2 to the degree, so the square stays Eulerian
+        let graph: BitMatrixGraph<MockVertex, Undirected> =
+            build_graph(4, &[(0, 1), (1, 2), (2, 3), (3, 0), (0, 0)]);
+
+        assert!(graph.is_eulerian());
+        assert!(!graph.is_semi_eulerian());
+    }
+
+    #[rstest]
+    #[case::cycle(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)], true, false)]
+    #[case::path(4, vec![(0, 1), (1, 2), (2, 3)], false, true)]
+    #[case::wrong_orientation(4, vec![(0, 1), (2, 1), (2, 3), (3, 0)], false, false)]
+    #[case::two_sources(3, vec![(0, 1), (2, 1)], false, false)]
+    fn test_eulerian_directed(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] eulerian: bool,
+        #[case] semi_eulerian: bool,
+    ) {
+        let graph: ListGraph<MockVertex, (), Directed> = build_graph(n_vertices, &edges);
+
+        assert_eq!(graph.is_eulerian(), eulerian);
+        assert_eq!(graph.is_semi_eulerian(), semi_eulerian);
+    }
+}
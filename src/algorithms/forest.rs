@@ -0,0 +1,95 @@
+use std::{fmt::Debug, hash::Hash};
+
+use crate::{
+    algorithms::mst::union_find::UnionFind,
+    graph::{GraphBase, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash + Debug,
+{
+    /// Returns whether the graph is a forest, i.e. it does not contain any cycle.
+    /// The graph may be disconnected, every connected component is a tree.
+    ///
+    /// Uses a union-find structure: an edge between two vertices that are already in the same set closes a cycle.
+    pub fn is_forest(&self) -> bool {
+        let mut union_find = UnionFind::new();
+        for v in self.get_all_vertices() {
+            union_find
+                .make_set(v.get_id())
+                .expect("Vertex IDs must be unique");
+        }
+
+        self.get_all_edges().all(|(from, to, _)| {
+            union_find
+                .union(&from, &to)
+                .expect("All edges must connect to existing vertices")
+        })
+    }
+
+    /// Returns whether the graph is a tree, i.e. a connected forest.
+    ///
+    /// The empty graph is not considered a tree.
+    pub fn is_tree(&self) -> bool {
+        self.tree_count() == 1
+    }
+
+    /// Returns the number of trees if the graph is a forest (which equals the number of connected components).
+    ///
+    /// Returns 0 if the graph contains a cycle (or has no vertices).
+    pub fn tree_count(&self) -> usize {
+        if !self.is_forest() {
+            return 0;
+        }
+
+        // Every tree with k vertices has exactly k - 1 edges
+        self.vertex_count() - self.edge_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::two_trees(7, vec![(0, 1), (0, 2), (2, 3), (4, 5), (4, 6)], true, 2)]
+    #[case::single_tree(4, vec![(0, 1), (1, 2), (1, 3)], true, 1)]
+    #[case::isolated_vertices(3, vec![], true, 3)]
+    #[case::cycle(7, vec![(0, 1), (0, 2), (2, 3), (3, 0), (4, 5), (4, 6)], false, 0)]
+    fn test_forest(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected_forest: bool,
+        #[case] expected_tree_count: usize,
+    ) {
+        let list_graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+        let matrix_graph: MatrixGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(list_graph.is_forest(), expected_forest);
+        assert_eq!(matrix_graph.is_forest(), expected_forest);
+        assert_eq!(list_graph.tree_count(), expected_tree_count);
+        assert_eq!(matrix_graph.tree_count(), expected_tree_count);
+        assert_eq!(list_graph.is_tree(), expected_tree_count == 1);
+    }
+
+    #[test]
+    fn test_adding_cycle_breaks_forest() {
+        let mut graph: ListGraph<MockVertex, (), Undirected> =
+            build_graph(5, &[(0, 1), (1, 2), (3, 4)]);
+        assert!(graph.is_forest());
+        assert_eq!(graph.tree_count(), 2);
+
+        graph.push_edge(2, 0, ()).unwrap();
+        assert!(!graph.is_forest());
+        assert_eq!(graph.tree_count(), 0);
+    }
+}
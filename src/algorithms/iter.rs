@@ -1,11 +1,13 @@
 use crate::{
     algorithms::{
         bfs_iter::{BfsIter, BfsIterMut},
-        dfs_iter::DfsIter,
+        dfs_iter::{DfsIter, DfsIterMut},
     },
     graph::{GraphBase, WithID},
     Graph, GraphError,
 };
+use rustc_hash::FxHashSet;
+use std::collections::VecDeque;
 use std::fmt::Display;
 use std::hash::Hash;
 
@@ -37,6 +39,19 @@ where
     DFS(DfsIter<'a, Backend>),
 }
 
+impl<Backend> Clone for GraphIter<'_, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::BFS(iter) => Self::BFS(iter.clone()),
+            Self::DFS(iter) => Self::DFS(iter.clone()),
+        }
+    }
+}
+
 impl<'a, Backend> Iterator for GraphIter<'a, Backend>
 where
     Backend: GraphBase,
@@ -70,6 +85,75 @@ where
             TraversalType::DFS => Ok(GraphIter::DFS(self.dfs_iter(start_vertex)?)),
         }
     }
+
+    /// Folds all vertices reachable from `start_vertex` in breadth-first order into a single value,
+    /// without collecting them first.
+    pub fn fold_bfs<B, F>(
+        &self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+        init: B,
+        f: F,
+    ) -> Result<B, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        F: FnMut(B, &Backend::Vertex) -> B,
+    {
+        Ok(self.bfs_iter(start_vertex)?.fold(init, f))
+    }
+
+    /// Folds all vertices reachable from `start_vertex` in depth-first order into a single value,
+    /// without collecting them first.
+    pub fn fold_dfs<B, F>(
+        &self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+        init: B,
+        f: F,
+    ) -> Result<B, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        F: FnMut(B, &Backend::Vertex) -> B,
+    {
+        Ok(self.dfs_iter(start_vertex)?.fold(init, f))
+    }
+
+    /// Returns the edges of the traversal tree from `start_vertex` in the order they are discovered.
+    ///
+    /// An edge `(from, to, edge)` is emitted when `to` is first discovered as a neighbor of `from`,
+    /// using the same rules as [`Graph::iter`] (vertices are marked as visited when they are queued or pushed).
+    /// So every reachable vertex except the start is the target of exactly one emitted edge.
+    #[allow(clippy::type_complexity)]
+    pub fn traversal_edges(
+        &self,
+        start_vertex: <Backend::Vertex as WithID>::IDType,
+        traversal: TraversalType,
+    ) -> Result<
+        Vec<(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+            &Backend::Edge,
+        )>,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        self.get_vertex_by_id(start_vertex)
+            .ok_or(GraphError::VertexNotFound(start_vertex))?;
+
+        let mut edges = Vec::new();
+        let mut visited = FxHashSet::from_iter([start_vertex]);
+        let mut pending = VecDeque::from([start_vertex]);
+
+        while let Some(current) = match traversal {
+            TraversalType::BFS => pending.pop_front(),
+            TraversalType::DFS => pending.pop_back(),
+        } {
+            for (neighbor, edge) in self.get_adjacent_vertices_with_edges(current) {
+                let neighbor_id = neighbor.get_id();
+                if visited.insert(neighbor_id) {
+                    edges.push((current, neighbor_id, edge));
+                    pending.push_back(neighbor_id);
+                }
+            }
+        }
+
+        Ok(edges)
+    }
 }
 
 /// A wrapper enum around different graph iterator implementations
@@ -78,6 +162,7 @@ where
     Backend: GraphBase,
 {
     BFS(BfsIterMut<'a, Backend>),
+    DFS(DfsIterMut<'a, Backend>),
 }
 
 impl<'a, Backend> Iterator for GraphIterMut<'a, Backend>
@@ -91,6 +176,7 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             Self::BFS(iter) => iter.next(),
+            Self::DFS(iter) => iter.next(),
         }
     }
 }
@@ -109,7 +195,7 @@ where
     ) -> Result<GraphIterMut<'_, Backend>, GraphError<<Backend::Vertex as WithID>::IDType>> {
         match iter_type {
             TraversalType::BFS => Ok(GraphIterMut::BFS(self.bfs_iter_mut(start_vertex)?)),
-            TraversalType::DFS => todo!(),
+            TraversalType::DFS => Ok(GraphIterMut::DFS(self.dfs_iter_mut(start_vertex)?)),
         }
     }
 }
@@ -120,7 +206,7 @@ mod tests {
 
     use crate::{
         algorithms::iter::TraversalType,
-        graph::{Directed, GraphBase, ListGraph, WithID},
+        graph::{Directed, GraphBase, ListGraph, Undirected, WithID},
         Graph, GraphError,
     };
     use std::collections::HashSet;
@@ -144,6 +230,26 @@ mod tests {
         weight: usize,
     }
 
+    /// Builds a graph with the vertices `0..n_vertices` (their ID as value) and the given edges with weight 1
+    fn build_graph<Backend>(n_vertices: usize, edges: &[(usize, usize)]) -> Graph<Backend>
+    where
+        Backend: GraphBase<Vertex = TestVertex, Edge = TestEdge>,
+    {
+        Graph::from_vertices_and_edges(
+            (0..n_vertices)
+                .map(|id| TestVertex {
+                    id,
+                    value: id.to_string(),
+                })
+                .collect(),
+            edges
+                .iter()
+                .map(|(from, to)| (*from, *to, TestEdge { weight: 1 }))
+                .collect(),
+        )
+        .unwrap()
+    }
+
     #[fixture]
     fn create_test_graph() -> ListGraph<TestVertex, TestEdge, Directed> {
         // Create a graph with the following structure:
@@ -235,6 +341,73 @@ mod tests {
         assert_eq!(visited_ids, expected_from_2);
     }
 
+    #[rstest]
+    fn test_iter_clone_forks_traversal(
+        create_test_graph: ListGraph<TestVertex, TestEdge, Directed>,
+        #[values(TraversalType::BFS, TraversalType::DFS)] traversal_type: TraversalType,
+    ) {
+        let graph = create_test_graph;
+
+        let mut iter = graph.iter(0, traversal_type).unwrap();
+        let consumed: Vec<usize> = iter.by_ref().take(3).map(|v| v.get_id()).collect();
+        assert_eq!(consumed.len(), 3);
+
+        // Both copies continue from the same state, independently of each other
+        let forked = iter.clone();
+        let remaining: Vec<usize> = iter.map(|v| v.get_id()).collect();
+        let remaining_forked: Vec<usize> = forked.map(|v| v.get_id()).collect();
+
+        assert_eq!(remaining, remaining_forked);
+        assert_eq!(consumed.len() + remaining.len(), 6);
+    }
+
+    #[rstest]
+    fn test_dfs_postorder(create_test_graph: ListGraph<TestVertex, TestEdge, Directed>) {
+        let graph = create_test_graph;
+
+        let finish_order: Vec<usize> = graph
+            .dfs_postorder_iter(0)
+            .unwrap()
+            .map(|v| v.get_id())
+            .collect();
+        assert_eq!(finish_order, vec![1, 5, 3, 4, 2, 0]);
+
+        let finish_order: Vec<usize> = graph
+            .dfs_postorder_iter(2)
+            .unwrap()
+            .map(|v| v.get_id())
+            .collect();
+        assert_eq!(finish_order, vec![5, 3, 4, 2]);
+
+        assert!(matches!(
+            graph.dfs_postorder_iter(42),
+            Err(GraphError::VertexNotFound(42))
+        ));
+    }
+
+    #[rstest]
+    fn test_fold_traversal(create_test_graph: ListGraph<TestVertex, TestEdge, Directed>) {
+        let graph = create_test_graph;
+
+        for start in [0, 2] {
+            let bfs_len = graph.bfs_iter(start).unwrap().count();
+            let bfs_count = graph.fold_bfs(start, 0, |count, _| count + 1).unwrap();
+            let dfs_count = graph.fold_dfs(start, 0, |count, _| count + 1).unwrap();
+            assert_eq!(bfs_count, bfs_len);
+            assert_eq!(dfs_count, bfs_len);
+        }
+
+        let values = graph
+            .fold_bfs(0, String::new(), |acc, v| acc + &v.value)
+            .unwrap();
+        assert_eq!(values.len(), 6);
+
+        assert!(matches!(
+            graph.fold_dfs(42, 0, |count, _| count + 1),
+            Err(GraphError::VertexNotFound(42))
+        ));
+    }
+
     #[rstest]
     fn test_iter_traversal_subset(
         create_test_graph: ListGraph<TestVertex, TestEdge, Directed>,
@@ -252,18 +425,14 @@ mod tests {
     #[rstest]
     fn test_iter_mut_traversal(
         create_test_graph: ListGraph<TestVertex, TestEdge, Directed>,
-        #[values(
-            TraversalType::BFS, 
-            // TraversalType::DFS // Not yet implemented
-        )]
-        traversal_type: TraversalType,
+        #[values(TraversalType::BFS, TraversalType::DFS)] traversal_type: TraversalType,
     ) {
         let mut graph = create_test_graph;
 
-        // Use mutable BFS to modify vertex values
+        // Use the mutable iterator to modify vertex values
         {
-            let bfs_mut = graph.iter_mut(0, traversal_type).unwrap();
-            for vertex in bfs_mut {
+            let iter_mut = graph.iter_mut(0, traversal_type).unwrap();
+            for vertex in iter_mut {
                 vertex.value = format!("Modified_{}", vertex.value);
             }
         }
@@ -355,4 +524,216 @@ mod tests {
         assert!(visited.contains(&1));
         assert!(visited.contains(&2));
     }
+
+    #[rstest]
+    fn test_iter_dense_graph_yields_each_vertex_once(
+        #[values(TraversalType::BFS, TraversalType::DFS)] traversal_type: TraversalType,
+    ) {
+        let n = 8;
+        // Complete directed graph: every vertex is reachable from every other vertex many times
+        let edges = (0..n)
+            .flat_map(|from| {
+                (0..n)
+                    .filter(move |to| *to != from)
+                    .map(move |to| (from, to))
+            })
+            .collect::<Vec<_>>();
+        let graph: ListGraph<TestVertex, TestEdge, Directed> = build_graph(n, &edges);
+
+        for start in 0..n {
+            let visited: Vec<usize> = graph
+                .iter(start, traversal_type)
+                .unwrap()
+                .map(|v| v.get_id())
+                .collect();
+
+            assert_eq!(visited.len(), n);
+            assert_eq!(visited[0], start);
+            assert_eq!(visited.iter().collect::<HashSet<_>>().len(), n);
+        }
+    }
+
+    #[rstest]
+    fn test_iter_mut_dense_graph_modifies_each_vertex_once(
+        #[values(TraversalType::BFS, TraversalType::DFS)] traversal_type: TraversalType,
+    ) {
+        let n = 8;
+        let edges = (0..n)
+            .flat_map(|from| {
+                (0..n)
+                    .filter(move |to| *to != from)
+                    .map(move |to| (from, to))
+            })
+            .collect::<Vec<_>>();
+        // The vertex `n` is not reachable from the start vertex
+        let mut graph: ListGraph<TestVertex, TestEdge, Directed> = build_graph(n + 1, &edges);
+
+        for vertex in graph.iter_mut(3, traversal_type).unwrap() {
+            vertex.value.push('x');
+        }
+
+        for id in 0..n {
+            assert_eq!(graph.get_vertex_by_id(id).unwrap().value, format!("{id}x"));
+        }
+        assert_eq!(graph.get_vertex_by_id(n).unwrap().value, n.to_string());
+    }
+
+    #[test]
+    fn test_dfs_iter_with_order_is_stable() {
+        // Same cyclic graph (0 -> 1 -> 2 -> 0, 0 -> 3 -> 2, 1 -> 3), with edges inserted in different orders
+        let edges = [(0, 1), (1, 2), (2, 0), (0, 3), (3, 2), (1, 3)];
+        let graph: ListGraph<TestVertex, TestEdge, Directed> = build_graph(4, &edges);
+        let reversed_graph: ListGraph<TestVertex, TestEdge, Directed> =
+            build_graph(4, &edges.iter().rev().copied().collect::<Vec<_>>());
+
+        for start in 0..4 {
+            let order: Vec<usize> = graph
+                .dfs_iter_with_order(start)
+                .unwrap()
+                .map(|v| v.get_id())
+                .collect();
+            let reversed_order: Vec<usize> = reversed_graph
+                .dfs_iter_with_order(start)
+                .unwrap()
+                .map(|v| v.get_id())
+                .collect();
+
+            assert_eq!(order, reversed_order);
+            assert_eq!(order.iter().collect::<HashSet<_>>().len(), 4);
+        }
+
+        let order: Vec<usize> = graph
+            .dfs_iter_with_order(0)
+            .unwrap()
+            .map(|v| v.get_id())
+            .collect();
+        assert_eq!(order, vec![0, 1, 2, 3]);
+    }
+
+    #[rstest]
+    #[case(2, 0, true, vec![2])]
+    #[case(2, 1, false, vec![1, 3])]
+    #[case(2, 1, true, vec![1, 2, 3])]
+    #[case(2, 2, false, vec![0, 1, 3, 4])]
+    #[case(0, 10, false, vec![1, 2, 3, 4])]
+    #[case(42, 1, true, vec![])]
+    fn test_neighbors_within(
+        #[case] start: usize,
+        #[case] max_hops: usize,
+        #[case] include_start: bool,
+        #[case] expected: Vec<usize>,
+    ) {
+        // Path 0 -> 1 -> 2 -> 3 -> 4 (in both directions)
+        let edges = (0..4)
+            .flat_map(|id| [(id, id + 1), (id + 1, id)])
+            .collect::<Vec<_>>();
+        let graph: ListGraph<TestVertex, TestEdge, Directed> = build_graph(5, &edges);
+
+        let neighbors = graph.neighbors_within(start, max_hops, include_start);
+
+        assert_eq!(
+            neighbors.into_iter().collect::<HashSet<_>>(),
+            expected.into_iter().collect::<HashSet<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case(0, 1, true)]
+    #[case(1, 0, false)]
+    #[case(0, 3, true)]
+    #[case(3, 0, false)]
+    #[case(0, 4, false)]
+    #[case(4, 4, true)]
+    #[case(0, 42, false)]
+    #[case(42, 42, false)]
+    fn test_has_path_respects_direction(
+        #[case] from: usize,
+        #[case] to: usize,
+        #[case] expected: bool,
+    ) {
+        // 0 -> 1 -> 2 -> 3 and the isolated vertex 4
+        let graph: ListGraph<TestVertex, TestEdge, Directed> =
+            build_graph(5, &[(0, 1), (1, 2), (2, 3)]);
+
+        assert_eq!(graph.has_path(from, to), expected);
+    }
+
+    #[test]
+    fn test_reverse_bfs_iter_follows_incoming_edges() {
+        // 0 -> 1 -> 2, 3 -> 1 and 2 -> 4
+        let graph: ListGraph<TestVertex, TestEdge, Directed> =
+            build_graph(5, &[(0, 1), (1, 2), (3, 1), (2, 4)]);
+
+        let order = graph
+            .reverse_bfs_iter(2)
+            .unwrap()
+            .map(|v| v.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(order[..2], [2, 1]);
+        assert_eq!(
+            order[2..].iter().copied().collect::<HashSet<_>>(),
+            HashSet::from([0, 3])
+        );
+        let from_start = graph
+            .reverse_bfs_iter(0)
+            .unwrap()
+            .map(|v| v.id)
+            .collect::<Vec<_>>();
+        assert_eq!(from_start, vec![0]);
+        assert!(matches!(
+            graph.reverse_bfs_iter(42),
+            Err(GraphError::VertexNotFound(42))
+        ));
+    }
+
+    #[test]
+    fn test_bfs_multi_source_uses_closest_source() {
+        // The path 0 - 1 - 2 - 3 - 4 - 5 - 6 with sources at both ends
+        let edges = (0..6).map(|id| (id, id + 1)).collect::<Vec<_>>();
+        let graph: ListGraph<TestVertex, TestEdge, Undirected> = build_graph(7, &edges);
+
+        let distances = graph.bfs_multi_source(&[0, 6]);
+
+        assert_eq!(distances.len(), 7);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&2], 2);
+        assert_eq!(distances[&3], 3);
+        assert_eq!(distances[&5], 1);
+        assert_eq!(distances[&6], 0);
+
+        // Moving a source closer shortens the distance of the middle vertex
+        let distances = graph.bfs_multi_source(&[0, 4, 42]);
+        assert_eq!(distances[&3], 1);
+        assert_eq!(distances[&6], 2);
+        assert!(graph.bfs_multi_source(&[]).is_empty());
+    }
+
+    #[rstest]
+    #[case(TraversalType::BFS)]
+    #[case(TraversalType::DFS)]
+    fn test_traversal_edges(
+        create_test_graph: ListGraph<TestVertex, TestEdge, Directed>,
+        #[case] traversal: TraversalType,
+    ) {
+        let edges = create_test_graph
+            .traversal_edges(0, traversal)
+            .unwrap()
+            .into_iter()
+            .map(|(from, to, edge)| (from, to, edge.weight))
+            .collect::<Vec<_>>();
+
+        // The graph is a tree, so both traversals discover it in the same order
+        assert_eq!(
+            edges,
+            vec![(0, 1, 1), (0, 2, 2), (2, 3, 3), (2, 4, 4), (3, 5, 5)]
+        );
+
+        let from_subtree = create_test_graph.traversal_edges(2, traversal).unwrap();
+        assert_eq!(from_subtree.len(), 3);
+        assert!(matches!(
+            create_test_graph.traversal_edges(42, traversal),
+            Err(GraphError::VertexNotFound(42))
+        ));
+    }
 }
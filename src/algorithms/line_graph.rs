@@ -0,0 +1,121 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, GraphError, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Builds the line graph L(G) of this graph.
+    ///
+    /// Every edge `(from, to)` of the graph becomes a vertex of the line graph, created by `vertex_builder`.
+    /// Two of these vertices are connected iff the original edges share an endpoint.
+    /// The edges are passed to `vertex_builder` with `from <= to` and in ascending order.
+    ///
+    /// # Errors
+    /// Returns the errors of building the output graph,
+    /// e.g. `GraphError::DuplicateVertex` if `vertex_builder` creates the same ID for two edges.
+    #[allow(clippy::type_complexity)]
+    pub fn line_graph<F, OutputBackend>(
+        &self,
+        vertex_builder: F,
+    ) -> Result<Graph<OutputBackend>, GraphError<<OutputBackend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(
+            (
+                <Backend::Vertex as WithID>::IDType,
+                <Backend::Vertex as WithID>::IDType,
+            ),
+        ) -> OutputBackend::Vertex,
+        OutputBackend: GraphBase<Edge = (), Direction = Undirected>,
+        <OutputBackend::Vertex as WithID>::IDType: Copy,
+    {
+        let mut edges = self
+            .get_all_edges()
+            .map(|(from, to, _)| (from.min(to), from.max(to)))
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+
+        let mut line_graph = Graph::<OutputBackend>::new();
+        // The new vertex IDs of all edges incident to each original vertex
+        let mut incident_edges = FxHashMap::<_, Vec<_>>::default();
+        for (from, to) in edges {
+            let vertex = vertex_builder((from, to));
+            let new_id = vertex.get_id();
+            line_graph.push_vertex(vertex)?;
+
+            incident_edges.entry(from).or_default().push(new_id);
+            if from != to {
+                incident_edges.entry(to).or_default().push(new_id);
+            }
+        }
+
+        // All edges incident to the same vertex are pairwise adjacent in the line graph
+        let mut vertices = incident_edges.keys().copied().collect::<Vec<_>>();
+        vertices.sort_unstable();
+        for vertex in vertices {
+            let incident = &incident_edges[&vertex];
+            for (i, &first) in incident.iter().enumerate() {
+                for &second in &incident[i + 1..] {
+                    line_graph.push_edge(first, second, ())?;
+                }
+            }
+        }
+
+        Ok(line_graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graph::{GraphBase, WithID},
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct EdgeVertex {
+        endpoints: (usize, usize),
+    }
+
+    impl WithID for EdgeVertex {
+        type IDType = (usize, usize);
+
+        fn get_id(&self) -> (usize, usize) {
+            self.endpoints
+        }
+    }
+
+    #[test]
+    fn test_line_graph_of_path() {
+        let graph: ListGraph<MockVertex, (), Undirected> = build_graph(3, &[(1, 0), (1, 2)]);
+
+        let line_graph: ListGraph<EdgeVertex, (), Undirected> = graph
+            .line_graph(|endpoints| EdgeVertex { endpoints })
+            .unwrap();
+
+        assert_eq!(line_graph.vertex_count(), 2);
+        assert_eq!(line_graph.edge_count(), 1);
+        assert!(line_graph.contains_edge((0, 1), (1, 2)));
+    }
+
+    #[test]
+    fn test_line_graph_of_star_is_complete() {
+        let graph: ListGraph<MockVertex, (), Undirected> =
+            build_graph(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+
+        let line_graph: ListGraph<EdgeVertex, (), Undirected> = graph
+            .line_graph(|endpoints| EdgeVertex { endpoints })
+            .unwrap();
+
+        assert_eq!(line_graph.vertex_count(), 4);
+        assert_eq!(line_graph.edge_count(), 6);
+    }
+}
@@ -0,0 +1,134 @@
+use std::hash::Hash;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Enumerates all maximal cliques using the Bron-Kerbosch algorithm with pivoting.
+    ///
+    /// Every returned clique is sorted by vertex ID and the cliques are sorted as well.
+    /// Isolated vertices form a maximal clique of size 1.
+    ///
+    /// # Warning
+    /// A graph can have exponentially many maximal cliques (up to 3^(n/3)), so the worst case runtime is exponential.
+    /// For large sparse graphs, processing the vertices in [`degeneracy_ordering`](Self::degeneracy_ordering)
+    /// at the outermost level bounds the runtime by the degeneracy instead of the number of vertices.
+    pub fn maximal_cliques(&self) -> Vec<Vec<<Backend::Vertex as WithID>::IDType>> {
+        let neighbors = self
+            .get_all_vertices()
+            .map(|v| {
+                let vid = v.get_id();
+                let adjacent = self
+                    .get_adjacent_vertices(vid)
+                    .map(|n| n.get_id())
+                    .filter(|n| *n != vid)
+                    .collect::<FxHashSet<_>>();
+                (vid, adjacent)
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut cliques = Vec::new();
+        bron_kerbosch(
+            &neighbors,
+            &mut Vec::new(),
+            neighbors.keys().copied().collect(),
+            FxHashSet::default(),
+            &mut cliques,
+        );
+
+        for clique in &mut cliques {
+            clique.sort_unstable();
+        }
+        cliques.sort_unstable();
+        cliques
+    }
+}
+
+/// Recursive Bron-Kerbosch step.
+///
+/// - `clique`: the vertices of the clique that is currently being built (R)
+/// - `candidates`: vertices that can extend the clique (P)
+/// - `excluded`: vertices that were already processed and would lead to duplicate cliques (X)
+fn bron_kerbosch<VId>(
+    neighbors: &FxHashMap<VId, FxHashSet<VId>>,
+    clique: &mut Vec<VId>,
+    mut candidates: FxHashSet<VId>,
+    mut excluded: FxHashSet<VId>,
+    cliques: &mut Vec<Vec<VId>>,
+) where
+    VId: Copy + Eq + Hash,
+{
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(clique.clone());
+        }
+        return;
+    }
+
+    // Choose the pivot with the most neighbors in P, only its non-neighbors have to be branched on
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .max_by_key(|v| neighbors[v].intersection(&candidates).count())
+        .copied()
+        .expect("candidates are not empty");
+    let branch_vertices = candidates
+        .difference(&neighbors[&pivot])
+        .copied()
+        .collect::<Vec<_>>();
+
+    for v in branch_vertices {
+        let v_neighbors = &neighbors[&v];
+        clique.push(v);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates.intersection(v_neighbors).copied().collect(),
+            excluded.intersection(v_neighbors).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+
+        candidates.remove(&v);
+        excluded.insert(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::MatrixGraph,
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], vec![vec![0, 1, 2, 3]])]
+    #[case::path(4, vec![(0, 1), (1, 2), (2, 3)], vec![vec![0, 1], vec![1, 2], vec![2, 3]])]
+    #[case::triangle_with_tail_and_isolated(
+        5,
+        vec![(0, 1), (1, 2), (2, 0), (2, 3)],
+        vec![vec![0, 1, 2], vec![2, 3], vec![4]]
+    )]
+    fn test_maximal_cliques(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: Vec<Vec<usize>>,
+    ) {
+        let list_graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+        let matrix_graph: MatrixGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(list_graph.maximal_cliques(), expected);
+        assert_eq!(matrix_graph.maximal_cliques(), expected);
+    }
+}
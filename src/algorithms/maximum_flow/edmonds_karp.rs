@@ -27,6 +27,8 @@ where
     /// Edmonds-Karp-Algorithm
     ///
     /// Returns ...
+    ///
+    /// If `start` and `target` are the same vertex, the max flow is zero and no flow is changed.
     pub fn edmonds_karp<ResBackend, Flow, FlowFn, MaxFlowFn>(
         &mut self,
         start: <Backend::Vertex as WithID>::IDType,
@@ -42,10 +44,101 @@ where
         Flow: Default + Copy + PartialEq + PartialOrd + Sub<Output = Flow> + Add<Output = Flow>,
     {
         if start == target {
-            return Err(GraphError::AlgorithmError(
-                "Start vertex and target vertex must be different".to_string(),
-            ));
+            self.get_vertex_by_id(start)
+                .ok_or(GraphError::VertexNotFound(start))?;
+            return Ok(());
+        }
+
+        let residual_graph =
+            self.residual_max_flow::<ResBackend, _, _>(start, target, &max_flow)?;
+
+        // Apply flows found in residual graph to the main graph
+        for (from, to, edge) in residual_graph
+            .get_all_edges()
+            .filter(|(_from, _to, edge)| !edge.is_residual)
+        {
+            let edge_to_modify = self
+                .get_edge_mut(from, to)
+                .expect("Edge must also exist in original graph");
+
+            *flow(edge_to_modify) = *max_flow(edge_to_modify) - edge.flow;
         }
+
+        Ok(())
+    }
+
+    /// Edmonds-Karp-Algorithm without modifying the graph
+    ///
+    /// Works like [`Graph::edmonds_karp`], but returns the flow of every edge `(from, to)` in a map instead of
+    /// writing it to the edges, so it can be used on read-only graphs.
+    ///
+    /// If `start` and `target` are the same vertex, the max flow is zero and every edge is mapped to a zero flow.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: if `start` and `target` are the same vertex, but it does not exist.
+    /// - Errors of building the residual graph.
+    #[allow(clippy::type_complexity)]
+    pub fn edmonds_karp_with_flows<ResBackend, Flow, MaxFlowFn>(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        target: <Backend::Vertex as WithID>::IDType,
+        max_flow: MaxFlowFn,
+    ) -> Result<
+        FxHashMap<
+            (
+                <Backend::Vertex as WithID>::IDType,
+                <Backend::Vertex as WithID>::IDType,
+            ),
+            Flow,
+        >,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    >
+    where
+        ResBackend:
+            GraphBase<Vertex = Backend::Vertex, Edge = ResidualEdge<Flow>, Direction = Directed>,
+        MaxFlowFn: Fn(&Backend::Edge) -> &Flow,
+        Flow: Default + Copy + PartialEq + PartialOrd + Sub<Output = Flow> + Add<Output = Flow>,
+    {
+        if start == target {
+            self.get_vertex_by_id(start)
+                .ok_or(GraphError::VertexNotFound(start))?;
+            return Ok(self
+                .get_all_edges()
+                .map(|(from, to, _)| ((from, to), Flow::default()))
+                .collect());
+        }
+
+        let residual_graph =
+            self.residual_max_flow::<ResBackend, _, _>(start, target, &max_flow)?;
+
+        Ok(residual_graph
+            .get_all_edges()
+            .filter(|(_from, _to, edge)| !edge.is_residual)
+            .map(|(from, to, edge)| {
+                let original_edge = self
+                    .get_edge(from, to)
+                    .expect("Edge must also exist in original graph");
+                ((from, to), *max_flow(original_edge) - edge.flow)
+            })
+            .collect())
+    }
+
+    /// Runs Edmonds-Karp on a residual copy of the graph and returns the final residual graph.
+    ///
+    /// The callers handle `start == target` themselves, as there is no path to augment.
+    fn residual_max_flow<ResBackend, Flow, MaxFlowFn>(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        target: <Backend::Vertex as WithID>::IDType,
+        max_flow: &MaxFlowFn,
+    ) -> Result<Graph<ResBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        ResBackend:
+            GraphBase<Vertex = Backend::Vertex, Edge = ResidualEdge<Flow>, Direction = Directed>,
+        MaxFlowFn: Fn(&Backend::Edge) -> &Flow,
+        Flow: Default + Copy + PartialEq + PartialOrd + Sub<Output = Flow> + Add<Output = Flow>,
+    {
+        debug_assert!(start != target, "Start and target must be different");
         // 1. Starte mit Fluss f(u, v) = 0 ∀(u, v) ∈ E
         // Set all flow values to 0
         // self.get_all_edges_mut()
@@ -135,19 +228,7 @@ where
             // 5. Springe zurück zu Schritt 2
         }
 
-        // Apply flows found in residual graph to the main graph
-        for (from, to, edge) in residual_graph
-            .get_all_edges()
-            .filter(|(_from, _to, edge)| !edge.is_residual)
-        {
-            let edge_to_modify = self
-                .get_edge_mut(from, to)
-                .expect("Edge must also exist in original graph");
-
-            *flow(edge_to_modify) = *max_flow(edge_to_modify) - edge.flow;
-        }
-
-        Ok(())
+        Ok(residual_graph)
     }
 
     /// Find an shortest path (in terms of edge count) from start to target using BFS
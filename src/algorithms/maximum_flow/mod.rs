@@ -1 +1,2 @@
 pub mod edmonds_karp;
+pub mod vertex_connectivity;
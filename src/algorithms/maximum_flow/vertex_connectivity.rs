@@ -0,0 +1,128 @@
+use std::hash::Hash;
+
+use crate::{
+    graph::{GraphBase, ListGraphBackend, Vertex, VertexIDType, WithID},
+    Directed, Graph, ListGraph, Undirected,
+};
+
+/// Edge of the split graph used to compute the vertex connectivity
+#[derive(Debug, Clone)]
+struct UnitFlowEdge {
+    capacity: u32,
+    flow: u32,
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Returns the vertex connectivity of the graph: the minimum number of vertices that have to be removed
+    /// to disconnect it (or to leave a single vertex).
+    ///
+    /// A complete graph with n vertices has a connectivity of n - 1, a disconnected graph a connectivity of 0.
+    /// Otherwise, the connectivity is the minimum over all pairs of non-adjacent vertices `(s, t)` of the number of
+    /// vertex-disjoint `s`-`t` paths. That number is the maximum flow between `s` and `t` in a split graph,
+    /// where every vertex `v` becomes an edge `v_in -> v_out` with capacity 1.
+    ///
+    /// Runs one max-flow computation (Edmonds-Karp) per non-adjacent pair, so it is only meant for small graphs.
+    pub fn vertex_connectivity(&self) -> usize {
+        let vertices = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        let n = vertices.len();
+        if n <= 1 {
+            return 0;
+        }
+
+        // Vertex `i` is split into `2 * i` (in) and `2 * i + 1` (out)
+        let split_vertices = (0..2 * n)
+            .map(|id| Vertex {
+                id: id as VertexIDType,
+            })
+            .collect::<Vec<_>>();
+        let mut split_edges = (0..n).map(|i| (2 * i, 2 * i + 1)).collect::<Vec<_>>();
+        let mut non_adjacent_pairs = Vec::new();
+        for (i, from) in vertices.iter().enumerate() {
+            for (j, to) in vertices.iter().enumerate().skip(i + 1) {
+                if self.get_edge(*from, *to).is_some() {
+                    split_edges.push((2 * i + 1, 2 * j));
+                    split_edges.push((2 * j + 1, 2 * i));
+                } else {
+                    non_adjacent_pairs.push((i, j));
+                }
+            }
+        }
+
+        non_adjacent_pairs
+            .into_iter()
+            .map(|(s, t)| {
+                let mut split_graph =
+                    ListGraph::<Vertex, UnitFlowEdge, Directed>::from_vertices_and_edges(
+                        split_vertices.clone(),
+                        split_edges
+                            .iter()
+                            .map(|(from, to)| {
+                                (
+                                    *from as VertexIDType,
+                                    *to as VertexIDType,
+                                    UnitFlowEdge {
+                                        capacity: 1,
+                                        flow: 0,
+                                    },
+                                )
+                            })
+                            .collect(),
+                    )
+                    .expect("The split graph only contains valid edges");
+
+                let source = (2 * s + 1) as VertexIDType;
+                split_graph
+                    .edmonds_karp::<ListGraphBackend<_, _, Directed>, _, _, _>(
+                        source,
+                        (2 * t) as VertexIDType,
+                        |e| &mut e.flow,
+                        |e| &e.capacity,
+                    )
+                    .expect("Source and target of the split graph are different vertices");
+
+                split_graph
+                    .get_adjacent_vertices_with_edges(source)
+                    .map(|(_, edge)| edge.flow as usize)
+                    .sum::<usize>()
+            })
+            .min()
+            // Every pair is adjacent
+            .unwrap_or(n - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::cycle_4(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)], 2)]
+    #[case::cycle_6(6, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)], 2)]
+    #[case::path(4, vec![(0, 1), (1, 2), (2, 3)], 1)]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], 3)]
+    #[case::k4_minus_edge(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3)], 2)]
+    #[case::two_triangles_sharing_vertex(5, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)], 1)]
+    #[case::disconnected(4, vec![(0, 1), (2, 3)], 0)]
+    #[case::single_vertex(1, vec![], 0)]
+    fn test_vertex_connectivity(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: usize,
+    ) {
+        let graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(graph.vertex_connectivity(), expected);
+    }
+}
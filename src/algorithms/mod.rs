@@ -1,9 +1,32 @@
+pub mod all_simple_paths;
+pub mod betweenness;
 pub mod bfs_iter;
+pub mod bipartite;
+pub mod bridges;
+pub mod centrality;
+pub mod community;
+pub mod components;
 pub mod count_connected_subgraphs;
+pub mod count_triangles;
+pub mod dag;
+pub mod degeneracy;
 pub mod dfs_iter;
+pub mod diameter;
+pub mod eulerian;
+pub mod forest;
 pub mod iter;
+pub mod line_graph;
+pub mod maximal_cliques;
 pub mod maximum_flow;
 pub mod mst;
+pub mod planarity;
+pub mod prufer;
+pub mod quotient;
+pub mod random_spanning_tree;
+pub mod random_walk;
 pub mod shortest_path;
+pub mod spectral;
+pub mod stats;
+pub mod steiner_tree;
 pub mod tsp;
 mod utils;
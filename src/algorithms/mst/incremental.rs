@@ -0,0 +1,123 @@
+use std::collections::hash_map::Entry::Vacant;
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WeightedEdge, WithID},
+    Graph, GraphError, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+{
+    /// Updates `mst` after the edge `(from, to)` was inserted into this graph.
+    ///
+    /// Uses the cycle property: if `from` and `to` are already connected in the MST, the new edge closes a cycle
+    /// and replaces the heaviest edge on the MST path between them, if it is cheaper.
+    /// If they are not connected yet (the MST is a spanning forest), the edge is simply added.
+    ///
+    /// `mst` must be a minimum spanning tree (or forest) of this graph, containing all its vertices.
+    ///
+    /// Returns whether the MST was changed.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: if `from` or `to` is not a vertex of this graph.
+    pub fn mst_add_edge<OutputBackend>(
+        &self,
+        mst: &mut Graph<OutputBackend>,
+        from: <Backend::Vertex as WithID>::IDType,
+        to: <Backend::Vertex as WithID>::IDType,
+        edge: Backend::Edge,
+    ) -> Result<bool, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend:
+            GraphBase<Vertex = Backend::Vertex, Edge = Backend::Edge, Direction = Undirected>,
+    {
+        for vertex_id in [from, to] {
+            self.get_vertex_by_id(vertex_id)
+                .ok_or(GraphError::VertexNotFound(vertex_id))?;
+        }
+
+        // A self-loop is never part of a spanning tree
+        if from == to {
+            return Ok(false);
+        }
+
+        let Some(path) = Self::mst_path(mst, from, to) else {
+            // Different trees of the spanning forest -> the edge connects them
+            mst.push_edge(from, to, edge)?;
+            return Ok(true);
+        };
+
+        // Find the heaviest edge on the cycle closed by the new edge
+        let (heaviest_from, heaviest_to, heaviest_weight) = path
+            .windows(2)
+            .map(|pair| {
+                let weight = mst
+                    .get_edge(pair[0], pair[1])
+                    .expect("Consecutive path vertices must be connected in the MST")
+                    .get_weight();
+                (pair[0], pair[1], weight)
+            })
+            .reduce(|heaviest, current| {
+                if current.2 > heaviest.2 {
+                    current
+                } else {
+                    heaviest
+                }
+            })
+            .expect("The path between two different vertices contains at least one edge");
+
+        if edge.get_weight() >= heaviest_weight {
+            return Ok(false);
+        }
+
+        mst.remove_edge(heaviest_from, heaviest_to);
+        mst.push_edge(from, to, edge)?;
+        Ok(true)
+    }
+
+    /// Finds the path from `from` to `to` in the tree `mst` using a BFS.
+    ///
+    /// Returns `None` if `to` is not reachable.
+    fn mst_path<OutputBackend>(
+        mst: &Graph<OutputBackend>,
+        from: <Backend::Vertex as WithID>::IDType,
+        to: <Backend::Vertex as WithID>::IDType,
+    ) -> Option<Vec<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<Vertex = Backend::Vertex>,
+    {
+        let mut predecessors = FxHashMap::default();
+        predecessors.insert(from, from);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                break;
+            }
+            for neighbor in mst.get_adjacent_vertices(current).map(|v| v.get_id()) {
+                if let Vacant(entry) = predecessors.entry(neighbor) {
+                    entry.insert(current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        predecessors.get(&to)?;
+
+        // Walk back from `to` to `from`
+        let mut path = vec![to];
+        let mut current = to;
+        while current != from {
+            current = predecessors[&current];
+            path.push(current);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod incremental;
 pub mod kruskal;
 pub mod prim;
 pub(crate) mod union_find;
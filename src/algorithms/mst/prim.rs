@@ -3,7 +3,7 @@ use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
 use rustc_hash::FxHashSet;
 
 use crate::{
-    graph::{GraphBase, WeightedEdge, WithID},
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
     Graph, GraphError,
 };
 
@@ -13,6 +13,7 @@ where
     Backend::Vertex: Clone,
     <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
     Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: HeapWeight,
 {
     /// Creates an MST using the Prim's algorithm.
     ///
@@ -71,20 +72,20 @@ where
         // Add initial edges from the start vertex to the priority queue
         for (neighbor_vertex, edge) in self.get_adjacent_vertices_with_edges(start_id) {
             let weight = edge.get_weight();
-            edge_pq.push(EdgeEntry::new(
-                // Reverse is used to make BinaryHeap behave as a min-heap based on weight
-                Reverse(weight),
+            // Reverse is used to make BinaryHeap behave as a min-heap based on weight
+            edge_pq.push(Reverse(EdgeEntry::new(
+                weight,
                 start_id,
                 neighbor_vertex.get_id(),
                 edge,
-            ));
+            )));
         }
 
         // Step 2: Loop while the new mst graph does not contain all vertices from the original graph
         while !remaining_vertices.is_empty() {
             //   Step (a): Choose the cheapest edge
             let cheapest = match edge_pq.pop() {
-                Some(entry) => entry,
+                Some(Reverse(entry)) => entry,
                 None => break, // No more reachable vertices
             };
 
@@ -110,12 +111,12 @@ where
                 }
 
                 let next_weight = next_edge.get_weight();
-                edge_pq.push(EdgeEntry::new(
-                    Reverse(next_weight),
+                edge_pq.push(Reverse(EdgeEntry::new(
+                    next_weight,
                     cheapest.to,
                     neighbor_id,
                     next_edge,
-                ));
+                )));
             }
         }
 
@@ -124,14 +125,14 @@ where
 }
 
 // Helper struct for Min-Heap behavior if weights are floats or need custom ordering
-struct EdgeEntry<W: PartialOrd, VId, E> {
+struct EdgeEntry<W: HeapWeight, VId, E> {
     weight: W,
     from: VId,
     to: VId,
     edge: E,
 }
 
-impl<W: PartialOrd, VId, E> EdgeEntry<W, VId, E> {
+impl<W: HeapWeight, VId, E> EdgeEntry<W, VId, E> {
     pub fn new(weight: W, from: VId, to: VId, edge: E) -> Self {
         EdgeEntry {
             weight,
@@ -142,24 +143,22 @@ impl<W: PartialOrd, VId, E> EdgeEntry<W, VId, E> {
     }
 }
 
-impl<W: PartialOrd, VId, E> PartialEq for EdgeEntry<W, VId, E> {
+impl<W: HeapWeight, VId, E> PartialEq for EdgeEntry<W, VId, E> {
     fn eq(&self, other: &Self) -> bool {
-        self.weight == other.weight
+        self.weight.heap_cmp(&other.weight) == std::cmp::Ordering::Equal
     }
 }
 
-impl<W: PartialOrd, VId, E> Eq for EdgeEntry<W, VId, E> {}
+impl<W: HeapWeight, VId, E> Eq for EdgeEntry<W, VId, E> {}
 
-impl<W: PartialOrd, VId, E> PartialOrd for EdgeEntry<W, VId, E> {
+impl<W: HeapWeight, VId, E> PartialOrd for EdgeEntry<W, VId, E> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<W: PartialOrd, VId, E> Ord for EdgeEntry<W, VId, E> {
+impl<W: HeapWeight, VId, E> Ord for EdgeEntry<W, VId, E> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.weight
-            .partial_cmp(&other.weight)
-            .expect("Graph weights must not contain NaN values")
+        self.weight.heap_cmp(&other.weight)
     }
 }
@@ -107,6 +107,60 @@ where
     }
 }
 
+/// Union-find over the dense indices `0..n` with path halving and union by size.
+///
+/// Cheaper than [`UnionFind`] when the elements are already numbered, as no hashing is needed.
+#[derive(Debug)]
+pub struct DenseUnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    set_count: usize,
+}
+
+impl DenseUnionFind {
+    /// Creates `n` singleton sets for the indices `0..n`
+    pub fn new(n: usize) -> Self {
+        DenseUnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            set_count: n,
+        }
+    }
+
+    /// Returns the root of the set containing `x`, halving the path on the way
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `x` and `y`, the smaller set is merged into the bigger one
+    ///
+    /// Returns `true` if the sets were merged, `false` if both indices are already in the same set
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns the number of disjoint sets
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum UnionFindError<VId> {
     #[error("Vertex with ID {0} not found")]
@@ -211,4 +265,17 @@ mod tests {
         assert_eq!(union_find.find(&8).unwrap(), 8);
         assert_eq!(union_find.find(&9).unwrap(), 9);
     }
+
+    #[rstest]
+    fn test_dense_union_find() {
+        let mut union_find = DenseUnionFind::new(5);
+
+        assert!(union_find.union(0, 1));
+        assert!(union_find.union(3, 1));
+        assert!(!union_find.union(0, 3));
+
+        assert_eq!(union_find.find(3), union_find.find(0));
+        assert_ne!(union_find.find(2), union_find.find(0));
+        assert_eq!(union_find.set_count(), 3);
+    }
 }
@@ -0,0 +1,163 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Checks whether the graph is planar, using a heuristic instead of a full planarity test (like Boyer-Myrvold).
+    ///
+    /// First, the graph is reduced without changing its planarity: vertices with degree ≤ 1 are removed,
+    /// vertices with degree 2 are smoothed away (replaced by an edge between their neighbors), and parallel edges
+    /// as well as self-loops are ignored. This turns subdivisions of K5 and K3,3 into K5 and K3,3.
+    /// Every connected component of the reduced graph is then checked against the necessary edge count conditions
+    /// `E ≤ 3V - 6` and, if it is bipartite, `E ≤ 2V - 4`. Both are violated by K5 and K3,3.
+    ///
+    /// # Limitations
+    /// `false` is always correct, but `true` only means that no obstruction was found:
+    /// non-planar graphs that satisfy the edge count conditions after the reduction (e.g. the Petersen graph)
+    /// are reported as planar.
+    pub fn is_planar(&self) -> bool {
+        let mut adjacency = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), FxHashSet::default()))
+            .collect::<FxHashMap<_, _>>();
+        for (from, to, _) in self.get_all_edges() {
+            if from != to {
+                adjacency.entry(from).or_default().insert(to);
+                adjacency.entry(to).or_default().insert(from);
+            }
+        }
+
+        reduce_low_degree_vertices(&mut adjacency);
+
+        let mut visited = FxHashSet::default();
+        for &start in adjacency.keys() {
+            if !visited.insert(start) {
+                continue;
+            }
+
+            // BFS over the component, 2-coloring it on the way
+            let mut color = FxHashMap::from_iter([(start, false)]);
+            let mut is_bipartite = true;
+            let mut degree_sum = 0;
+            let mut queue = VecDeque::from([start]);
+            while let Some(current) = queue.pop_front() {
+                degree_sum += adjacency[&current].len();
+                for &neighbor in &adjacency[&current] {
+                    if visited.insert(neighbor) {
+                        color.insert(neighbor, !color[&current]);
+                        queue.push_back(neighbor);
+                    } else if color[&neighbor] == color[&current] {
+                        is_bipartite = false;
+                    }
+                }
+            }
+
+            let vertex_count = color.len();
+            let edge_count = degree_sum / 2;
+            // Every graph with at most 4 vertices is planar
+            if vertex_count <= 4 {
+                continue;
+            }
+            if edge_count > 3 * vertex_count - 6
+                || (is_bipartite && edge_count > 2 * vertex_count - 4)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Removes vertices with degree ≤ 1 and smooths vertices with degree 2 until every remaining vertex has degree ≥ 3
+fn reduce_low_degree_vertices<VId: Copy + Eq + Hash>(
+    adjacency: &mut FxHashMap<VId, FxHashSet<VId>>,
+) {
+    let mut queue = adjacency.keys().copied().collect::<VecDeque<_>>();
+
+    while let Some(vertex) = queue.pop_front() {
+        let Some(neighbors) = adjacency.get(&vertex) else {
+            // Already removed
+            continue;
+        };
+        if neighbors.len() > 2 {
+            continue;
+        }
+
+        let neighbors = adjacency
+            .remove(&vertex)
+            .expect("Vertex exists")
+            .into_iter()
+            .collect::<Vec<_>>();
+        for neighbor in &neighbors {
+            adjacency
+                .get_mut(neighbor)
+                .expect("Neighbors are part of the graph")
+                .remove(&vertex);
+        }
+
+        if let [first, second] = neighbors[..] {
+            // An existing edge between the neighbors just absorbs the smoothed path
+            adjacency
+                .get_mut(&first)
+                .expect("Neighbors are part of the graph")
+                .insert(second);
+            adjacency
+                .get_mut(&second)
+                .expect("Neighbors are part of the graph")
+                .insert(first);
+        }
+
+        // The degrees of the neighbors may have dropped
+        queue.extend(neighbors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        test_utils::{build_graph, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    fn complete_graph_edges(n_vertices: usize) -> Vec<(usize, usize)> {
+        (0..n_vertices)
+            .flat_map(|from| (from + 1..n_vertices).map(move |to| (from, to)))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::k5(5, complete_graph_edges(5), false)]
+    #[case::k3_3(6, vec![(0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)], false)]
+    // K3,3 with the edges 0-3 and 1-4 subdivided by the vertices 6 and 7
+    #[case::subdivided_k3_3(8, vec![(0, 6), (6, 3), (0, 4), (0, 5), (1, 3), (1, 7), (7, 4), (1, 5), (2, 3), (2, 4), (2, 5)], false)]
+    // K5 with a pendant path attached
+    #[case::k5_with_tail(7, [complete_graph_edges(5), vec![(4, 5), (5, 6)]].concat(), false)]
+    #[case::cycle(6, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)], true)]
+    #[case::k4(4, complete_graph_edges(4), true)]
+    // K5 minus one edge
+    #[case::k5_minus_edge(5, complete_graph_edges(5)[1..].to_vec(), true)]
+    // 3x3 grid
+    #[case::grid(9, vec![(0, 1), (1, 2), (3, 4), (4, 5), (6, 7), (7, 8), (0, 3), (3, 6), (1, 4), (4, 7), (2, 5), (5, 8)], true)]
+    #[case::empty(0, vec![], true)]
+    fn test_is_planar(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: bool,
+    ) {
+        let graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(graph.is_planar(), expected);
+    }
+}
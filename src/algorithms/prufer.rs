@@ -0,0 +1,237 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, GraphError, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Encodes the tree as its Prüfer sequence, using the vertex IDs as labels.
+    ///
+    /// Repeatedly removes the leaf with the smallest ID and appends its neighbor to the sequence,
+    /// until only two vertices are left. A tree with n vertices has a sequence of length n - 2.
+    ///
+    /// # Errors
+    /// - `GraphError::AlgorithmError`: if the graph is not a tree or has less than two vertices.
+    #[allow(clippy::type_complexity)]
+    pub fn to_prufer(
+        &self,
+    ) -> Result<
+        Vec<<Backend::Vertex as WithID>::IDType>,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        let vertex_count = self.vertex_count();
+        let is_tree = vertex_count >= 2
+            && self.edge_count() == vertex_count - 1
+            && self.get_all_vertices().next().is_some_and(|start| {
+                self.bfs_multi_source(&[start.get_id()]).len() == vertex_count
+            });
+        if !is_tree {
+            return Err(GraphError::AlgorithmError(
+                "A Prüfer sequence requires a tree with at least two vertices".to_string(),
+            ));
+        }
+
+        let mut degrees = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), self.get_adjacent_vertices(v.get_id()).count()))
+            .collect::<FxHashMap<_, _>>();
+        let mut leaves = degrees
+            .iter()
+            .filter(|(_, degree)| **degree == 1)
+            .map(|(vid, _)| Reverse(*vid))
+            .collect::<BinaryHeap<_>>();
+        let mut removed = FxHashSet::default();
+
+        let mut sequence = Vec::with_capacity(vertex_count - 2);
+        while sequence.len() < vertex_count - 2 {
+            let Reverse(leaf) = leaves.pop().expect("A tree always has a leaf");
+            removed.insert(leaf);
+
+            let neighbor = self
+                .get_adjacent_vertices(leaf)
+                .map(|v| v.get_id())
+                .find(|vid| !removed.contains(vid))
+                .expect("A leaf has exactly one remaining neighbor");
+            sequence.push(neighbor);
+
+            let degree = degrees.get_mut(&neighbor).expect("Neighbor exists");
+            *degree -= 1;
+            if *degree == 1 {
+                leaves.push(Reverse(neighbor));
+            }
+        }
+
+        Ok(sequence)
+    }
+
+    /// Decodes a Prüfer sequence into a labeled tree over the given vertices, whose IDs are the labels.
+    ///
+    /// `edge_builder` creates the data of the edge between two vertices.
+    ///
+    /// # Errors
+    /// - `GraphError::AlgorithmError`: if the sequence does not have exactly `vertices.len() - 2` entries.
+    /// - `GraphError::DuplicateVertex`: if two of the `vertices` share an ID.
+    /// - `GraphError::VertexNotFound`: if the sequence contains an ID that is not part of `vertices`.
+    /// - Errors of building the graph with the backend.
+    pub fn from_prufer(
+        sequence: &[<Backend::Vertex as WithID>::IDType],
+        vertices: Vec<Backend::Vertex>,
+        edge_builder: impl Fn(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        ) -> Backend::Edge,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        if vertices.len() != sequence.len() + 2 {
+            return Err(GraphError::AlgorithmError(
+                "A Prüfer sequence for n vertices must have n - 2 entries".to_string(),
+            ));
+        }
+
+        // Every vertex appears (degree - 1) times in the sequence
+        let mut degrees = FxHashMap::default();
+        for v in &vertices {
+            // Duplicates would be merged here, leaving too few leaves to decode the sequence
+            if degrees.insert(v.get_id(), 1usize).is_some() {
+                return Err(GraphError::DuplicateVertex(v.get_id()));
+            }
+        }
+        for vid in sequence {
+            *degrees
+                .get_mut(vid)
+                .ok_or(GraphError::VertexNotFound(*vid))? += 1;
+        }
+
+        let mut leaves = degrees
+            .iter()
+            .filter(|(_, degree)| **degree == 1)
+            .map(|(vid, _)| Reverse(*vid))
+            .collect::<BinaryHeap<_>>();
+
+        let mut edges = Vec::with_capacity(vertices.len() - 1);
+        for &vid in sequence {
+            let Reverse(leaf) = leaves.pop().expect("A valid sequence always leaves a leaf");
+            edges.push((leaf, vid, edge_builder(leaf, vid)));
+
+            let degree = degrees.get_mut(&vid).expect("Checked above");
+            *degree -= 1;
+            if *degree == 1 {
+                leaves.push(Reverse(vid));
+            }
+        }
+
+        // The last two leaves are connected
+        let Reverse(first) = leaves.pop().expect("Two vertices remain");
+        let Reverse(second) = leaves.pop().expect("Two vertices remain");
+        edges.push((first, second, edge_builder(first, second)));
+
+        Self::from_vertices_and_edges(vertices, edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rustc_hash::FxHashSet;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Graph, GraphError, ListGraph, Undirected,
+    };
+
+    fn vertices(n_vertices: usize) -> Vec<MockVertex> {
+        (0..n_vertices).map(|id| MockVertex { id }).collect()
+    }
+
+    #[test]
+    fn test_prufer_round_trip() {
+        let sequence = vec![3, 3, 3, 4];
+
+        let tree: ListGraph<MockVertex, (), Undirected> =
+            Graph::from_prufer(&sequence, vertices(6), |_, _| ()).unwrap();
+
+        let edges = tree
+            .get_all_edges()
+            .map(|(from, to, _)| (from.min(to), from.max(to)))
+            .collect::<FxHashSet<_>>();
+        assert_eq!(
+            edges,
+            FxHashSet::from_iter([(0, 3), (1, 3), (2, 3), (3, 4), (4, 5)])
+        );
+        assert_eq!(tree.to_prufer().unwrap(), sequence);
+    }
+
+    #[test]
+    fn test_prufer_round_trip_random_sequences() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let n_vertices = rng.random_range(2..12);
+            let sequence = (0..n_vertices - 2)
+                .map(|_| rng.random_range(0..n_vertices))
+                .collect::<Vec<_>>();
+
+            let tree: MatrixGraph<MockVertex, (), Undirected> =
+                Graph::from_prufer(&sequence, vertices(n_vertices), |_, _| ()).unwrap();
+
+            assert_eq!(tree.edge_count(), n_vertices - 1);
+            assert_eq!(tree.to_prufer().unwrap(), sequence);
+        }
+    }
+
+    #[test]
+    fn test_to_prufer_rejects_non_trees() {
+        let cycle = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            vertices(3),
+            vec![(0, 1, ()), (1, 2, ()), (2, 0, ())],
+        )
+        .unwrap();
+        // Right edge count, but a cycle and an isolated vertex
+        let disconnected = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            vertices(4),
+            vec![(0, 1, ()), (1, 2, ()), (2, 0, ())],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            cycle.to_prufer(),
+            Err(GraphError::AlgorithmError(_))
+        ));
+        assert!(matches!(
+            disconnected.to_prufer(),
+            Err(GraphError::AlgorithmError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_prufer_rejects_invalid_sequences() {
+        let too_long: Result<ListGraph<MockVertex, (), Undirected>, _> =
+            Graph::from_prufer(&[0, 1, 2], vertices(4), |_, _| ());
+        let unknown_vertex: Result<ListGraph<MockVertex, (), Undirected>, _> =
+            Graph::from_prufer(&[0, 7], vertices(4), |_, _| ());
+
+        assert!(matches!(too_long, Err(GraphError::AlgorithmError(_))));
+        assert!(matches!(unknown_vertex, Err(GraphError::VertexNotFound(7))));
+    }
+
+    #[test]
+    fn test_from_prufer_rejects_duplicate_vertices() {
+        let duplicate: Result<ListGraph<MockVertex, (), Undirected>, _> = Graph::from_prufer(
+            &[1],
+            vec![
+                MockVertex { id: 0 },
+                MockVertex { id: 0 },
+                MockVertex { id: 1 },
+            ],
+            |_, _| (),
+        );
+
+        assert!(matches!(duplicate, Err(GraphError::DuplicateVertex(0))));
+    }
+}
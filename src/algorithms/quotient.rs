@@ -0,0 +1,121 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Direction, Graph, GraphError,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Builds the quotient graph for a partition of the vertices.
+    ///
+    /// `group_of` assigns every vertex to a group, all vertices of a group are merged into one vertex
+    /// of the output graph, created by `vertex_builder`. The group IDs become the vertex IDs of the output graph.
+    /// An edge between two groups is added for every edge between vertices of these groups,
+    /// parallel edges are merged with `combine_edges`. Edges inside a group are dropped.
+    ///
+    /// Groups are inserted in ascending order, so compact group IDs (`0..k`) work with the matrix backends.
+    ///
+    /// # Errors
+    /// Returns the errors of building the output graph, e.g. if `vertex_builder` does not preserve the group ID.
+    pub fn quotient<F, B, C, OutputBackend>(
+        &self,
+        group_of: F,
+        vertex_builder: B,
+        combine_edges: C,
+    ) -> Result<Graph<OutputBackend>, GraphError<<OutputBackend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(<Backend::Vertex as WithID>::IDType) -> <OutputBackend::Vertex as WithID>::IDType,
+        B: Fn(<OutputBackend::Vertex as WithID>::IDType) -> OutputBackend::Vertex,
+        C: Fn(Backend::Edge, &Backend::Edge) -> Backend::Edge,
+        Backend::Edge: Clone,
+        OutputBackend: GraphBase<Edge = Backend::Edge, Direction = Backend::Direction>,
+        <OutputBackend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+    {
+        let mut groups = self
+            .get_all_vertices()
+            .map(|v| group_of(v.get_id()))
+            .collect::<Vec<_>>();
+        groups.sort_unstable();
+        groups.dedup();
+
+        let mut quotient_edges = FxHashMap::default();
+        for (from, to, edge) in self.get_all_edges() {
+            let (from_group, to_group) = (group_of(from), group_of(to));
+            if from_group == to_group {
+                continue;
+            }
+
+            // Both directions of an undirected edge are the same quotient edge
+            let key = if Backend::Direction::IS_DIRECTED {
+                (from_group, to_group)
+            } else {
+                (from_group.min(to_group), from_group.max(to_group))
+            };
+            let combined = match quotient_edges.remove(&key) {
+                Some(existing) => combine_edges(existing, edge),
+                None => edge.clone(),
+            };
+            quotient_edges.insert(key, combined);
+        }
+
+        let vertices = groups.into_iter().map(vertex_builder).collect();
+        let edges = quotient_edges
+            .into_iter()
+            .map(|((from, to), edge)| (from, to, edge))
+            .collect();
+
+        Graph::from_vertices_and_edges(vertices, edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, ListGraph, Undirected,
+    };
+
+    #[test]
+    fn test_quotient_of_cycle() {
+        // 4-cycle 0 - 1 - 2 - 3 - 0, grouped into {0, 1} and {2, 3}
+        let graph = ListGraph::<MockVertex, u32, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)],
+        )
+        .unwrap();
+
+        let quotient: MatrixGraph<MockVertex, u32, Undirected> = graph
+            .quotient(|vid| vid / 2, |id| MockVertex { id }, |a, b| a + b)
+            .unwrap();
+
+        assert_eq!(quotient.vertex_count(), 2);
+        assert_eq!(quotient.edge_count(), 1);
+        // The edges 1 - 2 and 3 - 0 are combined
+        assert_eq!(quotient.get_edge(0, 1), Some(&6));
+    }
+
+    #[test]
+    fn test_quotient_directed_keeps_orientation() {
+        let graph = ListGraph::<MockVertex, u32, Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 2, 1), (1, 3, 2), (3, 0, 5), (0, 1, 7)],
+        )
+        .unwrap();
+
+        let quotient: ListGraph<MockVertex, u32, Directed> = graph
+            .quotient(|vid| vid / 2, |id| MockVertex { id }, |a, b| a.max(*b))
+            .unwrap();
+
+        assert_eq!(quotient.vertex_count(), 2);
+        assert_eq!(quotient.edge_count(), 2);
+        assert_eq!(quotient.get_edge(0, 1), Some(&2));
+        assert_eq!(quotient.get_edge(1, 0), Some(&5));
+    }
+}
@@ -0,0 +1,165 @@
+use std::{fmt::Debug, hash::Hash};
+
+use rand::{seq::IteratorRandom, Rng};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph, GraphError, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    Backend::Vertex: Clone,
+    Backend::Edge: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash + Debug,
+{
+    /// Samples a spanning tree uniformly at random among all spanning trees of the graph (Wilson's algorithm).
+    ///
+    /// Starting with the smallest vertex as the tree, a random walk is started from every vertex that is not
+    /// part of the tree yet. Once the walk hits the tree, its loop-erased path is added to the tree.
+    /// Edge weights are ignored for the sampling, but the edge data is copied to the output graph.
+    /// Pass a seeded RNG (e.g. `StdRng::seed_from_u64`) for reproducible trees.
+    ///
+    /// Vertices are inserted in ascending ID order, so compact IDs work with the matrix backends.
+    ///
+    /// # Errors
+    /// - `GraphError::AlgorithmError`: if the graph is not connected.
+    /// - Errors of building the output graph.
+    pub fn random_spanning_tree<OutputBackend>(
+        &self,
+        mut rng: impl Rng,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        if self.analyze_components().count() > 1 {
+            return Err(GraphError::AlgorithmError(
+                "A spanning tree requires a connected graph".to_string(),
+            ));
+        }
+
+        let mut vertices = self.get_all_vertices().collect::<Vec<_>>();
+        vertices.sort_unstable_by_key(|v| v.get_id());
+
+        let mut in_tree = FxHashSet::default();
+        // The successor of every vertex on the last walk, which is its parent in the final tree
+        let mut next = FxHashMap::default();
+        let mut tree_edges = Vec::with_capacity(vertices.len().saturating_sub(1));
+
+        if let Some(root) = vertices.first() {
+            in_tree.insert(root.get_id());
+        }
+
+        for start in vertices.iter().map(|v| v.get_id()) {
+            // Random walk until the tree is hit. Overwriting the successor of revisited vertices erases the loops.
+            let mut current = start;
+            while !in_tree.contains(&current) {
+                let neighbor = self
+                    .get_adjacent_vertices(current)
+                    .choose(&mut rng)
+                    .expect(
+                        "Every vertex of a connected graph with multiple vertices has neighbors",
+                    )
+                    .get_id();
+                next.insert(current, neighbor);
+                current = neighbor;
+            }
+
+            // Add the loop-erased walk to the tree
+            let mut current = start;
+            while in_tree.insert(current) {
+                let parent = next[&current];
+                let edge = self
+                    .get_edge(current, parent)
+                    .expect("The random walk only follows existing edges");
+                tree_edges.push((current, parent, edge.clone()));
+                current = parent;
+            }
+        }
+
+        Graph::from_vertices_and_edges(vertices.into_iter().cloned().collect(), tree_edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+    use rustc_hash::FxHashSet;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        GraphError, ListGraph, Undirected,
+    };
+
+    /// A `size` × `size` grid graph, vertex `row * size + col`
+    fn grid(size: usize) -> ListGraph<MockVertex, (), Undirected> {
+        let mut edges = Vec::new();
+        for row in 0..size {
+            for col in 0..size {
+                let id = row * size + col;
+                if col + 1 < size {
+                    edges.push((id, id + 1, ()));
+                }
+                if row + 1 < size {
+                    edges.push((id, id + size, ()));
+                }
+            }
+        }
+
+        ListGraph::from_vertices_and_edges(
+            (0..size * size).map(|id| MockVertex { id }).collect(),
+            edges,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_random_spanning_tree_on_grid() {
+        let graph = grid(4);
+
+        let mut trees = FxHashSet::default();
+        for seed in 0..5 {
+            let tree: MatrixGraph<MockVertex, (), Undirected> = graph
+                .random_spanning_tree(StdRng::seed_from_u64(seed))
+                .unwrap();
+
+            assert_eq!(tree.vertex_count(), 16);
+            assert_eq!(tree.edge_count(), 15);
+            assert_eq!(tree.analyze_components().count(), 1);
+            for (from, to, _) in tree.get_all_edges() {
+                assert!(graph.get_edge(from, to).is_some());
+            }
+
+            let mut edges = tree
+                .get_all_edges()
+                .map(|(from, to, _)| (from.min(to), from.max(to)))
+                .collect::<Vec<_>>();
+            edges.sort_unstable();
+            trees.insert(edges);
+        }
+
+        // A 4x4 grid has 100352 spanning trees, so different seeds yield different trees
+        assert!(trees.len() > 1);
+    }
+
+    #[test]
+    fn test_random_spanning_tree_requires_connected_graph() {
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (2, 3, ())],
+        )
+        .unwrap();
+
+        let result: Result<ListGraph<MockVertex, (), Undirected>, _> =
+            graph.random_spanning_tree(StdRng::seed_from_u64(42));
+
+        assert!(matches!(result, Err(GraphError::AlgorithmError(_))));
+    }
+}
@@ -0,0 +1,177 @@
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    graph::{GraphBase, WeightedEdge, WithID},
+    Graph,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Performs a uniform random walk of up to `steps` steps, starting at `start`.
+    ///
+    /// In every step, one of the (outgoing) neighbors of the current vertex is chosen uniformly at random.
+    /// The walk stops early at a vertex without neighbors.
+    /// Pass a seeded RNG (e.g. `StdRng::seed_from_u64`) for reproducible walks.
+    ///
+    /// Returns the visited vertices including `start`, or an empty `Vec` if `start` does not exist.
+    pub fn random_walk(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        steps: usize,
+        mut rng: impl Rng,
+    ) -> Vec<<Backend::Vertex as WithID>::IDType> {
+        if self.get_vertex_by_id(start).is_none() {
+            return vec![];
+        }
+
+        let mut walk = Vec::with_capacity(steps + 1);
+        walk.push(start);
+
+        let mut current = start;
+        for _ in 0..steps {
+            match self.get_adjacent_vertices(current).choose(&mut rng) {
+                Some(next) => current = next.get_id(),
+                // Dead end
+                None => break,
+            }
+            walk.push(current);
+        }
+
+        walk
+    }
+
+    /// Performs a random walk of up to `steps` steps, starting at `start`, where every step follows an
+    /// outgoing edge with a probability proportional to its weight.
+    ///
+    /// Edge weights must be non-negative; edges with weight zero are never taken.
+    /// The walk stops early at a vertex whose outgoing edges have a total weight of zero (including dead ends).
+    /// Pass a seeded RNG (e.g. `StdRng::seed_from_u64`) for reproducible walks.
+    ///
+    /// Returns the visited vertices including `start`, or an empty `Vec` if `start` does not exist.
+    pub fn weighted_random_walk(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        steps: usize,
+        mut rng: impl Rng,
+    ) -> Vec<<Backend::Vertex as WithID>::IDType>
+    where
+        Backend::Edge: WeightedEdge,
+        <Backend::Edge as WeightedEdge>::WeightType: Into<f64>,
+    {
+        if self.get_vertex_by_id(start).is_none() {
+            return vec![];
+        }
+
+        let mut walk = Vec::with_capacity(steps + 1);
+        walk.push(start);
+
+        let mut current = start;
+        for _ in 0..steps {
+            let candidates = self
+                .get_adjacent_vertices_with_edges(current)
+                .map(|(v, e)| (v.get_id(), e.get_weight().into()))
+                .filter(|(_, weight)| *weight > 0.0)
+                .collect::<Vec<(_, f64)>>();
+            let total_weight: f64 = candidates.iter().map(|(_, weight)| weight).sum();
+            if total_weight <= 0.0 {
+                break;
+            }
+
+            // Pick the first candidate whose cumulative weight exceeds the sampled value
+            let mut remaining = rng.random_range(0.0..total_weight);
+            current = candidates
+                .iter()
+                .find(|(_, weight)| {
+                    remaining -= weight;
+                    remaining < 0.0
+                })
+                // Rounding errors may leave a tiny rest, which belongs to the last candidate
+                .unwrap_or(&candidates[candidates.len() - 1])
+                .0;
+            walk.push(current);
+        }
+
+        walk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{graph::GraphBase, test_utils::MockVertex, Directed, ListGraph, Undirected};
+
+    #[test]
+    fn test_random_walk_directed_path() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 3, ())],
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        // The only way leads along the path and ends at the sink
+        assert_eq!(graph.random_walk(0, 10, &mut rng), vec![0, 1, 2, 3]);
+        assert_eq!(graph.random_walk(1, 1, &mut rng), vec![1, 2]);
+        assert_eq!(graph.random_walk(3, 5, &mut rng), vec![3]);
+        assert_eq!(graph.random_walk(42, 5, &mut rng), vec![]);
+    }
+
+    #[test]
+    fn test_random_walk_is_reproducible() {
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..5).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, ()),
+                (0, 2, ()),
+                (1, 2, ()),
+                (2, 3, ()),
+                (3, 4, ()),
+                (4, 0, ()),
+            ],
+        )
+        .unwrap();
+
+        let walk = graph.random_walk(0, 50, StdRng::seed_from_u64(7));
+
+        assert_eq!(walk.len(), 51);
+        assert_eq!(walk, graph.random_walk(0, 50, StdRng::seed_from_u64(7)));
+        for step in walk.windows(2) {
+            assert!(graph.contains_edge(step[0], step[1]));
+        }
+    }
+
+    #[test]
+    fn test_weighted_random_walk_prefers_heavy_edges() {
+        let graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1.0), (0, 2, 3.0)],
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let trials = 10_000;
+        let heavy_steps = (0..trials)
+            .filter(|_| graph.weighted_random_walk(0, 1, &mut rng) == vec![0, 2])
+            .count();
+
+        let heavy_share = heavy_steps as f64 / trials as f64;
+        assert!((heavy_share - 0.75).abs() < 0.02, "share was {heavy_share}");
+    }
+
+    #[test]
+    fn test_weighted_random_walk_halts_without_out_weight() {
+        let graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 2.0), (1, 2, 0.0)],
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(graph.weighted_random_walk(0, 10, &mut rng), vec![0, 1]);
+        assert_eq!(graph.weighted_random_walk(42, 10, &mut rng), vec![]);
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    hash::Hash,
+    ops::Sub,
+};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
+    Graph, Undirected,
+};
+
+use super::{dijkstra::EdgeEntry, single_source_shortest_paths::SingleSourceShortestPaths};
+
+/// Precomputed landmark distances for answering repeated shortest path queries
+/// on a fixed graph with A*, using landmarks and the triangle inequality (ALT).
+///
+/// Created by [`Graph::alt_index`]. The index borrows the graph, so it cannot outlive
+/// or observe modifications of it.
+#[allow(clippy::type_complexity)]
+pub struct AltIndex<'a, Backend>
+where
+    Backend: GraphBase,
+    Backend::Edge: WeightedEdge,
+    <Backend::Vertex as WithID>::IDType: Hash + Eq,
+{
+    graph: &'a Graph<Backend>,
+    landmarks: Vec<<Backend::Vertex as WithID>::IDType>,
+    /// Shortest path distances from each landmark (same order as `landmarks`)
+    distances: Vec<
+        SingleSourceShortestPaths<
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Edge as WeightedEdge>::WeightType,
+        >,
+    >,
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType:
+        Copy + HeapWeight + Sub<Output = <Backend::Edge as WeightedEdge>::WeightType>,
+{
+    /// Precomputes an [`AltIndex`] with up to `n_landmarks` landmarks for fast repeated shortest path queries.
+    ///
+    /// Landmarks are chosen with the farthest-point heuristic: the first landmark is the vertex with the smallest ID,
+    /// every following landmark is the vertex farthest away from all previously chosen landmarks
+    /// (vertices unreachable from all landmarks are preferred, so every component gets a landmark).
+    ///
+    /// The preprocessing runs one Dijkstra per landmark. More landmarks give a tighter heuristic
+    /// at the cost of memory and preprocessing time.
+    ///
+    /// # Warning
+    /// Just like Dijkstra, this only works with non-negative weights.
+    pub fn alt_index(&self, n_landmarks: usize) -> AltIndex<'_, Backend> {
+        let mut vertex_ids = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        vertex_ids.sort_unstable();
+
+        let mut landmarks = Vec::with_capacity(n_landmarks);
+        let mut distances: Vec<SingleSourceShortestPaths<_, _>> = Vec::with_capacity(n_landmarks);
+
+        let mut next_landmark = vertex_ids.first().copied();
+        while let Some(landmark) = next_landmark {
+            if landmarks.len() >= n_landmarks {
+                break;
+            }
+            landmarks.push(landmark);
+            distances.push(self.dijkstra(landmark, None));
+
+            // Distance to the closest landmark, `None` if no landmark reaches the vertex
+            let closest_landmark_distance = |vid| {
+                distances
+                    .iter()
+                    .filter_map(|d| d.get_cost(vid))
+                    .min_by(HeapWeight::heap_cmp)
+            };
+            next_landmark = vertex_ids
+                .iter()
+                .filter(|vid| !landmarks.contains(vid))
+                .map(|&vid| (vid, closest_landmark_distance(vid)))
+                .max_by(|(id_a, dist_a), (id_b, dist_b)| {
+                    let by_distance = match (dist_a, dist_b) {
+                        (None, None) => Ordering::Equal,
+                        (None, Some(_)) => Ordering::Greater,
+                        (Some(_), None) => Ordering::Less,
+                        (Some(a), Some(b)) => a.heap_cmp(b),
+                    };
+                    // Prefer the smaller ID on ties
+                    by_distance.then(id_b.cmp(id_a))
+                })
+                .map(|(vid, _)| vid);
+        }
+
+        AltIndex {
+            graph: self,
+            landmarks,
+            distances,
+        }
+    }
+}
+
+impl<Backend> AltIndex<'_, Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType:
+        Copy + HeapWeight + Sub<Output = <Backend::Edge as WeightedEdge>::WeightType>,
+{
+    /// The IDs of the chosen landmarks
+    pub fn landmarks(&self) -> &[<Backend::Vertex as WithID>::IDType] {
+        &self.landmarks
+    }
+
+    /// Computes the shortest path from `start` to `goal` using A* with the landmark heuristic.
+    ///
+    /// Returns the path cost and the vertices along the path (including `start` and `goal`),
+    /// or `None` if `goal` is not reachable from `start`.
+    #[allow(clippy::type_complexity)]
+    pub fn shortest_path(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        goal: <Backend::Vertex as WithID>::IDType,
+    ) -> Option<(
+        <Backend::Edge as WeightedEdge>::WeightType,
+        Vec<<Backend::Vertex as WithID>::IDType>,
+    )> {
+        self.graph.get_vertex_by_id(start)?;
+        self.graph.get_vertex_by_id(goal)?;
+
+        let mut costs = FxHashMap::default();
+        let mut predecessors = FxHashMap::default();
+        let mut visited = FxHashSet::default();
+        // Ordered by the estimated total cost (cost so far + heuristic)
+        let mut visit_next = BinaryHeap::new();
+
+        costs.insert(
+            start,
+            <Backend::Edge as WeightedEdge>::WeightType::default(),
+        );
+        visit_next.push(Reverse(EdgeEntry::new(self.heuristic(start, goal), start)));
+
+        while let Some(Reverse(node_entry)) = visit_next.pop() {
+            let current = node_entry.vertex_id;
+            if !visited.insert(current) {
+                continue;
+            }
+
+            if current == goal {
+                let cost = costs[&goal];
+                let path =
+                    SingleSourceShortestPaths::new(start, costs, predecessors).get_path(goal);
+                return Some((cost, path));
+            }
+
+            let current_cost = costs[&current];
+            for (next_v, edge) in self
+                .graph
+                .get_adjacent_vertices_with_edges(current)
+                .map(|(v, e)| (v.get_id(), e))
+                .filter(|(v, _e)| !visited.contains(v))
+            {
+                let new_cost = current_cost + edge.get_weight();
+                let improves = costs
+                    .get(&next_v)
+                    .is_none_or(|existing_cost| new_cost < *existing_cost);
+                if improves {
+                    costs.insert(next_v, new_cost);
+                    predecessors.insert(next_v, current);
+                    visit_next.push(Reverse(EdgeEntry::new(
+                        new_cost + self.heuristic(next_v, goal),
+                        next_v,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Lower bound for the distance between `vertex` and `goal`.
+    ///
+    /// By the triangle inequality `|d(L, goal) - d(L, vertex)| <= d(vertex, goal)` holds for every landmark `L`.
+    fn heuristic(
+        &self,
+        vertex: <Backend::Vertex as WithID>::IDType,
+        goal: <Backend::Vertex as WithID>::IDType,
+    ) -> <Backend::Edge as WeightedEdge>::WeightType {
+        self.distances
+            .iter()
+            .filter_map(|d| Some((d.get_cost(vertex)?, d.get_cost(goal)?)))
+            .map(|(to_vertex, to_goal)| {
+                if to_vertex > to_goal {
+                    to_vertex - to_goal
+                } else {
+                    to_goal - to_vertex
+                }
+            })
+            .max_by(HeapWeight::heap_cmp)
+            .unwrap_or_default()
+    }
+}
@@ -4,7 +4,7 @@ use std::hash::Hash;
 
 use crate::{
     graph::{GraphBase, WeightedEdge, WithID},
-    Directed, Graph,
+    Directed, Graph, Undirected,
 };
 
 use super::single_source_shortest_paths::SingleSourceShortestPaths;
@@ -40,6 +40,60 @@ where
     ) -> BellmanFordResult<
         <Backend::Vertex as WithID>::IDType,
         <Backend::Edge as WeightedEdge>::WeightType,
+    > {
+        self.bellman_ford_internal(start)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy,
+{
+    /// Bellman Ford's shortest path algorithm for undirected graphs.
+    ///
+    /// Every undirected edge is treated as two directed arcs, like [`Graph::bellman_ford`] on the
+    /// result of `into_directed()`. Such arcs turn a single negative edge into a negative cycle of length 2
+    /// (walking the edge back and forth), so any negative edge reachable from `start` is reported as
+    /// `BellmanFordResult::NegativeCycle` with its two endpoints, before running the algorithm.
+    #[allow(clippy::type_complexity)]
+    pub fn bellman_ford_undirected(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+    ) -> BellmanFordResult<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    > {
+        let reachable = self.bfs_multi_source(&[start]);
+        let zero = <Backend::Edge as WeightedEdge>::WeightType::default();
+        if let Some((from, to, _)) = self
+            .get_all_edges()
+            .find(|(from, _, edge)| reachable.contains_key(from) && edge.get_weight() < zero)
+        {
+            return BellmanFordResult::NegativeCycle(vec![from, to]);
+        }
+
+        self.bellman_ford_internal(start)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy,
+{
+    /// Queue-based Bellman-Ford, following the edges returned by `get_adjacent_vertices_with_edges`
+    #[allow(clippy::type_complexity)]
+    fn bellman_ford_internal(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+    ) -> BellmanFordResult<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
     > {
         // Final map of costs from start to each v
         let mut costs = FxHashMap::default();
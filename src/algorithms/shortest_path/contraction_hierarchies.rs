@@ -0,0 +1,490 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash, ops::Add};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
+    Graph, Undirected,
+};
+
+use super::dijkstra::EdgeEntry;
+
+/// The maximum number of vertices settled by a single witness search during the preprocessing.
+///
+/// Stopping early can only miss witnesses, which adds unnecessary (but correct) shortcuts.
+const WITNESS_SEARCH_LIMIT: usize = 64;
+
+/// Vertices with more remaining neighbors than this are not contracted, but kept in the core.
+///
+/// Contracting a vertex adds up to `degree²` shortcuts, which makes graphs without a road-network-like
+/// structure (e.g. random graphs) increasingly dense. The core is searched like the original graph instead.
+const MAX_CONTRACTION_DEGREE: usize = 8;
+
+/// A contraction hierarchy for answering repeated shortest path queries on a fixed graph.
+///
+/// Created by [`Graph::contraction_hierarchies`]. Unlike the [`AltIndex`](super::alt::AltIndex),
+/// the hierarchy stores its own augmented graph (the original edges plus all shortcuts),
+/// so it does not borrow the graph, but it also does not reflect later modifications of it.
+#[derive(Debug, Clone)]
+pub struct ContractionHierarchy<VId: Hash + Eq, Cost> {
+    /// The position of every vertex in the contraction order, the core vertices come last
+    rank: FxHashMap<VId, usize>,
+    /// The edges and shortcuts to the neighbors with a higher rank, and between the core vertices
+    upward: FxHashMap<VId, Vec<(VId, Cost)>>,
+    /// The contracted vertex every shortcut `(smaller ID, larger ID)` bypasses
+    shortcut_middles: FxHashMap<(VId, VId), VId>,
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy + HeapWeight,
+{
+    /// Preprocesses the graph into a [`ContractionHierarchy`] for fast repeated shortest path queries.
+    ///
+    /// The vertices are contracted one by one, ordered by a simple importance heuristic
+    /// (the edge difference plus the number of already contracted neighbors, updated lazily).
+    /// Contracting a vertex adds a shortcut between two of its neighbors, unless a (limited) witness search
+    /// finds a path between them that is at most as long without the contracted vertex.
+    /// Vertices that have too many neighbors once it is their turn are not contracted and form the core.
+    ///
+    /// # Warning
+    /// Just like Dijkstra, this only works with non-negative weights.
+    #[allow(clippy::type_complexity)]
+    pub fn contraction_hierarchies(
+        &self,
+    ) -> ContractionHierarchy<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    > {
+        // The graph of the vertices that are not contracted yet, with the cheapest edge between each pair
+        let mut remaining = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), FxHashMap::default()))
+            .collect::<FxHashMap<_, _>>();
+        // All edges and shortcuts by `(smaller ID, larger ID)`, with the bypassed vertex of the shortcuts
+        let mut edges = FxHashMap::default();
+        for (from, to, edge) in self.get_all_edges() {
+            let cost = edge.get_weight();
+            let cheaper = remaining[&from]
+                .get(&to)
+                .is_none_or(|existing_cost| cost < *existing_cost);
+            if from != to && cheaper {
+                insert_undirected(&mut remaining, from, to, cost);
+                edges.insert((from.min(to), from.max(to)), (cost, None));
+            }
+        }
+
+        let mut contracted_neighbors = FxHashMap::default();
+        let priority = |remaining: &FxHashMap<_, FxHashMap<_, _>>,
+                        contracted_neighbors: &FxHashMap<_, isize>,
+                        vertex_id| {
+            let degree = remaining[&vertex_id].len();
+            if degree > MAX_CONTRACTION_DEGREE {
+                // Will end up in the core, so the shortcuts are not needed
+                return (isize::MAX, Vec::new());
+            }
+            let shortcuts = shortcuts(remaining, vertex_id);
+            let priority = shortcuts.len() as isize - degree as isize
+                + contracted_neighbors
+                    .get(&vertex_id)
+                    .copied()
+                    .unwrap_or_default();
+            (priority, shortcuts)
+        };
+
+        let mut vertex_ids = remaining.keys().copied().collect::<Vec<_>>();
+        vertex_ids.sort_unstable();
+        let mut queue = vertex_ids
+            .into_iter()
+            .map(|vid| Reverse((priority(&remaining, &contracted_neighbors, vid).0, vid)))
+            .collect::<BinaryHeap<_>>();
+
+        let mut rank = FxHashMap::default();
+        let mut core = Vec::new();
+        while let Some(Reverse((_, vertex_id))) = queue.pop() {
+            if remaining[&vertex_id].len() > MAX_CONTRACTION_DEGREE {
+                core.push(vertex_id);
+                continue;
+            }
+
+            // The priorities in the queue may be outdated, so recompute it before contracting
+            let (priority, shortcuts) = priority(&remaining, &contracted_neighbors, vertex_id);
+            if let Some(Reverse((next_priority, _))) = queue.peek() {
+                if priority > *next_priority {
+                    queue.push(Reverse((priority, vertex_id)));
+                    continue;
+                }
+            }
+
+            rank.insert(vertex_id, rank.len());
+            let neighbors = remaining
+                .remove(&vertex_id)
+                .expect("Vertices are only contracted once");
+            for neighbor in neighbors.keys() {
+                remaining
+                    .get_mut(neighbor)
+                    .expect("Neighbors are not contracted yet")
+                    .remove(&vertex_id);
+                *contracted_neighbors.entry(*neighbor).or_default() += 1;
+            }
+            for (from, to, cost) in shortcuts {
+                insert_undirected(&mut remaining, from, to, cost);
+                edges.insert((from.min(to), from.max(to)), (cost, Some(vertex_id)));
+            }
+        }
+        core.sort_unstable();
+        for vertex_id in core {
+            rank.insert(vertex_id, rank.len());
+        }
+
+        let mut upward = FxHashMap::<_, Vec<_>>::default();
+        let mut shortcut_middles = FxHashMap::default();
+        for ((from, to), (cost, middle)) in edges {
+            if remaining.contains_key(&from) && remaining.contains_key(&to) {
+                // Both are core vertices, so the edge is searched in both directions
+                upward.entry(from).or_default().push((to, cost));
+                upward.entry(to).or_default().push((from, cost));
+            } else if rank[&from] < rank[&to] {
+                upward.entry(from).or_default().push((to, cost));
+            } else {
+                upward.entry(to).or_default().push((from, cost));
+            }
+            if let Some(middle) = middle {
+                shortcut_middles.insert((from, to), middle);
+            }
+        }
+
+        ContractionHierarchy {
+            rank,
+            upward,
+            shortcut_middles,
+        }
+    }
+}
+
+impl<VId, Cost> ContractionHierarchy<VId, Cost>
+where
+    VId: Copy + Ord + Hash,
+    Cost: Copy + HeapWeight + PartialOrd + Add<Output = Cost> + Default,
+{
+    /// The number of shortcuts added during the preprocessing
+    pub fn shortcut_count(&self) -> usize {
+        self.shortcut_middles.len()
+    }
+
+    /// Computes the shortest path from `start` to `goal` with a bidirectional upward search.
+    ///
+    /// Both searches only follow edges and shortcuts to vertices contracted later (and all edges within the core),
+    /// the shortest path passes through the vertex where the sum of both costs is minimal.
+    /// The searches alternate by their next cost and stop once neither can improve the best path found so far.
+    ///
+    /// Returns the path cost and the vertices along the path (including `start` and `goal`, without shortcuts),
+    /// or `None` if `goal` is not reachable from `start` or one of them is not part of the graph.
+    pub fn ch_query(&self, start: VId, goal: VId) -> Option<(Cost, Vec<VId>)> {
+        if !self.rank.contains_key(&start) || !self.rank.contains_key(&goal) {
+            return None;
+        }
+
+        let mut searches: [UpwardSearch<_, Cost>; 2] =
+            [UpwardSearch::new(start), UpwardSearch::new(goal)];
+        // The cost of the best path found so far and the vertex where both searches meet on it
+        let mut best: Option<(Cost, VId)> = None;
+        loop {
+            let next_search = (0..2)
+                .filter_map(|i| Some((i, searches[i].next_cost()?)))
+                .filter(|(_, cost)| best.is_none_or(|(best_cost, _)| *cost < best_cost))
+                .min_by(|(_, cost_a), (_, cost_b)| cost_a.heap_cmp(cost_b));
+            let Some((i, _)) = next_search else {
+                break;
+            };
+
+            let Some((current, cost)) = searches[i].settle_next(&self.upward) else {
+                continue;
+            };
+            if let Some(&other_cost) = searches[1 - i].costs.get(&current) {
+                let total_cost = cost + other_cost;
+                if best.is_none_or(|(best_cost, _)| total_cost < best_cost) {
+                    best = Some((total_cost, current));
+                }
+            }
+        }
+        let (cost, meeting_vertex) = best?;
+        let [forward, backward] = searches;
+
+        // The hops from `start` up to the meeting vertex and from there down to `goal`
+        let mut hops = Vec::new();
+        let mut current = meeting_vertex;
+        while let Some(&predecessor) = forward.predecessors.get(&current) {
+            hops.push((predecessor, current));
+            current = predecessor;
+        }
+        hops.reverse();
+        let mut current = meeting_vertex;
+        while let Some(&predecessor) = backward.predecessors.get(&current) {
+            hops.push((current, predecessor));
+            current = predecessor;
+        }
+
+        // Replace every shortcut by the two hops it bypasses (in reverse order, as the last hop is popped first)
+        let mut path = vec![start];
+        hops.reverse();
+        while let Some((from, to)) = hops.pop() {
+            match self.shortcut_middles.get(&(from.min(to), from.max(to))) {
+                Some(&middle) => {
+                    hops.push((middle, to));
+                    hops.push((from, middle));
+                }
+                None => path.push(to),
+            }
+        }
+
+        Some((cost, path))
+    }
+}
+
+/// The state of one direction of [`ContractionHierarchy::ch_query`], a Dijkstra on the upward edges
+struct UpwardSearch<VId, Cost: HeapWeight> {
+    costs: FxHashMap<VId, Cost>,
+    predecessors: FxHashMap<VId, VId>,
+    visited: FxHashSet<VId>,
+    visit_next: BinaryHeap<Reverse<EdgeEntry<Cost, VId>>>,
+}
+
+impl<VId, Cost> UpwardSearch<VId, Cost>
+where
+    VId: Copy + Eq + Hash,
+    Cost: Copy + HeapWeight + PartialOrd + Add<Output = Cost> + Default,
+{
+    fn new(start: VId) -> Self {
+        let mut costs = FxHashMap::default();
+        costs.insert(start, Cost::default());
+        UpwardSearch {
+            costs,
+            predecessors: FxHashMap::default(),
+            visited: FxHashSet::default(),
+            visit_next: BinaryHeap::from([Reverse(EdgeEntry::new(Cost::default(), start))]),
+        }
+    }
+
+    /// The cost of the next vertex to settle, `None` if the search is exhausted
+    fn next_cost(&self) -> Option<Cost> {
+        self.visit_next
+            .peek()
+            .map(|Reverse(node_entry)| node_entry.cost)
+    }
+
+    /// Settles the next vertex and returns it with its cost, `None` if it was already settled
+    fn settle_next(&mut self, upward: &FxHashMap<VId, Vec<(VId, Cost)>>) -> Option<(VId, Cost)> {
+        let Reverse(node_entry) = self.visit_next.pop()?;
+        let current = node_entry.vertex_id;
+        if !self.visited.insert(current) {
+            return None;
+        }
+
+        for &(next_v, edge_cost) in upward.get(&current).into_iter().flatten() {
+            let new_cost = node_entry.cost + edge_cost;
+            let improves = self
+                .costs
+                .get(&next_v)
+                .is_none_or(|existing_cost| new_cost < *existing_cost);
+            if improves {
+                self.costs.insert(next_v, new_cost);
+                self.predecessors.insert(next_v, current);
+                self.visit_next
+                    .push(Reverse(EdgeEntry::new(new_cost, next_v)));
+            }
+        }
+
+        Some((current, node_entry.cost))
+    }
+}
+
+fn insert_undirected<VId: Copy + Eq + Hash, Cost: Copy>(
+    remaining: &mut FxHashMap<VId, FxHashMap<VId, Cost>>,
+    from: VId,
+    to: VId,
+    cost: Cost,
+) {
+    for (a, b) in [(from, to), (to, from)] {
+        remaining
+            .get_mut(&a)
+            .expect("Edges only connect remaining vertices")
+            .insert(b, cost);
+    }
+}
+
+/// Returns the shortcuts `(from, to, cost)` that are needed when contracting `vertex_id`
+fn shortcuts<VId, Cost>(
+    remaining: &FxHashMap<VId, FxHashMap<VId, Cost>>,
+    vertex_id: VId,
+) -> Vec<(VId, VId, Cost)>
+where
+    VId: Copy + Ord + Hash,
+    Cost: Copy + HeapWeight + PartialOrd + Add<Output = Cost> + Default,
+{
+    let mut neighbors = remaining[&vertex_id]
+        .iter()
+        .map(|(vid, cost)| (*vid, *cost))
+        .collect::<Vec<_>>();
+    neighbors.sort_unstable_by_key(|(vid, _)| *vid);
+
+    let mut shortcuts = Vec::new();
+    for (i, &(from, from_cost)) in neighbors.iter().enumerate() {
+        let targets = &neighbors[i + 1..];
+        let Some(max_cost) = targets
+            .iter()
+            .map(|(_, to_cost)| from_cost + *to_cost)
+            .max_by(HeapWeight::heap_cmp)
+        else {
+            continue;
+        };
+
+        let witness_costs = witness_search(remaining, from, vertex_id, max_cost);
+        for &(to, to_cost) in targets {
+            let cost = from_cost + to_cost;
+            if witness_costs
+                .get(&to)
+                .is_none_or(|witness_cost| cost < *witness_cost)
+            {
+                shortcuts.push((from, to, cost));
+            }
+        }
+    }
+    shortcuts
+}
+
+/// Searches paths from `start` that avoid `excluded` and cost at most `max_cost`.
+///
+/// Returns the costs of all found paths, which might not be the shortest ones as the search stops early.
+fn witness_search<VId, Cost>(
+    remaining: &FxHashMap<VId, FxHashMap<VId, Cost>>,
+    start: VId,
+    excluded: VId,
+    max_cost: Cost,
+) -> FxHashMap<VId, Cost>
+where
+    VId: Copy + Eq + Hash,
+    Cost: Copy + HeapWeight + PartialOrd + Add<Output = Cost> + Default,
+{
+    let mut costs = FxHashMap::default();
+    let mut visited = FxHashSet::default();
+    let mut visit_next = BinaryHeap::new();
+
+    costs.insert(start, Cost::default());
+    visit_next.push(Reverse(EdgeEntry::new(Cost::default(), start)));
+
+    while let Some(Reverse(node_entry)) = visit_next.pop() {
+        let current = node_entry.vertex_id;
+        if node_entry.cost > max_cost || visited.len() >= WITNESS_SEARCH_LIMIT {
+            break;
+        }
+        if !visited.insert(current) {
+            continue;
+        }
+
+        for (&next_v, &edge_cost) in &remaining[&current] {
+            if next_v == excluded {
+                continue;
+            }
+            let new_cost = node_entry.cost + edge_cost;
+            let improves = costs
+                .get(&next_v)
+                .is_none_or(|existing_cost| new_cost < *existing_cost);
+            if improves {
+                costs.insert(next_v, new_cost);
+                visit_next.push(Reverse(EdgeEntry::new(new_cost, next_v)));
+            }
+        }
+    }
+
+    costs
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Graph, ListGraph, Undirected,
+    };
+
+    #[rstest]
+    fn test_ch_query_on_grid(
+        #[values(
+            ListGraph::<MockVertex, u32, Undirected>::new(),
+            MatrixGraph::<MockVertex, u32, Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = u32, Direction = Undirected>,
+        >,
+    ) {
+        // A 4x4 grid with varying weights, vertex 16 is isolated
+        for id in 0..17 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for row in 0..4 {
+            for col in 0..4 {
+                let id = row * 4 + col;
+                if col < 3 {
+                    graph.push_edge(id, id + 1, (id % 3 + 1) as u32).unwrap();
+                }
+                if row < 3 {
+                    graph.push_edge(id, id + 4, (id % 5 + 1) as u32).unwrap();
+                }
+            }
+        }
+
+        let hierarchy = graph.contraction_hierarchies();
+
+        for start in 0..16 {
+            let shortest_paths = graph.dijkstra(start, None);
+            for goal in 0..16 {
+                let (cost, path) = hierarchy.ch_query(start, goal).unwrap();
+                assert_eq!(Some(cost), shortest_paths.get_cost(goal));
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&goal));
+                assert_eq!(graph.path_cost(&path).unwrap(), cost);
+            }
+        }
+        assert_eq!(hierarchy.ch_query(0, 16), None);
+        assert_eq!(hierarchy.ch_query(16, 16), Some((0, vec![16])));
+        assert_eq!(hierarchy.ch_query(0, 42), None);
+    }
+
+    #[test]
+    fn test_ch_query_matches_dijkstra_on_random_graphs() {
+        let mut rng = StdRng::seed_from_u64(42);
+        // The dense graphs mostly end up in the core
+        for density in [0.1, 0.5].repeat(5) {
+            let mut graph = ListGraph::<MockVertex, u32, Undirected>::new();
+            for id in 0..30 {
+                graph.push_vertex(MockVertex { id }).unwrap();
+            }
+            for from in 0..30 {
+                for to in from + 1..30 {
+                    if rng.random_bool(density) {
+                        graph.push_edge(from, to, rng.random_range(1..10)).unwrap();
+                    }
+                }
+            }
+
+            let hierarchy = graph.contraction_hierarchies();
+
+            for start in 0..30 {
+                let shortest_paths = graph.dijkstra(start, None);
+                for goal in 0..30 {
+                    assert_eq!(
+                        hierarchy.ch_query(start, goal).map(|(cost, _)| cost),
+                        shortest_paths.get_cost(goal)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WeightedEdge, WithID},
+    Directed, Graph, GraphError,
+};
+
+use super::single_source_shortest_paths::SingleSourceShortestPaths;
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Directed>,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy,
+{
+    /// Computes the shortest paths from `start` to every reachable vertex of a directed acyclic graph (DAG).
+    ///
+    /// Relaxes the outgoing edges of every vertex in [topological order](Graph::topological_sort),
+    /// so every vertex has its final cost once its edges are relaxed. This runs in O(n + m),
+    /// compared to O((n + m) log n) for [`Graph::dijkstra`], and unlike Dijkstra, negative edge weights are allowed.
+    /// Note that the topological sort always covers the whole graph, so Dijkstra can still be faster
+    /// if only a small part of the graph is reachable from `start`.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: if `start` does not exist.
+    /// - `GraphError::CycleDetected`: if the graph contains a cycle, even if it is not reachable from `start`.
+    #[allow(clippy::type_complexity)]
+    pub fn dag_shortest_paths(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+    ) -> Result<
+        SingleSourceShortestPaths<
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Edge as WeightedEdge>::WeightType,
+        >,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        if self.get_vertex_by_id(start).is_none() {
+            return Err(GraphError::VertexNotFound(start));
+        }
+        let order = self.topological_sort()?;
+
+        let mut costs = FxHashMap::default();
+        let mut predecessors = FxHashMap::default();
+        costs.insert(
+            start,
+            <Backend::Edge as WeightedEdge>::WeightType::default(),
+        );
+
+        // The vertices before `start` cannot be reached from it
+        for vid in order.into_iter().skip_while(|&vid| vid != start) {
+            let Some(&cost) = costs.get(&vid) else {
+                continue;
+            };
+
+            for (neighbor, edge) in self.get_adjacent_vertices_with_edges(vid) {
+                let neighbor_id = neighbor.get_id();
+                let neighbor_cost = cost + edge.get_weight();
+                if costs
+                    .get(&neighbor_id)
+                    .is_none_or(|&known_cost| neighbor_cost < known_cost)
+                {
+                    costs.insert(neighbor_id, neighbor_cost);
+                    predecessors.insert(neighbor_id, vid);
+                }
+            }
+        }
+
+        Ok(SingleSourceShortestPaths::new(start, costs, predecessors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, GraphError, ListGraph,
+    };
+
+    #[rstest]
+    fn test_dag_shortest_paths_negative_edges(
+        #[values(
+            ListGraph::<MockVertex, i32, Directed>::new(),
+            MatrixGraph::<MockVertex, i32, Directed>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = i32, Direction = Directed>,
+        >,
+    ) {
+        // 1 -> 2 -> 4 is only cheaper than 1 -> 3 -> 4 because of the negative edge,
+        // 0 comes before the start and cannot be reached from it
+        for id in 0..6 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to, weight) in [
+            (0, 1, 1),
+            (1, 2, 3),
+            (1, 3, 1),
+            (2, 4, -4),
+            (3, 4, 2),
+            (4, 5, 1),
+        ] {
+            graph.push_edge(from, to, weight).unwrap();
+        }
+
+        let shortest_paths = graph.dag_shortest_paths(1).unwrap();
+
+        assert_eq!(shortest_paths.get_cost(1), Some(0));
+        assert_eq!(shortest_paths.get_cost(4), Some(-1));
+        assert_eq!(shortest_paths.get_path(4), vec![1, 2, 4]);
+        assert_eq!(shortest_paths.get_cost(5), Some(0));
+        assert_eq!(shortest_paths.get_cost(0), None);
+        assert!(shortest_paths.get_path(0).is_empty());
+    }
+
+    #[test]
+    fn test_dag_shortest_paths_matches_dijkstra() {
+        // Edges only point from lower to higher IDs, so the graph is acyclic
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut graph = ListGraph::<MockVertex, u32, Directed>::new();
+        for id in 0..50 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for from in 0..50 {
+            for to in from + 1..50 {
+                if rng.random_bool(0.2) {
+                    graph.push_edge(from, to, rng.random_range(0..100)).unwrap();
+                }
+            }
+        }
+
+        for start in [0, 10, 49] {
+            let dag_paths = graph.dag_shortest_paths(start).unwrap();
+            let dijkstra_paths = graph.dijkstra(start, None);
+
+            for id in 0..50 {
+                assert_eq!(dag_paths.get_cost(id), dijkstra_paths.get_cost(id));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dag_shortest_paths_rejects_cycles() {
+        let graph = ListGraph::<MockVertex, i32, Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1), (2, 3, 1), (3, 2, 1)],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            graph.dag_shortest_paths(0),
+            Err(GraphError::CycleDetected)
+        ));
+        assert!(matches!(
+            graph.dag_shortest_paths(42),
+            Err(GraphError::VertexNotFound(42))
+        ));
+    }
+}
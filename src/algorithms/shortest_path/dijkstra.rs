@@ -3,7 +3,7 @@ use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
 
 use crate::{
-    graph::{GraphBase, WeightedEdge, WithID},
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
     Graph,
 };
 
@@ -14,7 +14,7 @@ where
     Backend: GraphBase,
     <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
     Backend::Edge: WeightedEdge + Clone,
-    <Backend::Edge as WeightedEdge>::WeightType: Copy,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy + HeapWeight,
 {
     /// Dijkstra's shortest path algorithm.
     ///
@@ -36,6 +36,119 @@ where
         <Backend::Vertex as WithID>::IDType,
         <Backend::Edge as WeightedEdge>::WeightType,
     > {
+        // If we are visiting the goal node, we can early stop as we already computed the shortest path to it
+        self.dijkstra_until(
+            start,
+            |vertex_id| goal.as_ref() == Some(&vertex_id),
+            |_, _, edge| edge.get_weight(),
+        )
+    }
+
+    /// Dijkstra's shortest path algorithm, where every edge costs its weight plus `penalty(from, to, edge)`.
+    ///
+    /// Allows steering the routes without modifying the graph, e.g. a large penalty avoids toll roads
+    /// unless there is no other way. The costs of the result include the penalties.
+    /// Stops early once `goal` is settled, like [`Graph::dijkstra`].
+    ///
+    /// # Warning
+    /// Like [`Graph::dijkstra`], this only works if the weights plus penalties are non-negative.
+    pub fn dijkstra_with_edge_penalty<F>(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        goal: Option<<Backend::Vertex as WithID>::IDType>,
+        penalty: F,
+    ) -> SingleSourceShortestPaths<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    >
+    where
+        F: Fn(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+            &Backend::Edge,
+        ) -> <Backend::Edge as WeightedEdge>::WeightType,
+    {
+        self.dijkstra_until(
+            start,
+            |vertex_id| goal.as_ref() == Some(&vertex_id),
+            |from, to, edge| edge.get_weight() + penalty(from, to, edge),
+        )
+    }
+
+    /// Dijkstra's shortest path algorithm that stops as soon as all `targets` are settled.
+    ///
+    /// The costs and paths to every target are final, while the costs to other vertices
+    /// might only be upper bounds if the search stopped early.
+    /// Unreachable targets cause a full search, just like [`Graph::dijkstra`] without a goal.
+    pub fn dijkstra_multi_target(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        targets: &[<Backend::Vertex as WithID>::IDType],
+    ) -> SingleSourceShortestPaths<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    > {
+        let mut remaining = targets.iter().copied().collect::<FxHashSet<_>>();
+        self.dijkstra_until(
+            start,
+            |vertex_id| {
+                remaining.remove(&vertex_id);
+                remaining.is_empty()
+            },
+            |_, _, edge| edge.get_weight(),
+        )
+    }
+
+    /// Returns the candidate closest to `start` (by shortest path cost) together with its cost.
+    ///
+    /// The search stops as soon as the first candidate is settled.
+    /// Returns `None` if no candidate is reachable from `start`.
+    #[allow(clippy::type_complexity)]
+    pub fn nearest_of(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        candidates: &[<Backend::Vertex as WithID>::IDType],
+    ) -> Option<(
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    )> {
+        let candidates = candidates.iter().copied().collect::<FxHashSet<_>>();
+        let mut nearest = None;
+        let shortest_paths = self.dijkstra_until(
+            start,
+            |vertex_id| {
+                if candidates.contains(&vertex_id) {
+                    nearest = Some(vertex_id);
+                }
+                nearest.is_some()
+            },
+            |_, _, edge| edge.get_weight(),
+        );
+
+        nearest.and_then(|vid| shortest_paths.get_cost(vid).map(|cost| (vid, cost)))
+    }
+
+    /// Runs Dijkstra from `start` until `is_done` returns `true` for a settled vertex.
+    ///
+    /// `is_done` is called once for every vertex when its shortest path cost is final.
+    /// `edge_cost` gives the cost of traversing an edge from one vertex to the other.
+    fn dijkstra_until<F, C>(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        mut is_done: F,
+        edge_cost: C,
+    ) -> SingleSourceShortestPaths<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    >
+    where
+        F: FnMut(<Backend::Vertex as WithID>::IDType) -> bool,
+        C: Fn(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+            &Backend::Edge,
+        ) -> <Backend::Edge as WeightedEdge>::WeightType,
+    {
         // Final map of costs from start to each v
         let mut costs = FxHashMap::default();
         // Which vertex was visited before each other. Can be used to reconstruct the exact path
@@ -62,8 +175,8 @@ where
                 continue;
             }
 
-            // If we are visiting the goal node, we can early stop as we already computed the shortest path to it
-            if goal.as_ref() == Some(&node_entry.vertex_id) {
+            // The cost to the current vertex is final, so we can early stop if the caller is done
+            if is_done(node_entry.vertex_id) {
                 break;
             }
 
@@ -73,7 +186,7 @@ where
                 .map(|(v, e)| (v.get_id(), e))
                 .filter(|(v, _e)| !visited.contains(v))
             {
-                let new_cost = node_entry.cost + edge.get_weight();
+                let new_cost = node_entry.cost + edge_cost(node_entry.vertex_id, next_v, edge);
                 match costs.entry(next_v) {
                     Occupied(existing_entry) => {
                         // Check if we the cost to `next_v` can be improved
@@ -99,35 +212,33 @@ where
 }
 
 /// Helper struct for Min-Heap behavior if weights are floats or need custom ordering
-struct EdgeEntry<W: PartialOrd, VId> {
-    cost: W,
-    vertex_id: VId,
+pub(super) struct EdgeEntry<W: HeapWeight, VId> {
+    pub(super) cost: W,
+    pub(super) vertex_id: VId,
 }
 
-impl<W: PartialOrd, VId> EdgeEntry<W, VId> {
+impl<W: HeapWeight, VId> EdgeEntry<W, VId> {
     pub fn new(cost: W, vertex_id: VId) -> Self {
         EdgeEntry { cost, vertex_id }
     }
 }
 
-impl<W: PartialOrd, VId> PartialEq for EdgeEntry<W, VId> {
+impl<W: HeapWeight, VId> PartialEq for EdgeEntry<W, VId> {
     fn eq(&self, other: &Self) -> bool {
-        self.cost == other.cost
+        self.cost.heap_cmp(&other.cost) == std::cmp::Ordering::Equal
     }
 }
 
-impl<W: PartialOrd, VId> Eq for EdgeEntry<W, VId> {}
+impl<W: HeapWeight, VId> Eq for EdgeEntry<W, VId> {}
 
-impl<W: PartialOrd, VId> PartialOrd for EdgeEntry<W, VId> {
+impl<W: HeapWeight, VId> PartialOrd for EdgeEntry<W, VId> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<W: PartialOrd, VId> Ord for EdgeEntry<W, VId> {
+impl<W: HeapWeight, VId> Ord for EdgeEntry<W, VId> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.cost
-            .partial_cmp(&other.cost)
-            .expect("Graph weights must not contain NaN values")
+        self.cost.heap_cmp(&other.cost)
     }
 }
@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Into<usize> + From<usize>,
+{
+    /// Computes the hop distances (number of edges on a shortest path) between all pairs of vertices,
+    /// ignoring edge weights.
+    ///
+    /// `matrix[from][to]` is the distance from `from` to `to`, or `None` if `to` is not reachable.
+    /// Runs a BFS from every vertex, so it is only meant for small graphs (O(n * (n + m)) time, O(n²) memory).
+    ///
+    /// # Requirements
+    /// The vertex IDs must be compact, i.e. form the range `0..n` (as for the adjacency matrix backend).
+    ///
+    /// # Panics
+    /// Panics if a vertex ID is not smaller than the number of vertices.
+    pub fn distance_matrix(&self) -> Vec<Vec<Option<usize>>> {
+        let n = self.vertex_count();
+
+        (0..n)
+            .map(|start| {
+                let mut distances = vec![None; n];
+                distances[start] = Some(0);
+                let mut queue = VecDeque::from([start]);
+
+                while let Some(current) = queue.pop_front() {
+                    let next_distance = distances[current].map(|d| d + 1);
+                    for neighbor in self.get_adjacent_vertices(current.into()) {
+                        let neighbor_idx: usize = neighbor.get_id().into();
+                        if distances[neighbor_idx].is_none() {
+                            distances[neighbor_idx] = next_distance;
+                            queue.push_back(neighbor_idx);
+                        }
+                    }
+                }
+
+                distances
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, ListGraph, Undirected,
+    };
+
+    #[rstest]
+    fn test_distance_matrix_cycle(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let distances = graph.distance_matrix();
+
+        assert_eq!(
+            distances,
+            vec![
+                vec![Some(0), Some(1), Some(2), Some(1)],
+                vec![Some(1), Some(0), Some(1), Some(2)],
+                vec![Some(2), Some(1), Some(0), Some(1)],
+                vec![Some(1), Some(2), Some(1), Some(0)],
+            ]
+        );
+        for (from, row) in distances.iter().enumerate() {
+            for (to, distance) in row.iter().enumerate() {
+                assert_eq!(*distance, distances[to][from]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_distance_matrix_unreachable() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ())],
+        )
+        .unwrap();
+
+        let distances = graph.distance_matrix();
+
+        assert_eq!(distances[0], vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(distances[2], vec![None, None, Some(0)]);
+    }
+}
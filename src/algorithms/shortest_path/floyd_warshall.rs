@@ -0,0 +1,200 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, WeightedEdge, WithID},
+    Graph,
+};
+
+/// The shortest paths between all pairs of vertices, as computed by [`Graph::floyd_warshall`]
+///
+/// - `costs[i][j]` is the cost of the shortest path from the `i`-th to the `j`-th vertex, `None` if unreachable
+/// - `predecessors[i][j]` is the index of the predecessor of the `j`-th vertex on that path
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllPairsShortestPaths<VId: Hash + Eq, Cost> {
+    indices: FxHashMap<VId, usize>,
+    vertex_ids: Vec<VId>,
+    costs: Vec<Vec<Option<Cost>>>,
+    predecessors: Vec<Vec<Option<usize>>>,
+}
+
+impl<VId, Cost> AllPairsShortestPaths<VId, Cost>
+where
+    VId: Hash + Eq + Copy,
+    Cost: Copy,
+{
+    /// Gets the cost of the shortest path from `from` to `to`
+    ///
+    /// Returns `None` if `to` is not reachable from `from` or one of them is not part of the graph.
+    pub fn get_cost(&self, from: VId, to: VId) -> Option<Cost> {
+        let (from, to) = (*self.indices.get(&from)?, *self.indices.get(&to)?);
+        self.costs[from][to]
+    }
+
+    /// Reconstruct the (shortest) path that is taken to get from `from` to `to`
+    ///
+    /// Returns an empty path if `to` is not reachable from `from`.
+    pub fn get_path(&self, from: VId, to: VId) -> Vec<VId> {
+        let (Some(&from_idx), Some(&to_idx)) = (self.indices.get(&from), self.indices.get(&to))
+        else {
+            return vec![];
+        };
+        if self.costs[from_idx][to_idx].is_none() {
+            return vec![];
+        }
+
+        // Walk backwards from `to` using the predecessors of the row of `from`
+        let mut path = vec![to];
+        let mut current = to_idx;
+        while current != from_idx {
+            match self.predecessors[from_idx][current] {
+                // A path visits every vertex at most once (unless there is a negative cycle)
+                Some(pred) if path.len() <= self.vertex_ids.len() => {
+                    path.push(self.vertex_ids[pred]);
+                    current = pred;
+                }
+                _ => return vec![],
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy,
+{
+    /// Floyd-Warshall's all-pairs shortest path algorithm.
+    ///
+    /// Computes the costs and paths between all pairs of vertices in O(n³) time and O(n²) memory,
+    /// so it is meant for small and dense graphs. Unlike [`Graph::dijkstra`], negative edge weights are allowed.
+    ///
+    /// # Warning
+    /// The graph must not contain a negative cycle, otherwise the result is incorrect.
+    /// Note that in an undirected graph, every negative edge forms a negative cycle (walking it back and forth).
+    #[allow(clippy::type_complexity)]
+    pub fn floyd_warshall(
+        &self,
+    ) -> AllPairsShortestPaths<
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Edge as WeightedEdge>::WeightType,
+    > {
+        let vertex_ids = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<Vec<_>>();
+        let indices = vertex_ids
+            .iter()
+            .enumerate()
+            .map(|(idx, &vid)| (vid, idx))
+            .collect::<FxHashMap<_, _>>();
+        let n = vertex_ids.len();
+
+        let mut costs = vec![vec![None; n]; n];
+        let mut predecessors = vec![vec![None; n]; n];
+        for (idx, row) in costs.iter_mut().enumerate() {
+            row[idx] = Some(<Backend::Edge as WeightedEdge>::WeightType::default());
+        }
+        for (from, to, edge) in self.get_all_edges() {
+            let (from, to) = (indices[&from], indices[&to]);
+            let weight = edge.get_weight();
+            // Keep the cheapest of parallel edges (and negative self-loops)
+            if costs[from][to].is_none_or(|cost| weight < cost) {
+                costs[from][to] = Some(weight);
+                predecessors[from][to] = Some(from);
+            }
+            // Undirected edges are only returned once
+            if !self.is_directed() && costs[to][from].is_none_or(|cost| weight < cost) {
+                costs[to][from] = Some(weight);
+                predecessors[to][from] = Some(to);
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                let Some(cost_ik) = costs[i][k] else {
+                    continue;
+                };
+                for j in 0..n {
+                    let Some(cost_kj) = costs[k][j] else {
+                        continue;
+                    };
+                    let cost_via_k = cost_ik + cost_kj;
+                    if costs[i][j].is_none_or(|cost| cost_via_k < cost) {
+                        costs[i][j] = Some(cost_via_k);
+                        predecessors[i][j] = predecessors[k][j];
+                    }
+                }
+            }
+        }
+
+        AllPairsShortestPaths {
+            indices,
+            vertex_ids,
+            costs,
+            predecessors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, ListGraph, Undirected,
+    };
+
+    #[rstest]
+    fn test_floyd_warshall_negative_edge(
+        #[values(
+            ListGraph::<MockVertex, i32, Directed>::new(),
+            MatrixGraph::<MockVertex, i32, Directed>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = i32, Direction = Directed>,
+        >,
+    ) {
+        // 0 -> 1 -> 3 is cheaper than 0 -> 2 -> 3 only because of the negative edge, 4 is unreachable
+        for id in 0..5 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to, weight) in [(0, 1, 4), (1, 3, -3), (0, 2, 1), (2, 3, 1), (3, 0, 2)] {
+            graph.push_edge(from, to, weight).unwrap();
+        }
+
+        let shortest_paths = graph.floyd_warshall();
+
+        assert_eq!(shortest_paths.get_cost(0, 3), Some(1));
+        assert_eq!(shortest_paths.get_path(0, 3), vec![0, 1, 3]);
+        assert_eq!(shortest_paths.get_cost(1, 2), Some(0));
+        assert_eq!(shortest_paths.get_path(1, 2), vec![1, 3, 0, 2]);
+        assert_eq!(shortest_paths.get_cost(2, 2), Some(0));
+        assert_eq!(shortest_paths.get_path(2, 2), vec![2]);
+        assert_eq!(shortest_paths.get_cost(0, 4), None);
+        assert!(shortest_paths.get_path(0, 4).is_empty());
+        assert_eq!(shortest_paths.get_cost(0, 42), None);
+    }
+
+    #[test]
+    fn test_floyd_warshall_undirected() {
+        let graph = ListGraph::<MockVertex, u32, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 5)],
+        )
+        .unwrap();
+
+        let shortest_paths = graph.floyd_warshall();
+
+        assert_eq!(shortest_paths.get_cost(0, 3), Some(3));
+        assert_eq!(shortest_paths.get_cost(3, 0), Some(3));
+        assert_eq!(shortest_paths.get_path(3, 0), vec![3, 2, 1, 0]);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::{GraphBase, HeapWeight, WeightedEdge, WithID},
+    Graph,
+};
+
+use super::dijkstra::EdgeEntry;
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy + HeapWeight,
+{
+    /// Computes the `k` cheapest walks from `start` to `goal` in non-decreasing cost order.
+    ///
+    /// Unlike simple paths, walks may repeat vertices and edges (e.g. go around a cheap cycle).
+    /// Works like Dijkstra, but a vertex is settled up to `k` times instead of once:
+    /// the i-th time a vertex is popped from the queue, the i-th cheapest walk to it was found.
+    /// Limiting the expansions to `k` per vertex bounds the memory to O(k * m).
+    ///
+    /// Returns less than `k` walks if there are not enough, and no walks if a vertex does not exist.
+    /// If `start == goal`, the first walk is `[start]` with cost 0.
+    ///
+    /// # Warning
+    /// Like [`Graph::dijkstra`], this only works with non-negative weights.
+    #[allow(clippy::type_complexity)]
+    pub fn k_shortest_walks(
+        &self,
+        start: <Backend::Vertex as WithID>::IDType,
+        goal: <Backend::Vertex as WithID>::IDType,
+        k: usize,
+    ) -> Vec<(
+        <Backend::Edge as WeightedEdge>::WeightType,
+        Vec<<Backend::Vertex as WithID>::IDType>,
+    )> {
+        let mut walks = Vec::new();
+        if k == 0 || self.get_vertex_by_id(start).is_none() || self.get_vertex_by_id(goal).is_none()
+        {
+            return walks;
+        }
+
+        // All partial walks as (last vertex, index of the walk without the last vertex)
+        let mut walk_steps = vec![(start, None)];
+        let mut expansions = FxHashMap::<_, usize>::default();
+        // The queue entries refer to an index of `walk_steps` instead of a vertex
+        let mut visit_next = BinaryHeap::from([Reverse(EdgeEntry::new(
+            <Backend::Edge as WeightedEdge>::WeightType::default(),
+            0,
+        ))]);
+
+        while let Some(Reverse(entry)) = visit_next.pop() {
+            let (vertex_id, _) = walk_steps[entry.vertex_id];
+            let count = expansions.entry(vertex_id).or_default();
+            if *count == k {
+                continue;
+            }
+            *count += 1;
+
+            if vertex_id == goal {
+                walks.push((entry.cost, reconstruct_walk(&walk_steps, entry.vertex_id)));
+                if walks.len() == k {
+                    break;
+                }
+            }
+
+            for (next_v, edge) in self.get_adjacent_vertices_with_edges(vertex_id) {
+                let next_v = next_v.get_id();
+                if expansions.get(&next_v).is_some_and(|count| *count == k) {
+                    continue;
+                }
+                walk_steps.push((next_v, Some(entry.vertex_id)));
+                visit_next.push(Reverse(EdgeEntry::new(
+                    entry.cost + edge.get_weight(),
+                    walk_steps.len() - 1,
+                )));
+            }
+        }
+
+        walks
+    }
+}
+
+/// Follows the walk steps back from `index` to the start
+fn reconstruct_walk<VId: Copy>(walk_steps: &[(VId, Option<usize>)], index: usize) -> Vec<VId> {
+    let mut walk = Vec::new();
+    let mut current = Some(index);
+    while let Some(index) = current {
+        let (vertex_id, previous) = walk_steps[index];
+        walk.push(vertex_id);
+        current = previous;
+    }
+    walk.reverse();
+    walk
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{graph::GraphBase, test_utils::MockVertex, Directed, ListGraph};
+
+    #[test]
+    fn test_k_shortest_walks_reuse_cheap_cycle() {
+        // 0 -> 1 -> 3 with the cheap cycle 1 -> 2 -> 1 and the direct edge 0 -> 3
+        let graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, 1.0),
+                (1, 3, 1.0),
+                (1, 2, 0.5),
+                (2, 1, 0.5),
+                (0, 3, 2.5),
+            ],
+        )
+        .unwrap();
+
+        let walks = graph.k_shortest_walks(0, 3, 4);
+
+        assert_eq!(
+            walks,
+            vec![
+                (2.0, vec![0, 1, 3]),
+                (2.5, vec![0, 3]),
+                (3.0, vec![0, 1, 2, 1, 3]),
+                (4.0, vec![0, 1, 2, 1, 2, 1, 3]),
+            ]
+        );
+        assert!(walks.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(graph.k_shortest_walks(0, 3, 1), vec![(2.0, vec![0, 1, 3])]);
+    }
+
+    #[test]
+    fn test_k_shortest_walks_without_enough_walks() {
+        let graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1.0), (1, 2, 1.0)],
+        )
+        .unwrap();
+
+        assert_eq!(graph.k_shortest_walks(0, 2, 3), vec![(2.0, vec![0, 1, 2])]);
+        assert_eq!(graph.k_shortest_walks(0, 0, 3), vec![(0.0, vec![0])]);
+        assert!(graph.k_shortest_walks(2, 0, 3).is_empty());
+        assert!(graph.k_shortest_walks(0, 42, 3).is_empty());
+        assert!(graph.k_shortest_walks(0, 2, 0).is_empty());
+    }
+}
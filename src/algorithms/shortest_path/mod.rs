@@ -1,3 +1,10 @@
+pub mod alt;
 pub mod bellman_ford;
+pub mod contraction_hierarchies;
+pub mod dag_shortest_paths;
 pub mod dijkstra;
+pub mod distance_matrix;
+pub mod floyd_warshall;
+pub mod k_shortest_walks;
 mod single_source_shortest_paths;
+pub mod zero_one_bfs;
@@ -2,6 +2,11 @@ use std::hash::Hash;
 
 use rustc_hash::FxHashMap;
 
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph,
+};
+
 /// A structure that holds information about the shortest path in a graph
 ///
 /// - `costs` is a `HashMap` that maps from vertex id to path costs
@@ -58,6 +63,31 @@ where
         path.reverse();
         path
     }
+
+    /// Reconstruct the (shortest) path from the start vertex to `target` as a sequence of edges
+    /// `(from, to, edge)`, with the edge data fetched from `graph`.
+    ///
+    /// `graph` must be the graph the shortest paths were computed on.
+    /// Returns an empty `Vec` if `target` is not reachable or equals the start vertex.
+    pub fn get_path_edges<'a, Backend>(
+        &self,
+        graph: &'a Graph<Backend>,
+        target: VId,
+    ) -> Vec<(VId, VId, &'a Backend::Edge)>
+    where
+        Backend: GraphBase,
+        Backend::Vertex: WithID<IDType = VId>,
+    {
+        self.get_path(target)
+            .windows(2)
+            .map(|pair| {
+                let edge = graph
+                    .get_edge(pair[0], pair[1])
+                    .expect("Edges on the shortest path must exist in the graph");
+                (pair[0], pair[1], edge)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
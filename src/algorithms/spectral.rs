@@ -0,0 +1,191 @@
+use crate::{
+    graph::{GraphBase, WeightedEdge, WithID},
+    Graph, Undirected,
+};
+
+/// Maximum number of sweeps of the Jacobi eigenvalue algorithm
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Into<f64>,
+    <Backend::Vertex as WithID>::IDType: Copy + Into<usize>,
+{
+    /// Returns the algebraic connectivity (Fiedler value) of the graph:
+    /// the second-smallest eigenvalue of its [Laplacian matrix](Graph::laplacian_matrix).
+    ///
+    /// It is greater than zero if and only if the graph is connected, larger values indicate a better connected graph.
+    /// The eigenvalues are computed with the Jacobi eigenvalue algorithm (O(n³) per sweep),
+    /// so it is only meant for small graphs and the result is subject to floating point errors.
+    ///
+    /// Returns `None` for graphs with less than two vertices.
+    ///
+    /// # Requirements
+    /// The vertex IDs must be compact, i.e. form the range `0..n` (as for the adjacency matrix backend).
+    pub fn algebraic_connectivity(&self) -> Option<f64> {
+        let mut eigenvalues = symmetric_eigenvalues(self.laplacian_matrix());
+        eigenvalues.sort_unstable_by(f64::total_cmp);
+        eigenvalues.get(1).copied()
+    }
+
+    /// Counts the spanning trees of the graph with Kirchhoff's matrix-tree theorem:
+    /// the count equals the determinant of the [Laplacian matrix](Graph::laplacian_matrix)
+    /// with the first row and column removed.
+    ///
+    /// For weighted graphs, this is the sum of the products of the edge weights over all spanning trees
+    /// (which is the plain count for unit weights). Returns 0.0 for disconnected graphs and for the empty graph.
+    ///
+    /// The determinant is computed with Gaussian elimination in floating point arithmetic (O(n³)),
+    /// so the result is not exact: round it for unit weights, and expect precision loss or overflow to infinity
+    /// for large graphs, where the count grows exponentially.
+    ///
+    /// # Requirements
+    /// The vertex IDs must be compact, i.e. form the range `0..n` (as for the adjacency matrix backend).
+    pub fn spanning_tree_count(&self) -> f64 {
+        if self.vertex_count() == 0 {
+            return 0.0;
+        }
+
+        let cofactor = self
+            .laplacian_matrix()
+            .into_iter()
+            .skip(1)
+            .map(|row| row.into_iter().skip(1).collect())
+            .collect();
+        determinant(cofactor)
+    }
+}
+
+/// Computes the determinant of a square matrix with Gaussian elimination and partial pivoting.
+fn determinant(mut matrix: Vec<Vec<f64>>) -> f64 {
+    let n = matrix.len();
+    let mut determinant = 1.0;
+
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|a, b| {
+                matrix[*a][column]
+                    .abs()
+                    .total_cmp(&matrix[*b][column].abs())
+            })
+            .expect("The range is not empty");
+        if matrix[pivot][column] == 0.0 {
+            return 0.0;
+        }
+        if pivot != column {
+            matrix.swap(pivot, column);
+            determinant = -determinant;
+        }
+
+        let pivot_row = matrix[column].clone();
+        determinant *= pivot_row[column];
+        for row in matrix.iter_mut().skip(column + 1) {
+            let factor = row[column] / pivot_row[column];
+            for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row).skip(column) {
+                *entry -= factor * pivot_entry;
+            }
+        }
+    }
+
+    determinant
+}
+
+/// Computes the eigenvalues of a symmetric matrix with the cyclic Jacobi eigenvalue algorithm.
+fn symmetric_eigenvalues(mut matrix: Vec<Vec<f64>>) -> Vec<f64> {
+    let n = matrix.len();
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |j| i != *j).map(move |j| (i, j)))
+            .map(|(i, j)| matrix[i][j].powi(2))
+            .sum();
+        if off_diagonal < 1e-20 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if matrix[p][q].abs() < 1e-300 {
+                    continue;
+                }
+
+                // Rotation that eliminates matrix[p][q]
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in matrix.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (row_p, row_q) = (matrix[p].clone(), matrix[q].clone());
+                for (k, (pk, qk)) in row_p.into_iter().zip(row_q).enumerate() {
+                    matrix[p][k] = c * pk - s * qk;
+                    matrix[q][k] = s * pk + c * qk;
+                }
+            }
+        }
+    }
+
+    (0..n).map(|i| matrix[i][i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        test_utils::{build_graph_with_edge, MockVertex},
+        ListGraph, Undirected,
+    };
+
+    #[rstest]
+    #[case::path(3, vec![(0, 1), (1, 2)], Some(1.0))]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], Some(4.0))]
+    #[case::cycle(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)], Some(2.0))]
+    #[case::disconnected(4, vec![(0, 1), (2, 3)], Some(0.0))]
+    #[case::single_vertex(1, vec![], None)]
+    fn test_algebraic_connectivity(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: Option<f64>,
+    ) {
+        let graph: ListGraph<MockVertex, f64, Undirected> =
+            build_graph_with_edge(n_vertices, &edges, 1.0);
+
+        match (graph.algebraic_connectivity(), expected) {
+            (Some(actual), Some(expected)) => assert!(
+                (actual - expected).abs() < 1e-9,
+                "expected {expected}, got {actual}"
+            ),
+            (actual, expected) => assert_eq!(actual, expected),
+        }
+    }
+
+    #[rstest]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], 16.0)]
+    #[case::tree(5, vec![(0, 1), (0, 2), (2, 3), (2, 4)], 1.0)]
+    #[case::cycle(5, vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)], 5.0)]
+    #[case::disconnected(4, vec![(0, 1), (2, 3)], 0.0)]
+    #[case::single_vertex(1, vec![], 1.0)]
+    #[case::empty(0, vec![], 0.0)]
+    fn test_spanning_tree_count(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: f64,
+    ) {
+        let graph: ListGraph<MockVertex, f64, Undirected> =
+            build_graph_with_edge(n_vertices, &edges, 1.0);
+
+        let count = graph.spanning_tree_count();
+
+        assert!(
+            (count - expected).abs() < 1e-9,
+            "expected {expected}, got {count}"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    fmt::{self, Debug, Display},
+    hash::Hash,
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    algorithms::mst::union_find::UnionFind,
+    graph::{EdgeWeight, GraphBase, WeightedEdge, WithID},
+    Direction, Graph,
+};
+
+/// Summary statistics over the weights of all edges in a graph.
+///
+/// Created by [`Graph::edge_weight_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightStats {
+    pub min: EdgeWeight,
+    pub max: EdgeWeight,
+    pub mean: EdgeWeight,
+    pub sum: EdgeWeight,
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Edge: WeightedEdge<WeightType = EdgeWeight>,
+{
+    /// Computes the minimum, maximum, mean and sum of all edge weights in a single pass.
+    ///
+    /// For undirected graphs every edge is only counted once.
+    ///
+    /// Returns `None` if the graph does not contain any edges.
+    pub fn edge_weight_stats(&self) -> Option<WeightStats> {
+        let mut edge_count = 0;
+        let mut stats = WeightStats {
+            min: EdgeWeight::INFINITY,
+            max: EdgeWeight::NEG_INFINITY,
+            mean: 0.0,
+            sum: 0.0,
+        };
+
+        for (_, _, edge) in self.get_all_edges() {
+            let weight = edge.get_weight();
+            stats.min = stats.min.min(weight);
+            stats.max = stats.max.max(weight);
+            stats.sum += weight;
+            edge_count += 1;
+        }
+
+        if edge_count == 0 {
+            return None;
+        }
+
+        stats.mean = stats.sum / edge_count as EdgeWeight;
+        Some(stats)
+    }
+}
+
+/// A one-shot summary of the structure of a graph.
+///
+/// Created by [`Graph::stats`]. The `Display` implementation prints one statistic per line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphStats {
+    pub vertex_count: usize,
+    /// Number of edges, undirected edges are counted once
+    pub edge_count: usize,
+    /// Ratio of existing edges to the number of possible edges (without self-loops)
+    pub density: f64,
+    /// Minimum degree (in- plus out-degree for directed graphs), 0 for an empty graph
+    pub min_degree: usize,
+    /// Maximum degree (in- plus out-degree for directed graphs), 0 for an empty graph
+    pub max_degree: usize,
+    pub avg_degree: f64,
+    /// Number of (weakly) connected components
+    pub component_count: usize,
+    /// Whether the graph does not contain any cycle, `None` for directed graphs
+    pub is_forest: Option<bool>,
+    /// Whether the graph is a connected forest, `None` for directed graphs
+    pub is_tree: Option<bool>,
+}
+
+impl Display for GraphStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Vertices:   {}", self.vertex_count)?;
+        writeln!(f, "Edges:      {}", self.edge_count)?;
+        writeln!(f, "Density:    {:.5}", self.density)?;
+        writeln!(
+            f,
+            "Degree:     min {}, max {}, avg {:.2}",
+            self.min_degree, self.max_degree, self.avg_degree
+        )?;
+        write!(f, "Components: {}", self.component_count)?;
+        if let (Some(is_forest), Some(is_tree)) = (self.is_forest, self.is_tree) {
+            write!(f, "\nForest:     {is_forest}\nTree:       {is_tree}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash + Debug,
+{
+    /// Computes a [`GraphStats`] summary of the graph.
+    ///
+    /// Degrees, components and cycles are all collected in a single pass over the edges
+    /// (components and cycles with a union-find structure).
+    pub fn stats(&self) -> GraphStats {
+        let mut union_find = UnionFind::new();
+        let mut degrees = FxHashMap::default();
+        for v in self.get_all_vertices() {
+            union_find
+                .make_set(v.get_id())
+                .expect("Vertex IDs must be unique");
+            degrees.insert(v.get_id(), 0);
+        }
+
+        let mut edge_count = 0;
+        let mut component_count = degrees.len();
+        let mut has_cycle = false;
+        for (from, to, _) in self.get_all_edges() {
+            edge_count += 1;
+            *degrees.get_mut(&from).expect("Edge must start at a vertex") += 1;
+            *degrees.get_mut(&to).expect("Edge must end at a vertex") += 1;
+
+            let was_merged = union_find
+                .union(&from, &to)
+                .expect("All edges must connect to existing vertices");
+            if was_merged {
+                component_count -= 1;
+            } else {
+                has_cycle = true;
+            }
+        }
+
+        let vertex_count = degrees.len();
+        let possible_edges = if Backend::Direction::IS_DIRECTED {
+            vertex_count * vertex_count.saturating_sub(1)
+        } else {
+            vertex_count * vertex_count.saturating_sub(1) / 2
+        };
+        let degree_sum = degrees.values().sum::<usize>();
+        let is_forest = (!Backend::Direction::IS_DIRECTED).then_some(!has_cycle);
+
+        GraphStats {
+            vertex_count,
+            edge_count,
+            density: if possible_edges == 0 {
+                0.0
+            } else {
+                edge_count as f64 / possible_edges as f64
+            },
+            min_degree: degrees.values().copied().min().unwrap_or(0),
+            max_degree: degrees.values().copied().max().unwrap_or(0),
+            avg_degree: if vertex_count == 0 {
+                0.0
+            } else {
+                degree_sum as f64 / vertex_count as f64
+            },
+            component_count,
+            is_forest,
+            is_tree: is_forest.map(|is_forest| is_forest && component_count == 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{EdgeWithWeight, GraphBase, Vertex},
+        Directed, ListGraph, Undirected,
+    };
+
+    use super::WeightStats;
+
+    #[rstest]
+    fn test_edge_weight_stats_undirected() {
+        let graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| Vertex { id }).collect(),
+            vec![
+                (0, 1, EdgeWithWeight::new(1.0)),
+                (1, 2, EdgeWithWeight::new(4.0)),
+                (2, 3, EdgeWithWeight::new(2.5)),
+                (3, 0, EdgeWithWeight::new(0.5)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            graph.edge_weight_stats(),
+            Some(WeightStats {
+                min: 0.5,
+                max: 4.0,
+                mean: 2.0,
+                sum: 8.0,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_edge_weight_stats_directed() {
+        let graph = ListGraph::<_, _, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| Vertex { id }).collect(),
+            vec![
+                (0, 1, EdgeWithWeight::new(-3.0)),
+                (1, 0, EdgeWithWeight::new(6.0)),
+                (1, 2, EdgeWithWeight::new(3.0)),
+            ],
+        )
+        .unwrap();
+
+        let stats = graph.edge_weight_stats().unwrap();
+        assert_eq!(stats.min, -3.0);
+        assert_eq!(stats.max, 6.0);
+        assert_eq!(stats.sum, 6.0);
+        assert_eq!(stats.mean, 2.0);
+    }
+
+    #[rstest]
+    fn test_edge_weight_stats_without_edges() {
+        let mut graph = ListGraph::<Vertex, EdgeWithWeight, Undirected>::new();
+        assert_eq!(graph.edge_weight_stats(), None);
+
+        graph.push_vertex(Vertex { id: 0 }).unwrap();
+        assert_eq!(graph.edge_weight_stats(), None);
+    }
+}
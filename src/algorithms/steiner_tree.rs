@@ -0,0 +1,284 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    algorithms::mst::union_find::UnionFind,
+    graph::{GraphBase, HeapWeight, ListGraphBackend, WeightedEdge, WithID},
+    Graph, GraphError, Undirected,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    Backend::Vertex: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: Copy + HeapWeight + WeightedEdge,
+{
+    /// Builds the metric closure over `terminals`, the first step of the 2-approximate Steiner tree.
+    ///
+    /// The result contains the terminal vertices (in the given order) and an edge between every pair of them,
+    /// weighted with the cost of their shortest path in this graph (see [`Graph::dijkstra`]).
+    /// The edges are the bare weights, so the closure can be passed to the weighted algorithms directly.
+    /// Pairs of terminals that are not connected in this graph get no edge.
+    ///
+    /// Runs one (early stopping) Dijkstra per terminal, so the same weight restrictions apply.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound` if a terminal is not part of the graph
+    /// - `GraphError::DuplicateVertex` if a terminal is given twice
+    /// - The errors of building the output graph
+    #[allow(clippy::type_complexity)]
+    pub fn metric_closure<OutputBackend>(
+        &self,
+        terminals: &[<Backend::Vertex as WithID>::IDType],
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = <Backend::Edge as WeightedEdge>::WeightType,
+            Direction = Undirected,
+        >,
+    {
+        let mut closure = Graph::<OutputBackend>::new();
+        for &terminal in terminals {
+            let vertex = self
+                .get_vertex_by_id(terminal)
+                .ok_or(GraphError::VertexNotFound(terminal))?;
+            closure.push_vertex(vertex.clone())?;
+        }
+
+        for (i, &from) in terminals.iter().enumerate() {
+            // The pairs with the earlier terminals were already added from their side
+            let targets = &terminals[i + 1..];
+            if targets.is_empty() {
+                break;
+            }
+
+            let shortest_paths = self.dijkstra_multi_target(from, targets);
+            for &to in targets {
+                if let Some(cost) = shortest_paths.get_cost(to) {
+                    closure.push_edge(from, to, cost)?;
+                }
+            }
+        }
+
+        Ok(closure)
+    }
+
+    /// Approximates a minimum Steiner tree, i.e. a tree of minimal weight that connects all `terminals`
+    /// and may use any other vertex of the graph.
+    ///
+    /// Uses the algorithm of Kou, Markowsky and Berman, whose result weighs at most twice the optimum:
+    /// 1. Build the metric closure over the terminals (see [`Graph::metric_closure`]) and compute its MST
+    /// 2. Replace every MST edge by the corresponding shortest path in this graph
+    /// 3. Compute an MST of the union of these paths, which removes the cycles where paths overlap
+    /// 4. Repeatedly remove leaves that are not terminals
+    ///
+    /// The result contains the original vertices and edges. For less than two terminals,
+    /// the result only contains the terminals themselves.
+    ///
+    /// # Errors
+    /// - `GraphError::AlgorithmError` if the terminals are not all connected
+    /// - The errors of [`Graph::metric_closure`] and of building the output graph
+    #[allow(clippy::type_complexity)]
+    pub fn steiner_tree_approx<OutputBackend>(
+        &self,
+        terminals: &[<Backend::Vertex as WithID>::IDType],
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend:
+            GraphBase<Vertex = Backend::Vertex, Edge = Backend::Edge, Direction = Undirected>,
+        <Backend::Vertex as WithID>::IDType: PartialOrd,
+    {
+        // 1. MST of the metric closure (Kruskal, as the closure is complete)
+        let closure = self.metric_closure::<ListGraphBackend<_, _, Undirected>>(terminals)?;
+        let mut closure_edges = closure
+            .get_all_edges()
+            .map(|(from, to, cost)| (from, to, *cost))
+            .collect::<Vec<_>>();
+        closure_edges.sort_by(|(_, _, a), (_, _, b)| a.heap_cmp(b));
+
+        let mut union_find = UnionFind::new();
+        for &terminal in terminals {
+            let added = union_find.make_set(terminal).is_ok();
+            debug_assert!(added, "Terminals are unique after building the closure");
+        }
+        let mut closure_mst_edges = Vec::new();
+        for (from, to, _) in closure_edges {
+            // The closure only connects terminals, so the union cannot fail
+            if matches!(union_find.union(&from, &to), Ok(true)) {
+                closure_mst_edges.push((from, to));
+            }
+        }
+        if closure_mst_edges.len() + 1 < terminals.len() {
+            return Err(GraphError::AlgorithmError(
+                "All terminals must be connected".to_string(),
+            ));
+        }
+
+        // 2. Union of the shortest paths between the terminals connected in the MST
+        let mut paths = Graph::<ListGraphBackend<_, _, Undirected>>::new();
+        for vertex in closure.get_all_vertices() {
+            paths.push_vertex(vertex.clone())?;
+        }
+        for (from, to) in closure_mst_edges {
+            let path = self.dijkstra(from, Some(to)).get_path(to);
+            for pair in path.windows(2) {
+                let (path_from, path_to) = (pair[0], pair[1]);
+                for vid in [path_from, path_to] {
+                    if paths.get_vertex_by_id(vid).is_none() {
+                        let vertex = self
+                            .get_vertex_by_id(vid)
+                            .expect("Vertices on a shortest path must exist in the graph");
+                        paths.push_vertex(vertex.clone())?;
+                    }
+                }
+                if !paths.has_edge(path_from, path_to) {
+                    let edge = self
+                        .get_edge(path_from, path_to)
+                        .expect("Edges on a shortest path must exist in the graph");
+                    paths.push_edge(path_from, path_to, edge.clone())?;
+                }
+            }
+        }
+
+        // 3. Remove the cycles of overlapping paths
+        let mut tree = paths.mst_prim::<ListGraphBackend<_, _, Undirected>>(None)?;
+
+        // 4. Prune the leaves that are no terminals, which may turn their neighbors into such leaves
+        let terminal_set = terminals.iter().copied().collect::<FxHashSet<_>>();
+        let mut candidates = tree
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<VecDeque<_>>();
+        while let Some(vid) = candidates.pop_front() {
+            if terminal_set.contains(&vid) || tree.get_vertex_by_id(vid).is_none() {
+                continue;
+            }
+            let neighbors = tree
+                .get_adjacent_vertices(vid)
+                .map(|v| v.get_id())
+                .collect::<Vec<_>>();
+            if let [neighbor] = neighbors[..] {
+                tree.remove_vertex(vid)?;
+                candidates.push_back(neighbor);
+            }
+        }
+
+        Graph::from_vertices_and_edges(
+            tree.get_all_vertices().cloned().collect(),
+            tree.get_all_edges()
+                .map(|(from, to, edge)| (from, to, edge.clone()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{EdgeWithWeight, GraphBase, ListGraphBackend, Vertex},
+        GraphError, ListGraph, Undirected,
+    };
+
+    fn build_graph() -> ListGraph<Vertex, EdgeWithWeight, Undirected> {
+        // The direct edges 0 - 2 and 1 - 3 are longer than the detours
+        ListGraph::from_vertices_and_edges(
+            (0..5).map(|id| Vertex { id }).collect(),
+            vec![
+                (0, 1, EdgeWithWeight::new(1.0)),
+                (1, 2, EdgeWithWeight::new(2.0)),
+                (0, 2, EdgeWithWeight::new(5.0)),
+                (2, 3, EdgeWithWeight::new(1.0)),
+                (1, 3, EdgeWithWeight::new(4.0)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_metric_closure_uses_shortest_path_costs() {
+        let graph = build_graph();
+
+        let closure = graph
+            .metric_closure::<ListGraphBackend<_, _, Undirected>>(&[0, 2, 3])
+            .unwrap();
+
+        assert_eq!(closure.vertex_count(), 3);
+        assert_eq!(closure.edge_count(), 3);
+        assert_eq!(closure.get_edge(0, 2), Some(&3.0));
+        assert_eq!(closure.get_edge(0, 3), Some(&4.0));
+        assert_eq!(closure.get_edge(2, 3), Some(&1.0));
+    }
+
+    #[rstest]
+    fn test_metric_closure_skips_disconnected_terminals() {
+        // Vertex 4 is isolated
+        let graph = build_graph();
+
+        let closure = graph
+            .metric_closure::<ListGraphBackend<_, _, Undirected>>(&[0, 4, 3])
+            .unwrap();
+
+        assert_eq!(closure.vertex_count(), 3);
+        assert_eq!(closure.edge_count(), 1);
+        assert_eq!(closure.get_edge(0, 3), Some(&4.0));
+    }
+
+    #[rstest]
+    fn test_steiner_tree_connects_terminals() {
+        // A wheel around vertex 0 whose spokes are cheaper than the rim, with the pendant path 4 - 5 - 6
+        let graph = ListGraph::<Vertex, EdgeWithWeight, Undirected>::from_vertices_and_edges(
+            (0..7).map(|id| Vertex { id }).collect(),
+            vec![
+                (0, 1, EdgeWithWeight::new(1.0)),
+                (0, 2, EdgeWithWeight::new(1.0)),
+                (0, 3, EdgeWithWeight::new(1.0)),
+                (0, 4, EdgeWithWeight::new(1.0)),
+                (1, 2, EdgeWithWeight::new(1.5)),
+                (2, 3, EdgeWithWeight::new(1.5)),
+                (3, 4, EdgeWithWeight::new(1.5)),
+                (4, 1, EdgeWithWeight::new(1.5)),
+                (4, 5, EdgeWithWeight::new(2.0)),
+                (5, 6, EdgeWithWeight::new(2.0)),
+            ],
+        )
+        .unwrap();
+        let terminals = [1, 3, 5];
+
+        let tree = graph
+            .steiner_tree_approx::<ListGraphBackend<_, _, Undirected>>(&terminals)
+            .unwrap();
+
+        // The optimal tree 1 - 0 - 3, 0 - 4 - 5 weighs 5
+        assert_eq!(tree.edge_count(), tree.vertex_count() - 1);
+        assert_eq!(tree.analyze_components().count(), 1);
+        assert!(terminals
+            .iter()
+            .all(|&terminal| tree.get_vertex_by_id(terminal).is_some()));
+        assert!(tree.get_vertex_by_id(6).is_none());
+        assert!(tree.get_total_weight() <= 2.0 * 5.0);
+    }
+
+    #[rstest]
+    fn test_steiner_tree_disconnected_terminals() {
+        let graph = build_graph();
+
+        let result = graph.steiner_tree_approx::<ListGraphBackend<_, _, Undirected>>(&[0, 4]);
+
+        assert!(matches!(result, Err(GraphError::AlgorithmError(_))));
+    }
+
+    #[rstest]
+    fn test_metric_closure_unknown_terminal() {
+        let graph = build_graph();
+
+        let result = graph.metric_closure::<ListGraphBackend<_, _, Undirected>>(&[0, 42]);
+
+        assert!(matches!(result, Err(GraphError::VertexNotFound(42))));
+    }
+}
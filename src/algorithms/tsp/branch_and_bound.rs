@@ -1,8 +1,8 @@
 use std::{fmt::Debug, hash::Hash, ops::Add};
 
 use crate::{
-    graph::{GraphBase, ListGraphBackend, Path, WeightedEdge, WithID},
-    Graph,
+    graph::{GraphBase, HeapWeight, ListGraphBackend, Path, WeightedEdge, WithID},
+    Graph, GraphError,
 };
 
 use super::TspResult;
@@ -10,11 +10,11 @@ use super::TspResult;
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
-    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash + Debug,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash + Debug,
     Backend::Vertex: Clone,
     Backend::Edge: WeightedEdge + Clone,
     <Backend::Edge as WeightedEdge>::WeightType:
-        Add<Output = <Backend::Edge as WeightedEdge>::WeightType> + Copy,
+        Add<Output = <Backend::Edge as WeightedEdge>::WeightType> + Copy + HeapWeight,
     ListGraphBackend<Backend::Vertex, Backend::Edge, Backend::Direction>:
         GraphBase<Vertex = Backend::Vertex, Edge = Backend::Edge, Direction = Backend::Direction>,
 {
@@ -24,7 +24,7 @@ where
     /// - `self` must be a fully connected graph with weights assigned to all edges.
     ///
     /// # Parameters
-    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, a default starting vertex is chosen.
+    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, the vertex with the smallest ID is chosen.
     ///
     /// # Returns
     /// - Returns a `TspResult<Backend>` containing the optimal path found, or an empty path if the graph is empty.
@@ -35,9 +35,62 @@ where
         &self,
         start_vertex_id: Option<<Backend::Vertex as WithID>::IDType>,
     ) -> TspResult<Backend> {
+        self.tsp_branch_and_bound_with(start_vertex_id, &|next, remaining, _start| {
+            self.two_cheapest_edges_bound(next, remaining)
+        })
+        .map(|(path, _expanded_nodes)| path)
+    }
+
+    /// Finds a path with the optimal TSP solution like [`Graph::tsp_branch_and_bound`],
+    /// but prunes the search tree with a stronger 1-tree lower bound.
+    ///
+    /// The rest of a partial tour leads from the current vertex through all unvisited vertices back to the start.
+    /// Its cost is at least the cheapest edge from the current vertex into the unvisited vertices, plus a minimum
+    /// spanning tree of the unvisited vertices, plus the cheapest edge from them back to the start.
+    /// Computing this bound costs O(k²) for k unvisited vertices, but it prunes far more nodes.
+    ///
+    /// # Requirements
+    /// - `self` must be a fully connected graph with weights assigned to all edges.
+    ///
+    /// # Panics
+    /// - May panic if the graph is not fully connected.
+    pub fn tsp_branch_and_bound_one_tree(
+        &self,
+        start_vertex_id: Option<<Backend::Vertex as WithID>::IDType>,
+    ) -> TspResult<Backend> {
+        self.tsp_branch_and_bound_with(start_vertex_id, &|next, remaining, start| {
+            self.one_tree_bound(next, remaining, start)
+        })
+        .map(|(path, _expanded_nodes)| path)
+    }
+
+    /// Branch and bound with the given lower bound for the cost of completing a tour.
+    ///
+    /// `lower_bound(next, remaining, start)` is called with the candidate vertex `next`, the unvisited vertices
+    /// (still including `next`) and the start vertex of the tour.
+    /// Returns the optimal path together with the number of expanded search tree nodes.
+    #[allow(clippy::type_complexity)]
+    fn tsp_branch_and_bound_with<F>(
+        &self,
+        start_vertex_id: Option<<Backend::Vertex as WithID>::IDType>,
+        lower_bound: &F,
+    ) -> Result<
+        (
+            Path<<Backend::Vertex as WithID>::IDType, Backend::Edge>,
+            usize,
+        ),
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    >
+    where
+        F: Fn(
+            <Backend::Vertex as WithID>::IDType,
+            &[<Backend::Vertex as WithID>::IDType],
+            <Backend::Vertex as WithID>::IDType,
+        ) -> <Backend::Edge as WeightedEdge>::WeightType,
+    {
         let (start_v, remaining_vertices) = match self.get_initial_vertex(start_vertex_id) {
             Some(v) => v,
-            None => return Ok(Path::default()),
+            None => return Ok((Path::default(), 0)),
         };
 
         // Einen ersten "besten" Pfad mit dem Nearest Neighbor Algorithmus berechnen
@@ -50,12 +103,15 @@ where
         let initial_cost = <Backend::Edge as WeightedEdge>::WeightType::default();
         let mut remaining = remaining_vertices.collect::<Vec<_>>();
 
+        let mut expanded_nodes = 0;
         self.branch_and_bound(
             start_v,
             &mut initial_path,
             initial_cost,
             &mut remaining,
             (&mut current_best_cost, &mut current_best_path),
+            lower_bound,
+            &mut expanded_nodes,
         );
 
         // Construct the Path object
@@ -67,11 +123,12 @@ where
             let edge = self.get_edge(from_v, to_v).unwrap().clone();
             path.push(from_v, to_v, edge);
         }
-        Ok(path)
+        Ok((path, expanded_nodes))
     }
 
     /// Recursive function to go through the different permutations
-    fn branch_and_bound(
+    #[allow(clippy::too_many_arguments)]
+    fn branch_and_bound<F>(
         &self,
         current_v: <Backend::Vertex as WithID>::IDType,
         current_path: &mut Vec<<Backend::Vertex as WithID>::IDType>,
@@ -81,7 +138,17 @@ where
             &mut <Backend::Edge as WeightedEdge>::WeightType,
             &mut Vec<<Backend::Vertex as WithID>::IDType>,
         ),
-    ) {
+        lower_bound: &F,
+        expanded_nodes: &mut usize,
+    ) where
+        F: Fn(
+            <Backend::Vertex as WithID>::IDType,
+            &[<Backend::Vertex as WithID>::IDType],
+            <Backend::Vertex as WithID>::IDType,
+        ) -> <Backend::Edge as WeightedEdge>::WeightType,
+    {
+        *expanded_nodes += 1;
+
         if current_path.len() == self.vertex_count() {
             // Alle Knoten besucht, Tour schließen
             let edge_cost = self
@@ -112,63 +179,7 @@ where
             let new_cost = current_cost + edge_cost;
 
             // Prüfen ob es sich noch lohnt, diese Tour weiter zu erkunden
-            // Verbesserte Prüfung:
-            // Für alle Knoten in remaining, schaue ich mir die billigste und 2. billigste Kante zu Knoten an, an denen ich noch nicht war
-            // Das Ergebnis * 0.5 und dann die Kantenkosten aufsummieren.
-            // Wenn das + die aktuellen Kosten dann schon größer sind als current_best_cost, können wir abbrechen
-
-            // 1. Für alle Knoten in remaining die billigste und 2. billigste Kante finden
-            // Außerdem prüfen, dass noch mindestens 2 (+ der aktuelle = 3) Knoten zu verarbeiten sind
-            let min_remaining_cost: <Backend::Edge as WeightedEdge>::WeightType =
-                if remaining.len() > 2 {
-                    remaining
-                        .iter()
-                        .filter(|v| v != &&next)
-                        .map(|remaining_to| {
-                            let weights = remaining
-                                .iter()
-                                .filter(move |remaining_from| remaining_to != *remaining_from)
-                                .map(|remaining_from| {
-                                    self.get_edge(*remaining_from, *remaining_to)
-                                        .unwrap()
-                                        .get_weight()
-                                });
-
-                            // Find the two cheapest
-                            let mut cheapest: Option<(
-                                usize,
-                                <Backend::Edge as WeightedEdge>::WeightType,
-                            )> = None;
-                            let mut second_cheapest: Option<(
-                                usize,
-                                <Backend::Edge as WeightedEdge>::WeightType,
-                            )> = None;
-                            for (i, weight) in weights.enumerate() {
-                                // Init cheapest
-                                if cheapest.is_none() {
-                                    cheapest = Some((i, weight));
-                                    continue;
-                                }
-
-                                // Init second cheapest
-                                if second_cheapest.is_none() && cheapest.is_some() {
-                                    second_cheapest = Some((i, weight));
-                                }
-
-                                if weight < cheapest.unwrap().1 {
-                                    second_cheapest = cheapest;
-                                    cheapest = Some((i, weight));
-                                }
-                            }
-
-                            // 2. Diese beiden Kantenkosten aufsummieren und das Ergebnis * 0.5
-                            (cheapest.unwrap().1 + second_cheapest.unwrap().1) / 2u8.into()
-                        })
-                        .sum()
-                } else {
-                    // Wenn wir nicht mehr genügend Restkanten zum prüfen haben, dann rechnen wir "einfach" mit den aktuellen Pfadkosten
-                    <Backend::Edge as WeightedEdge>::WeightType::default()
-                };
+            let min_remaining_cost = lower_bound(next, remaining, current_path[0]);
 
             // 3. Wenn diese minimalen Restkosten + die aktuellen Kosten >=
             if &(new_cost + min_remaining_cost) >= current_best_cost {
@@ -190,6 +201,8 @@ where
                 new_cost,
                 remaining,
                 (current_best_cost, current_best_path),
+                lower_bound,
+                expanded_nodes,
             );
 
             // State vor rekursivem Aufruf wiederherstellen
@@ -198,4 +211,164 @@ where
             remaining.swap(next_i, last_remaining_idx);
         }
     }
+
+    /// The original lower bound: for every unvisited vertex (except `next`), half the cost of its two cheapest edges
+    /// to other unvisited vertices
+    fn two_cheapest_edges_bound(
+        &self,
+        next: <Backend::Vertex as WithID>::IDType,
+        remaining: &[<Backend::Vertex as WithID>::IDType],
+    ) -> <Backend::Edge as WeightedEdge>::WeightType {
+        // Verbesserte Prüfung:
+        // Für alle Knoten in remaining, schaue ich mir die billigste und 2. billigste Kante zu Knoten an, an denen ich noch nicht war
+        // Das Ergebnis * 0.5 und dann die Kantenkosten aufsummieren.
+        // Wenn das + die aktuellen Kosten dann schon größer sind als current_best_cost, können wir abbrechen
+
+        // 1. Für alle Knoten in remaining die billigste und 2. billigste Kante finden
+        // Außerdem prüfen, dass noch mindestens 2 (+ der aktuelle = 3) Knoten zu verarbeiten sind
+        if remaining.len() > 2 {
+            remaining
+                .iter()
+                .filter(|v| v != &&next)
+                .map(|remaining_to| {
+                    let weights = remaining
+                        .iter()
+                        .filter(move |remaining_from| remaining_to != *remaining_from)
+                        .map(|remaining_from| {
+                            self.get_edge(*remaining_from, *remaining_to)
+                                .unwrap()
+                                .get_weight()
+                        });
+
+                    // Find the two cheapest
+                    let mut cheapest: Option<(usize, <Backend::Edge as WeightedEdge>::WeightType)> =
+                        None;
+                    let mut second_cheapest: Option<(
+                        usize,
+                        <Backend::Edge as WeightedEdge>::WeightType,
+                    )> = None;
+                    for (i, weight) in weights.enumerate() {
+                        // Init cheapest
+                        if cheapest.is_none() {
+                            cheapest = Some((i, weight));
+                            continue;
+                        }
+
+                        // Init second cheapest
+                        if second_cheapest.is_none() && cheapest.is_some() {
+                            second_cheapest = Some((i, weight));
+                        }
+
+                        if weight < cheapest.unwrap().1 {
+                            second_cheapest = cheapest;
+                            cheapest = Some((i, weight));
+                        }
+                    }
+
+                    // 2. Diese beiden Kantenkosten aufsummieren und das Ergebnis * 0.5
+                    (cheapest.unwrap().1 + second_cheapest.unwrap().1) / 2u8.into()
+                })
+                .sum()
+        } else {
+            // Wenn wir nicht mehr genügend Restkanten zum prüfen haben, dann rechnen wir "einfach" mit den aktuellen Pfadkosten
+            <Backend::Edge as WeightedEdge>::WeightType::default()
+        }
+    }
+
+    /// 1-tree lower bound for going from `next` through all other unvisited vertices back to `start`
+    fn one_tree_bound(
+        &self,
+        next: <Backend::Vertex as WithID>::IDType,
+        remaining: &[<Backend::Vertex as WithID>::IDType],
+        start: <Backend::Vertex as WithID>::IDType,
+    ) -> <Backend::Edge as WeightedEdge>::WeightType {
+        let weight = |from, to| self.get_edge(from, to).unwrap().get_weight();
+        let rest = remaining
+            .iter()
+            .copied()
+            .filter(|v| *v != next)
+            .collect::<Vec<_>>();
+
+        let cheapest_to = |from| {
+            rest.iter()
+                .map(|v| weight(from, *v))
+                .min_by(|a, b| a.heap_cmp(b))
+        };
+        match (cheapest_to(next), cheapest_to(start)) {
+            (Some(from_next), Some(to_start)) => {
+                from_next + self.spanning_tree_weight(&rest) + to_start
+            }
+            // `next` is the last unvisited vertex, so the tour is closed directly
+            _ => weight(next, start),
+        }
+    }
+
+    /// Weight of a minimum spanning tree over the given vertices (dense Prim's algorithm, O(k²))
+    fn spanning_tree_weight(
+        &self,
+        vertices: &[<Backend::Vertex as WithID>::IDType],
+    ) -> <Backend::Edge as WeightedEdge>::WeightType {
+        let weight = |from, to| self.get_edge(from, to).unwrap().get_weight();
+        let mut total = <Backend::Edge as WeightedEdge>::WeightType::default();
+        let Some((&first, rest)) = vertices.split_first() else {
+            return total;
+        };
+
+        // Cheapest known edge from the tree to every vertex outside of it
+        let mut cheapest = rest
+            .iter()
+            .map(|v| (*v, weight(first, *v)))
+            .collect::<Vec<_>>();
+        while let Some((closest_idx, _)) = cheapest
+            .iter()
+            .enumerate()
+            .min_by(|(_, (_, a)), (_, (_, b))| a.heap_cmp(b))
+        {
+            let (added, added_weight) = cheapest.swap_remove(closest_idx);
+            total += added_weight;
+            for (v, cheapest_weight) in cheapest.iter_mut() {
+                let new_weight = weight(added, *v);
+                if new_weight < *cheapest_weight {
+                    *cheapest_weight = new_weight;
+                }
+            }
+        }
+
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{graph::Vertex, ListGraph, Undirected};
+
+    #[rstest]
+    #[case("resources/test_graphs/complete_undirected_weighted/K_10.txt")]
+    #[case("resources/test_graphs/complete_undirected_weighted/K_12.txt")]
+    fn test_one_tree_bound_expands_fewer_nodes(#[case] input_path: &str) {
+        let graph = ListGraph::<Vertex, f64, Undirected>::from_hoever_file_with_weights(
+            input_path,
+            |remaining| remaining[0].parse().unwrap(),
+        )
+        .unwrap();
+
+        let (path, expanded_nodes) = graph
+            .tsp_branch_and_bound_with(None, &|next, remaining, _start| {
+                graph.two_cheapest_edges_bound(next, remaining)
+            })
+            .unwrap();
+        let (one_tree_path, one_tree_expanded_nodes) = graph
+            .tsp_branch_and_bound_with(None, &|next, remaining, start| {
+                graph.one_tree_bound(next, remaining, start)
+            })
+            .unwrap();
+
+        assert!((path.total_cost() - one_tree_path.total_cost()).abs() < 1e-9);
+        assert!(
+            one_tree_expanded_nodes < expanded_nodes,
+            "1-tree bound expanded {one_tree_expanded_nodes} nodes, the original bound {expanded_nodes}"
+        );
+    }
 }
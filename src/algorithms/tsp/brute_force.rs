@@ -10,7 +10,7 @@ use super::TspResult;
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
-    <Backend::Vertex as WithID>::IDType: Copy + PartialEq,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord,
     Backend::Edge: WeightedEdge + Clone,
     <Backend::Edge as WeightedEdge>::WeightType:
         Add<Output = <Backend::Edge as WeightedEdge>::WeightType> + Copy,
@@ -21,7 +21,7 @@ where
     /// - `self` must be a fully connected graph with weights assigned to all edges.
     ///
     /// # Parameters
-    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, a default starting vertex is chosen.
+    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, the vertex with the smallest ID is chosen.
     ///
     /// # Returns
     /// - Returns a `TspResult<Backend>` containing the optimal path found, or an empty path if the graph is empty.
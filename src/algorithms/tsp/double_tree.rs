@@ -1,8 +1,8 @@
 use std::hash::Hash;
 
 use crate::{
-    graph::{GraphBase, ListGraphBackend, Path, WeightedEdge, WithID},
-    Graph,
+    graph::{GraphBase, HeapWeight, ListGraphBackend, Path, WeightedEdge, WithID},
+    Graph, GraphError,
 };
 
 use super::TspResult;
@@ -10,9 +10,10 @@ use super::TspResult;
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
-    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
     Backend::Vertex: Clone,
     Backend::Edge: WeightedEdge + Clone,
+    <Backend::Edge as WeightedEdge>::WeightType: HeapWeight,
     ListGraphBackend<Backend::Vertex, Backend::Edge, Backend::Direction>:
         GraphBase<Vertex = Backend::Vertex, Edge = Backend::Edge, Direction = Backend::Direction>,
 {
@@ -25,7 +26,8 @@ where
     /// - `self` must be a fully connected graph with weights assigned to all edges.
     ///
     /// # Parameters
-    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, a default starting vertex is chosen.
+    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, the vertex with the smallest ID is chosen.
+    ///   The start vertex is also the root of the MST and its depth-first search.
     ///
     /// # Returns
     /// - Returns a `TspResult<Backend>` containing the optimal path found, or an empty path if the graph is empty.
@@ -36,12 +38,33 @@ where
         &self,
         start_vertex_id: Option<<Backend::Vertex as WithID>::IDType>,
     ) -> TspResult<Backend> {
+        self.tsp_double_tree_with_bound(start_vertex_id)
+            .map(|(path, _)| path)
+    }
+
+    /// Same as [`Graph::tsp_double_tree`], but additionally returns the weight of the MST the tour was built from.
+    ///
+    /// The MST weight is a lower bound for the optimal tour cost, so `tour_cost / mst_weight` is an upper bound
+    /// on how far the tour is from the optimum. With the triangle inequality this ratio is at most 2.
+    ///
+    /// For an empty graph an empty path and the default weight are returned.
+    #[allow(clippy::type_complexity)]
+    pub fn tsp_double_tree_with_bound(
+        &self,
+        start_vertex_id: Option<<Backend::Vertex as WithID>::IDType>,
+    ) -> Result<
+        (
+            Path<<Backend::Vertex as WithID>::IDType, Backend::Edge>,
+            <Backend::Edge as WeightedEdge>::WeightType,
+        ),
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
         let mut path = Path::default();
 
-        // Get random start vertex
+        // Get the start vertex (smallest ID if none is given)
         let (start_v, _) = match self.get_initial_vertex(start_vertex_id) {
             Some(v) => v,
-            None => return Ok(Path::default()),
+            None => return Ok((Path::default(), Default::default())),
         };
 
         // Generate MST
@@ -69,6 +92,6 @@ where
                 .to_owned(),
         );
 
-        Ok(path)
+        Ok((path, mst.get_total_weight()))
     }
 }
@@ -10,7 +10,7 @@ use super::TspResult;
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
-    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
     Backend::Edge: WeightedEdge + Clone,
 {
     /// Finds a path with a TSP solution using the nearest neighbor algorithm.
@@ -21,7 +21,8 @@ where
     /// - `self` must be a fully connected graph with weights assigned to all edges.
     ///
     /// # Parameters
-    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, a default starting vertex is chosen.
+    /// - `start_vertex_id`: Optional ID of the vertex to start the TSP from. If `None`, the vertex with the smallest ID is chosen.
+    ///   Ties between equally near vertices are broken by the smaller ID, so the tour does not depend on the backend.
     ///
     /// # Returns
     /// - Returns a `TspResult<Backend>` containing the optimal path found, or an empty path if the graph is empty.
@@ -32,7 +33,7 @@ where
         &self,
         start_vertex_id: Option<<Backend::Vertex as WithID>::IDType>,
     ) -> TspResult<Backend> {
-        // Get random start vertex
+        // Get the start vertex (smallest ID if none is given)
         let (start_v, remaining) = match self.get_initial_vertex(start_vertex_id) {
             Some(v) => v,
             None => return Ok(Path::default()),
@@ -48,9 +49,10 @@ where
                 .iter()
                 .enumerate()
                 .map(|(i, v)| (i, self.get_edge(*current, *v).unwrap().get_weight()))
-                .min_by(|(_to, edge), (_to_other, edge_other)| {
+                .min_by(|(to, edge), (to_other, edge_other)| {
                     edge.partial_cmp(edge_other)
                         .expect("Graph weights must not contain NaN values")
+                        .then_with(|| remaining[*to].cmp(&remaining[*to_other]))
                 })
                 .map(|(to, _edge)| to)
                 .unwrap();
@@ -6,8 +6,14 @@ use crate::{
 impl<Backend> Graph<Backend>
 where
     Backend: GraphBase,
-    <Backend::Vertex as WithID>::IDType: PartialEq + Copy,
+    <Backend::Vertex as WithID>::IDType: Ord + Copy,
 {
+    /// Picks the vertex to start an algorithm from and returns it together with all remaining vertex IDs.
+    ///
+    /// If `start_vertex_id` is `None`, the vertex with the smallest ID is chosen.
+    /// This keeps the result independent of the backend's iteration order (e.g. hash map order of the list backend).
+    ///
+    /// Returns `None` if the graph is empty or the given start vertex does not exist.
     #[allow(clippy::type_complexity)]
     pub fn get_initial_vertex(
         &self,
@@ -16,29 +22,16 @@ where
         <<Backend as GraphBase>::Vertex as WithID>::IDType,
         impl Iterator<Item = <Backend::Vertex as WithID>::IDType> + use<'_, Backend>,
     )> {
-        match start_vertex_id {
-            Some(start_vid) => {
-                let start_v = self.get_vertex_by_id(start_vid)?.get_id();
-                Some((
-                    start_v,
-                    Box::new(
-                        self.get_all_vertices()
-                            .map(|v| v.get_id())
-                            .filter(move |v| v != &start_v),
-                    )
-                        as Box<dyn Iterator<Item = <Backend::Vertex as WithID>::IDType> + '_>,
-                ))
-            }
-            None => {
-                let mut vertices = self.get_all_vertices().map(|v| v.get_id());
-                let start_v = vertices.next()?;
+        let start_v = match start_vertex_id {
+            Some(start_vid) => self.get_vertex_by_id(start_vid)?.get_id(),
+            None => self.get_all_vertices().map(|v| v.get_id()).min()?,
+        };
 
-                Some((
-                    start_v,
-                    Box::new(vertices)
-                        as Box<dyn Iterator<Item = <Backend::Vertex as WithID>::IDType> + '_>,
-                ))
-            }
-        }
+        Some((
+            start_v,
+            self.get_all_vertices()
+                .map(|v| v.get_id())
+                .filter(move |v| v != &start_v),
+        ))
     }
 }
@@ -0,0 +1,763 @@
+use std::marker::PhantomData;
+
+use super::{
+    error::GraphError, Directed, Direction, EdgeTuple, GraphBase, IntoDirected, Undirected,
+    WeightedEdge, WithID,
+};
+
+/// Number of matrix cells packed into one word of a row
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A graph data structure for unweighted graphs (`Edge = ()`), represented by an adjacency matrix of bits.
+///
+/// Each row of the matrix is a bitset packed into `u64` words, so every cell only takes a single bit.
+/// The [`AdjacencyMatrixGraph`](super::adjacency_matrix::AdjacencyMatrixGraph) stores an `Option<()>` (one byte) per cell,
+/// so this backend needs about an eighth of the memory for dense, unweighted graphs.
+///
+/// # Type Parameters
+/// - `Vertex`: The vertex type, which must implement [`WithID<VId>`].
+/// - `Dir`: Wether the graph is `Directed` or `Undirected`
+///
+/// # Important
+/// **This struct assumes that vertex IDs are sequential and correspond to indices in the range `0..n`, where `n` is the number of vertices.**
+/// If vertex IDs are not sequential or do not start at zero, the behavior is undefined and may result in panics or incorrect results.
+///
+/// # See Also
+/// - [`Graph`]: The generic graph struct which contains detailed documentation for all public graph operations.
+#[derive(Debug, Clone)]
+pub struct AdjacencyBitMatrixGraph<Vertex: WithID, Dir: Direction> {
+    vertices: Vec<Vertex>,
+    /// One bitset per vertex, bit `to` of row `from` is set if the edge `(from, to)` exists
+    rows: Vec<Vec<u64>>,
+    _phantom: PhantomData<Dir>,
+}
+
+/// Iterates over the indices of all set bits in a row
+fn iter_bits(row: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    row.iter().enumerate().flat_map(|(word_idx, &word)| {
+        let mut remaining = word;
+        std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            let bit = remaining.trailing_zeros() as usize;
+            // Clear the lowest set bit
+            remaining &= remaining - 1;
+            Some(word_idx * WORD_BITS + bit)
+        })
+    })
+}
+
+/// Returns a mutable reference to the unit edge.
+///
+/// A `&mut ()` literal cannot be returned, as only shared references to constants are promoted to `'static`,
+/// and a `()` field of the graph could not hand out the several references `get_all_edges_mut` yields at once.
+/// `()` is zero-sized, so leaking the box does not allocate.
+fn unit_mut<'a>() -> &'a mut () {
+    Box::leak(Box::new(()))
+}
+
+impl<Vertex: WithID, Dir: Direction> AdjacencyBitMatrixGraph<Vertex, Dir>
+where
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+{
+    /// Create a new, empty Graph with an Adjacency Bit Matrix representation
+    pub fn new() -> Self {
+        AdjacencyBitMatrixGraph {
+            vertices: vec![],
+            rows: vec![],
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of bytes taken by the words of the matrix rows, without the vertices.
+    ///
+    /// Every row takes `ceil(n / 64)` words, compared to `n` cells of the [`AdjacencyMatrixGraph`](super::adjacency_matrix::AdjacencyMatrixGraph).
+    pub fn matrix_size_in_bytes(&self) -> usize {
+        self.rows.iter().map(Vec::len).sum::<usize>() * size_of::<u64>()
+    }
+
+    fn new_with_size(n_vertices: usize) -> Self {
+        AdjacencyBitMatrixGraph {
+            vertices: Vec::with_capacity(n_vertices),
+            rows: Vec::with_capacity(n_vertices),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn has_bit(&self, from_idx: usize, to_idx: usize) -> bool {
+        self.rows[from_idx][to_idx / WORD_BITS] & (1 << (to_idx % WORD_BITS)) != 0
+    }
+
+    fn set_bit(&mut self, from_idx: usize, to_idx: usize) {
+        self.rows[from_idx][to_idx / WORD_BITS] |= 1 << (to_idx % WORD_BITS);
+    }
+
+    fn clear_bit(&mut self, from_idx: usize, to_idx: usize) {
+        self.rows[from_idx][to_idx / WORD_BITS] &= !(1 << (to_idx % WORD_BITS));
+    }
+
+    /// Converts both IDs to indices and checks that the vertices exist
+    fn indices(
+        &self,
+        from: Vertex::IDType,
+        to: Vertex::IDType,
+    ) -> Result<(usize, usize), GraphError<Vertex::IDType>> {
+        let from_idx: usize = from.into();
+        let to_idx: usize = to.into();
+
+        if from_idx >= self.vertices.len() {
+            return Err(GraphError::VertexNotFound(from));
+        }
+        if to_idx >= self.vertices.len() {
+            return Err(GraphError::VertexNotFound(to));
+        }
+
+        Ok((from_idx, to_idx))
+    }
+
+    fn push_edge_internal(
+        &mut self,
+        from: Vertex::IDType,
+        to: Vertex::IDType,
+    ) -> Result<(), GraphError<Vertex::IDType>> {
+        let (from_idx, to_idx) = self.indices(from, to)?;
+
+        if self.has_bit(from_idx, to_idx) {
+            return Err(GraphError::DuplicateEdge(from, to));
+        }
+
+        self.set_bit(from_idx, to_idx);
+        Ok(())
+    }
+
+    fn push_vertex_internal(&mut self, vertex: Vertex) -> Result<(), GraphError<Vertex::IDType>> {
+        let expected_id = self.vertices.len();
+        let idx: usize = vertex.get_id().into();
+        if idx > expected_id {
+            return Err(GraphError::OperationFailed(format!(
+                "Vertex ID must be sequential in AdjacencyBitMatrixGraph and equal to the current number of vertices (expected {}, got {})",
+                expected_id,
+                idx
+            )));
+        }
+        if idx < expected_id {
+            return Err(GraphError::DuplicateVertex(idx.into()));
+        }
+
+        self.vertices.push(vertex);
+
+        // Grow each existing row by one word once the new column does not fit anymore
+        let n_words = self.vertices.len().div_ceil(WORD_BITS);
+        for row in &mut self.rows {
+            row.resize(n_words, 0);
+        }
+        // Add a new row for the new vertex (no edges)
+        self.rows.push(vec![0; n_words]);
+
+        Ok(())
+    }
+
+    fn remove_vertex_internal(
+        &mut self,
+        vertex_id: Vertex::IDType,
+    ) -> Result<Vertex, GraphError<Vertex::IDType>> {
+        let idx: usize = vertex_id.into();
+        if idx >= self.vertices.len() {
+            return Err(GraphError::VertexNotFound(vertex_id));
+        }
+        if idx + 1 != self.vertices.len() {
+            return Err(GraphError::OperationFailed(format!(
+                "Only the vertex with the highest ID can be removed from AdjacencyBitMatrixGraph to keep IDs sequential (expected {}, got {})",
+                self.vertices.len() - 1,
+                idx
+            )));
+        }
+
+        let vertex = self.vertices.remove(idx);
+
+        // Drop the last row and column, including all edges of the removed vertex
+        self.rows.truncate(idx);
+        let n_words = idx.div_ceil(WORD_BITS);
+        for from_idx in 0..self.rows.len() {
+            self.clear_bit(from_idx, idx);
+            self.rows[from_idx].truncate(n_words);
+        }
+
+        Ok(vertex)
+    }
+
+    fn can_remove_vertices_internal(&self, vertex_ids: &[Vertex::IDType]) -> bool {
+        // Only the vertex with the highest ID can be removed at a time, so the vertices must form the end of the ID range
+        let mut indices = vertex_ids
+            .iter()
+            .map(|&vid| vid.into())
+            .collect::<Vec<usize>>();
+        indices.sort_unstable();
+        let n_vertices = self.vertices.len();
+        indices.len() <= n_vertices
+            && indices
+                .into_iter()
+                .eq(n_vertices - vertex_ids.len()..n_vertices)
+    }
+
+    fn remove_edge_internal(
+        &mut self,
+        from_id: Vertex::IDType,
+        to_id: Vertex::IDType,
+    ) -> Option<()> {
+        let (from_idx, to_idx) = self.indices(from_id, to_id).ok()?;
+        if !self.has_bit(from_idx, to_idx) {
+            return None;
+        }
+        self.clear_bit(from_idx, to_idx);
+        Some(())
+    }
+
+    fn clear_edges_internal(&mut self) {
+        for row in self.rows.iter_mut() {
+            row.fill(0);
+        }
+    }
+
+    fn get_edge_internal(&self, from_id: Vertex::IDType, to_id: Vertex::IDType) -> Option<&()> {
+        let (from_idx, to_idx) = self.indices(from_id, to_id).ok()?;
+        self.has_bit(from_idx, to_idx).then_some(&())
+    }
+
+    fn has_edge_internal(&self, from_id: Vertex::IDType, to_id: Vertex::IDType) -> bool {
+        self.indices(from_id, to_id)
+            .is_ok_and(|(from_idx, to_idx)| self.has_bit(from_idx, to_idx))
+    }
+
+    fn get_edge_mut_internal(
+        &mut self,
+        from_id: Vertex::IDType,
+        to_id: Vertex::IDType,
+    ) -> Option<&mut ()> {
+        self.get_edge_internal(from_id, to_id).map(|_| unit_mut())
+    }
+
+    fn get_adjacent_vertices_internal(
+        &self,
+        vertex_id: Vertex::IDType,
+    ) -> impl Iterator<Item = &Vertex> {
+        let idx: usize = vertex_id.into();
+        self.rows
+            .get(idx)
+            .into_iter()
+            .flat_map(|row| iter_bits(row))
+            .map(|to_idx| &self.vertices[to_idx])
+    }
+
+    fn out_degree_internal(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        let idx: usize = vertex_id.into();
+        self.rows
+            .get(idx)
+            .map(|row| row.iter().map(|word| word.count_ones() as usize).sum())
+    }
+
+    fn in_degree_internal(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        let idx: usize = vertex_id.into();
+        if idx >= self.vertices.len() {
+            return None;
+        }
+        Some(
+            (0..self.rows.len())
+                .filter(|&from_idx| self.has_bit(from_idx, idx))
+                .count(),
+        )
+    }
+
+    /// Iterates over all set bits as `(from, to)` index pairs
+    fn iter_edge_indices(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(from_idx, row)| iter_bits(row).map(move |to_idx| (from_idx, to_idx)))
+    }
+
+    fn count_set_bits(&self) -> usize {
+        self.rows
+            .iter()
+            .flat_map(|row| row.iter())
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+}
+
+impl<Vertex: WithID, Dir: Direction> Default for AdjacencyBitMatrixGraph<Vertex, Dir>
+where
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Vertex> IntoDirected<AdjacencyBitMatrixGraph<Vertex, Directed>>
+    for AdjacencyBitMatrixGraph<Vertex, Undirected>
+where
+    Vertex: WithID,
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+{
+    fn into_directed(self) -> AdjacencyBitMatrixGraph<Vertex, Directed> {
+        AdjacencyBitMatrixGraph {
+            vertices: self.vertices,
+            rows: self.rows,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Vertex> GraphBase for AdjacencyBitMatrixGraph<Vertex, Undirected>
+where
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+    Vertex: WithID,
+{
+    type Vertex = Vertex;
+
+    type Edge = ();
+
+    type Direction = Undirected;
+
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self::new()
+    }
+
+    fn new_with_size(n_vertices: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::new_with_size(n_vertices)
+    }
+
+    fn from_vertices_and_edges(
+        mut vertices: Vec<Self::Vertex>,
+        edges: Vec<EdgeTuple<<Self::Vertex as WithID>::IDType, Self::Edge>>,
+    ) -> Result<Self, GraphError<<Self::Vertex as WithID>::IDType>>
+    where
+        Self: Sized,
+    {
+        // Like the adjacency matrix, accept the vertices in any order
+        vertices.sort_unstable_by_key(|v| v.get_id().into());
+
+        let mut graph = Self::new_with_size(vertices.len());
+        for vertex in vertices {
+            graph.push_vertex(vertex)?;
+        }
+        for (from, to, edge) in edges {
+            graph.push_edge(from, to, edge)?;
+        }
+        Ok(graph)
+    }
+
+    fn push_vertex(
+        &mut self,
+        vertex: Self::Vertex,
+    ) -> Result<(), GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.push_vertex_internal(vertex)
+    }
+
+    fn push_edge(
+        &mut self,
+        from: <Self::Vertex as WithID>::IDType,
+        to: <Self::Vertex as WithID>::IDType,
+        _edge: Self::Edge,
+    ) -> Result<(), GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.push_edge_internal(from, to)?;
+        let (from_idx, to_idx) = self.indices(from, to)?;
+        self.set_bit(to_idx, from_idx);
+        Ok(())
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Result<Self::Vertex, GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.remove_vertex_internal(vertex_id)
+    }
+
+    fn can_remove_vertices(&self, vertex_ids: &[<Self::Vertex as WithID>::IDType]) -> bool {
+        self.can_remove_vertices_internal(vertex_ids)
+    }
+
+    fn remove_edge(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<Self::Edge> {
+        self.remove_edge_internal(from_id, to_id)?;
+        self.remove_edge_internal(to_id, from_id);
+        Some(())
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear_edges_internal();
+    }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
+
+    fn get_vertex_by_id(
+        &self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&Self::Vertex> {
+        self.vertices.get(vertex_id.into())
+    }
+
+    fn get_vertex_by_id_mut(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&mut Self::Vertex> {
+        self.vertices.get_mut(vertex_id.into())
+    }
+
+    fn get_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&Self::Edge> {
+        self.get_edge_internal(from_id, to_id)
+    }
+
+    fn has_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> bool {
+        self.has_edge_internal(from_id, to_id)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&mut Self::Edge> {
+        self.get_edge_mut_internal(from_id, to_id)
+    }
+
+    fn get_all_vertices<'a>(&'a self) -> impl Iterator<Item = &'a Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.vertices.iter()
+    }
+
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.vertices.iter_mut()
+    }
+
+    fn get_all_edges<'a>(
+        &'a self,
+    ) -> impl Iterator<
+        Item = (
+            <Self::Vertex as WithID>::IDType,
+            <Self::Vertex as WithID>::IDType,
+            &'a Self::Edge,
+        ),
+    >
+    where
+        Self::Edge: 'a,
+    {
+        // Iterate only upper triangle (including diagonal)
+        self.iter_edge_indices()
+            .filter(|(from, to)| from <= to)
+            .map(|(from, to)| (from.into(), to.into(), &()))
+    }
+
+    fn get_all_edges_mut<'a>(
+        &'a mut self,
+    ) -> impl Iterator<
+        Item = (
+            <Self::Vertex as WithID>::IDType,
+            <Self::Vertex as WithID>::IDType,
+            &'a mut Self::Edge,
+        ),
+    >
+    where
+        Self::Edge: 'a,
+    {
+        // Iterate only upper triangle (including diagonal)
+        self.iter_edge_indices()
+            .filter(|(from, to)| from <= to)
+            .map(|(from, to)| (from.into(), to.into(), unit_mut()))
+    }
+
+    fn get_adjacent_vertices<'a>(
+        &'a self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> impl Iterator<Item = &'a Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.get_adjacent_vertices_internal(vertex_id)
+    }
+
+    fn get_adjacent_vertices_with_edges<'a>(
+        &'a self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> impl Iterator<Item = (&'a Self::Vertex, &'a Self::Edge)>
+    where
+        Self::Vertex: 'a,
+        Self::Edge: 'a,
+    {
+        self.get_adjacent_vertices_internal(vertex_id)
+            .map(|v| (v, &()))
+    }
+
+    fn out_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        self.out_degree_internal(vertex_id)
+    }
+
+    fn in_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        // The matrix is symmetric, so the row has the same bits as the column
+        self.out_degree_internal(vertex_id)
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        // Self-loops only occupy a single bit, all other edges are stored twice
+        let self_loops = (0..self.vertices.len())
+            .filter(|&idx| self.has_bit(idx, idx))
+            .count();
+        (self.count_set_bits() + self_loops) / 2
+    }
+
+    fn get_total_weight(&self) -> <Self::Edge as WeightedEdge>::WeightType
+    where
+        Self::Edge: WeightedEdge,
+    {
+        // Unit edges do not carry any weight
+        Default::default()
+    }
+}
+
+impl<Vertex> GraphBase for AdjacencyBitMatrixGraph<Vertex, Directed>
+where
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+    Vertex: WithID,
+{
+    type Vertex = Vertex;
+
+    type Edge = ();
+
+    type Direction = Directed;
+
+    fn new() -> Self
+    where
+        Self: Sized,
+    {
+        Self::new()
+    }
+
+    fn new_with_size(n_vertices: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::new_with_size(n_vertices)
+    }
+
+    fn from_vertices_and_edges(
+        mut vertices: Vec<Self::Vertex>,
+        edges: Vec<EdgeTuple<<Self::Vertex as WithID>::IDType, Self::Edge>>,
+    ) -> Result<Self, GraphError<<Self::Vertex as WithID>::IDType>>
+    where
+        Self: Sized,
+    {
+        // Like the adjacency matrix, accept the vertices in any order
+        vertices.sort_unstable_by_key(|v| v.get_id().into());
+
+        let mut graph = Self::new_with_size(vertices.len());
+        for vertex in vertices {
+            graph.push_vertex(vertex)?;
+        }
+        for (from, to, edge) in edges {
+            graph.push_edge(from, to, edge)?;
+        }
+        Ok(graph)
+    }
+
+    fn push_vertex(
+        &mut self,
+        vertex: Self::Vertex,
+    ) -> Result<(), GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.push_vertex_internal(vertex)
+    }
+
+    fn push_edge(
+        &mut self,
+        from: <Self::Vertex as WithID>::IDType,
+        to: <Self::Vertex as WithID>::IDType,
+        _edge: Self::Edge,
+    ) -> Result<(), GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.push_edge_internal(from, to)
+    }
+
+    fn remove_vertex(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Result<Self::Vertex, GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.remove_vertex_internal(vertex_id)
+    }
+
+    fn can_remove_vertices(&self, vertex_ids: &[<Self::Vertex as WithID>::IDType]) -> bool {
+        self.can_remove_vertices_internal(vertex_ids)
+    }
+
+    fn remove_edge(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<Self::Edge> {
+        self.remove_edge_internal(from_id, to_id)
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear_edges_internal();
+    }
+
+    fn is_directed(&self) -> bool {
+        true
+    }
+
+    fn get_vertex_by_id(
+        &self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&Self::Vertex> {
+        self.vertices.get(vertex_id.into())
+    }
+
+    fn get_vertex_by_id_mut(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&mut Self::Vertex> {
+        self.vertices.get_mut(vertex_id.into())
+    }
+
+    fn get_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&Self::Edge> {
+        self.get_edge_internal(from_id, to_id)
+    }
+
+    fn has_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> bool {
+        self.has_edge_internal(from_id, to_id)
+    }
+
+    fn get_edge_mut(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<&mut Self::Edge> {
+        self.get_edge_mut_internal(from_id, to_id)
+    }
+
+    fn get_all_vertices<'a>(&'a self) -> impl Iterator<Item = &'a Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.vertices.iter()
+    }
+
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.vertices.iter_mut()
+    }
+
+    fn get_all_edges<'a>(
+        &'a self,
+    ) -> impl Iterator<
+        Item = (
+            <Self::Vertex as WithID>::IDType,
+            <Self::Vertex as WithID>::IDType,
+            &'a Self::Edge,
+        ),
+    >
+    where
+        Self::Edge: 'a,
+    {
+        self.iter_edge_indices()
+            .map(|(from, to)| (from.into(), to.into(), &()))
+    }
+
+    fn get_all_edges_mut<'a>(
+        &'a mut self,
+    ) -> impl Iterator<
+        Item = (
+            <Self::Vertex as WithID>::IDType,
+            <Self::Vertex as WithID>::IDType,
+            &'a mut Self::Edge,
+        ),
+    >
+    where
+        Self::Edge: 'a,
+    {
+        self.iter_edge_indices()
+            .map(|(from, to)| (from.into(), to.into(), unit_mut()))
+    }
+
+    fn get_adjacent_vertices<'a>(
+        &'a self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> impl Iterator<Item = &'a Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.get_adjacent_vertices_internal(vertex_id)
+    }
+
+    fn get_adjacent_vertices_with_edges<'a>(
+        &'a self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> impl Iterator<Item = (&'a Self::Vertex, &'a Self::Edge)>
+    where
+        Self::Vertex: 'a,
+        Self::Edge: 'a,
+    {
+        self.get_adjacent_vertices_internal(vertex_id)
+            .map(|v| (v, &()))
+    }
+
+    fn out_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        self.out_degree_internal(vertex_id)
+    }
+
+    fn in_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        self.in_degree_internal(vertex_id)
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.count_set_bits()
+    }
+
+    fn get_total_weight(&self) -> <Self::Edge as WeightedEdge>::WeightType
+    where
+        Self::Edge: WeightedEdge,
+    {
+        // Unit edges do not carry any weight
+        Default::default()
+    }
+}
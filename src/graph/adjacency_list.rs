@@ -70,6 +70,36 @@ where
         Ok(())
     }
 
+    /// Adds an edge without checking for an existing edge between the pair,
+    /// see [`Graph::push_parallel_edge`](crate::Graph::push_parallel_edge).
+    pub fn push_parallel_edge(
+        &mut self,
+        from: Vertex::IDType,
+        to: Vertex::IDType,
+        edge: Edge,
+    ) -> Result<(), GraphError<Vertex::IDType>> {
+        if !self.vertices.contains_key(&from) {
+            return Err(GraphError::VertexNotFound(from));
+        }
+        if !self.vertices.contains_key(&to) {
+            return Err(GraphError::VertexNotFound(to));
+        }
+
+        if !Dir::IS_DIRECTED {
+            if from == to {
+                return Err(GraphError::OperationFailed(
+                    "Undirected graphs do not support self-loops".to_string(),
+                ));
+            }
+            self.adjacency
+                .entry(to)
+                .or_default()
+                .push((from, edge.clone()));
+        }
+        self.adjacency.entry(from).or_default().push((to, edge));
+        Ok(())
+    }
+
     fn push_vertex(&mut self, vertex: Vertex) -> Result<(), GraphError<Vertex::IDType>> {
         let vid = vertex.get_id();
         if self.vertices.contains_key(&vid) {
@@ -80,6 +110,39 @@ where
         Ok(())
     }
 
+    fn remove_vertex(
+        &mut self,
+        vertex_id: Vertex::IDType,
+    ) -> Result<Vertex, GraphError<Vertex::IDType>> {
+        let vertex = self
+            .vertices
+            .remove(&vertex_id)
+            .ok_or(GraphError::VertexNotFound(vertex_id))?;
+
+        // Drop the outgoing edges and every edge pointing to the removed vertex
+        self.adjacency.remove(&vertex_id);
+        for adjacency_list in self.adjacency.values_mut() {
+            adjacency_list.retain(|(to, _)| to != &vertex_id);
+        }
+
+        Ok(vertex)
+    }
+
+    fn remove_edge_internal(
+        &mut self,
+        from_id: Vertex::IDType,
+        to_id: Vertex::IDType,
+    ) -> Option<Edge> {
+        let adjacency_list = self.adjacency.get_mut(&from_id)?;
+        let idx = adjacency_list.iter().position(|(to, _)| to == &to_id)?;
+        // Keep the order of the remaining neighbors stable
+        Some(adjacency_list.remove(idx).1)
+    }
+
+    fn clear_edges_internal(&mut self) {
+        self.adjacency.clear();
+    }
+
     fn get_vertex_by_id(&self, vertex_id: Vertex::IDType) -> Option<&Vertex> {
         self.vertices.get(&vertex_id)
     }
@@ -113,6 +176,13 @@ where
         self.vertices.values()
     }
 
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Vertex>
+    where
+        Vertex: 'a,
+    {
+        self.vertices.values_mut()
+    }
+
     fn get_adjacent_vertices<'a>(
         &'a self,
         vertex_id: Vertex::IDType,
@@ -157,6 +227,22 @@ where
             .flatten()
     }
 
+    fn out_degree(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        self.vertices
+            .contains_key(&vertex_id)
+            .then(|| self.adjacency.get(&vertex_id).map_or(0, Vec::len))
+    }
+
+    fn in_degree(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        self.vertices.contains_key(&vertex_id).then(|| {
+            self.adjacency
+                .values()
+                .flatten()
+                .filter(|(to, _)| to == &vertex_id)
+                .count()
+        })
+    }
+
     fn vertex_count(&self) -> usize {
         self.vertices.len()
     }
@@ -244,6 +330,21 @@ where
         Ok(())
     }
 
+    fn remove_vertex(
+        &mut self,
+        vertex_id: Vertex::IDType,
+    ) -> Result<Vertex, GraphError<Vertex::IDType>> {
+        self.remove_vertex(vertex_id)
+    }
+
+    fn remove_edge(&mut self, from_id: Vertex::IDType, to_id: Vertex::IDType) -> Option<Edge> {
+        self.remove_edge_internal(from_id, to_id)
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear_edges_internal();
+    }
+
     fn is_directed(&self) -> bool {
         true
     }
@@ -279,6 +380,13 @@ where
         self.get_all_vertices()
     }
 
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Vertex>
+    where
+        Vertex: 'a,
+    {
+        self.get_all_vertices_mut()
+    }
+
     fn get_adjacent_vertices<'a>(
         &'a self,
         vertex_id: Vertex::IDType,
@@ -335,6 +443,14 @@ where
             })
     }
 
+    fn out_degree(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        self.out_degree(vertex_id)
+    }
+
+    fn in_degree(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        self.in_degree(vertex_id)
+    }
+
     fn vertex_count(&self) -> usize {
         self.vertex_count()
     }
@@ -419,6 +535,25 @@ where
         Ok(())
     }
 
+    fn remove_vertex(
+        &mut self,
+        vertex_id: Vertex::IDType,
+    ) -> Result<Vertex, GraphError<Vertex::IDType>> {
+        self.remove_vertex(vertex_id)
+    }
+
+    fn remove_edge(&mut self, from_id: Vertex::IDType, to_id: Vertex::IDType) -> Option<Edge> {
+        let edge = self.remove_edge_internal(from_id, to_id)?;
+        if from_id != to_id {
+            self.remove_edge_internal(to_id, from_id);
+        }
+        Some(edge)
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear_edges_internal();
+    }
+
     fn is_directed(&self) -> bool {
         false
     }
@@ -454,6 +589,13 @@ where
         self.get_all_vertices()
     }
 
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Vertex>
+    where
+        Vertex: 'a,
+    {
+        self.get_all_vertices_mut()
+    }
+
     fn get_adjacent_vertices<'a>(
         &'a self,
         vertex_id: Vertex::IDType,
@@ -518,6 +660,15 @@ where
             })
     }
 
+    fn out_degree(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        self.out_degree(vertex_id)
+    }
+
+    fn in_degree(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        // Every edge is stored in the adjacency lists of both endpoints
+        self.out_degree(vertex_id)
+    }
+
     fn vertex_count(&self) -> usize {
         self.vertex_count()
     }
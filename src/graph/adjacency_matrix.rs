@@ -38,41 +38,146 @@ where
         }
     }
 
-    fn new_with_size(n_vertices: usize) -> Self
-    where
-        Edge: Clone,
-    {
+    /// Returns the number of bytes taken by the cells of the matrix, without the vertices.
+    pub fn matrix_size_in_bytes(&self) -> usize {
+        self.matrix.iter().map(Vec::len).sum::<usize>() * size_of::<Option<Edge>>()
+    }
+
+    /// Creates an empty graph with capacity for `n_vertices` vertices.
+    ///
+    /// The matrix always has exactly one row and column per vertex, so it only grows when vertices are pushed.
+    fn new_with_size(n_vertices: usize) -> Self {
         AdjacencyMatrixGraph {
             vertices: Vec::with_capacity(n_vertices),
-            matrix: vec![vec![None; n_vertices]; n_vertices],
+            matrix: Vec::with_capacity(n_vertices),
             _phantom: PhantomData,
         }
     }
 
+    /// Creates a graph without edges from the given vertices, which may be passed in any order.
+    ///
+    /// The vertex IDs must form the range `0..n` (the matrix is allocated once with `n` rows and columns).
+    fn from_vertices(mut vertices: Vec<Vertex>) -> Result<Self, GraphError<Vertex::IDType>>
+    where
+        Edge: Clone,
+    {
+        vertices.sort_unstable_by_key(|v| v.get_id().into());
+
+        for (expected_idx, vertex) in vertices.iter().enumerate() {
+            let idx: usize = vertex.get_id().into();
+            if idx < expected_idx {
+                return Err(GraphError::DuplicateVertex(vertex.get_id()));
+            }
+            if idx > expected_idx {
+                return Err(GraphError::OperationFailed(format!(
+                    "Vertex IDs must be sequential in AdjacencyMatrixGraph (missing vertex {})",
+                    expected_idx
+                )));
+            }
+        }
+
+        let n = vertices.len();
+        Ok(AdjacencyMatrixGraph {
+            vertices,
+            matrix: vec![vec![None; n]; n],
+            _phantom: PhantomData,
+        })
+    }
+
     fn push_edge_internal(
         &mut self,
         from: Vertex::IDType,
         to: Vertex::IDType,
         edge: Edge,
     ) -> Result<(), GraphError<Vertex::IDType>> {
+        let (from_idx, to_idx) = self.edge_indices(from, to)?;
+
+        if self.matrix[from_idx][to_idx].is_some() {
+            return Err(GraphError::DuplicateEdge(from, to));
+        }
+
+        self.matrix[from_idx][to_idx] = Some(edge);
+
+        Ok(())
+    }
+
+    /// Returns the matrix indices of an edge, or `GraphError::VertexNotFound` if an endpoint does not exist.
+    fn edge_indices(
+        &self,
+        from: Vertex::IDType,
+        to: Vertex::IDType,
+    ) -> Result<(usize, usize), GraphError<Vertex::IDType>> {
         let from_idx: usize = from.into();
         let to_idx: usize = to.into();
 
-        if self.vertices.get(from_idx).is_none() {
+        if from_idx >= self.vertices.len() {
             return Err(GraphError::VertexNotFound(from));
         }
-
-        if self.vertices.get(to_idx).is_none() {
+        if to_idx >= self.vertices.len() {
             return Err(GraphError::VertexNotFound(to));
         }
 
-        if self.matrix[from_idx][to_idx].is_some() {
-            return Err(GraphError::DuplicateEdge(from, to));
+        Ok((from_idx, to_idx))
+    }
+
+    fn remove_vertex_internal(
+        &mut self,
+        vertex_id: Vertex::IDType,
+    ) -> Result<Vertex, GraphError<Vertex::IDType>> {
+        let idx: usize = vertex_id.into();
+        if idx >= self.vertices.len() {
+            return Err(GraphError::VertexNotFound(vertex_id));
+        }
+        if idx + 1 != self.vertices.len() {
+            return Err(GraphError::OperationFailed(format!(
+                "Only the vertex with the highest ID can be removed from AdjacencyMatrixGraph to keep IDs sequential (expected {}, got {})",
+                self.vertices.len() - 1,
+                idx
+            )));
         }
 
-        self.matrix[from_idx][to_idx] = Some(edge);
+        let vertex = self.vertices.remove(idx);
 
-        Ok(())
+        // Drop the last row and column, including all edges of the removed vertex
+        self.matrix.truncate(idx);
+        for row in &mut self.matrix {
+            row.truncate(idx);
+        }
+
+        Ok(vertex)
+    }
+
+    fn can_remove_vertices_internal(&self, vertex_ids: &[Vertex::IDType]) -> bool {
+        // Only the vertex with the highest ID can be removed at a time, so the vertices must form the end of the ID range
+        let mut indices = vertex_ids
+            .iter()
+            .map(|&vid| vid.into())
+            .collect::<Vec<usize>>();
+        indices.sort_unstable();
+        let n_vertices = self.vertices.len();
+        indices.len() <= n_vertices
+            && indices
+                .into_iter()
+                .eq(n_vertices - vertex_ids.len()..n_vertices)
+    }
+
+    fn remove_edge_internal(
+        &mut self,
+        from_id: Vertex::IDType,
+        to_id: Vertex::IDType,
+    ) -> Option<Edge> {
+        let from_idx: usize = from_id.into();
+        let to_idx: usize = to_id.into();
+        if from_idx >= self.vertices.len() || to_idx >= self.vertices.len() {
+            return None;
+        }
+        self.matrix[from_idx][to_idx].take()
+    }
+
+    fn clear_edges_internal(&mut self) {
+        for row in self.matrix.iter_mut() {
+            row.iter_mut().for_each(|cell| *cell = None);
+        }
     }
 
     fn get_vertex_by_id_internal(&self, vertex_id: Vertex::IDType) -> Option<&Vertex> {
@@ -86,9 +191,21 @@ where
     fn get_edge_internal(&self, from_id: Vertex::IDType, to_id: Vertex::IDType) -> Option<&Edge> {
         let from_idx: usize = from_id.into();
         let to_idx: usize = to_id.into();
+        if from_idx >= self.vertices.len() || to_idx >= self.vertices.len() {
+            return None;
+        }
         self.matrix[from_idx][to_idx].as_ref()
     }
 
+    fn has_edge_internal(&self, from_id: Vertex::IDType, to_id: Vertex::IDType) -> bool {
+        let from_idx: usize = from_id.into();
+        let to_idx: usize = to_id.into();
+        self.matrix
+            .get(from_idx)
+            .and_then(|row| row.get(to_idx))
+            .is_some_and(Option::is_some)
+    }
+
     fn get_edge_mut_internal(
         &mut self,
         from_id: <Vertex as WithID>::IDType,
@@ -96,6 +213,9 @@ where
     ) -> Option<&mut Edge> {
         let from_idx: usize = from_id.into();
         let to_idx: usize = to_id.into();
+        if from_idx >= self.vertices.len() || to_idx >= self.vertices.len() {
+            return None;
+        }
         self.matrix[from_idx][to_idx].as_mut()
     }
 
@@ -103,6 +223,10 @@ where
         self.vertices.iter()
     }
 
+    fn get_all_vertices_mut_internal(&mut self) -> impl Iterator<Item = &mut Vertex> {
+        self.vertices.iter_mut()
+    }
+
     fn get_adjacent_vertices_internal(
         &self,
         vertex_id: Vertex::IDType,
@@ -149,6 +273,21 @@ where
         )
     }
 
+    fn out_degree_internal(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        let idx: usize = vertex_id.into();
+        self.matrix
+            .get(idx)
+            .map(|row| row.iter().filter(|e| e.is_some()).count())
+    }
+
+    fn in_degree_internal(&self, vertex_id: Vertex::IDType) -> Option<usize> {
+        let idx: usize = vertex_id.into();
+        if idx >= self.vertices.len() {
+            return None;
+        }
+        Some(self.matrix.iter().filter(|row| row[idx].is_some()).count())
+    }
+
     fn vertex_count_internal(&self) -> usize {
         self.vertices.len()
     }
@@ -241,22 +380,12 @@ where
     where
         Self: Sized,
     {
-        if vertices.is_empty() {
-            return Ok(Self::new());
-        }
-
-        let n = vertices.len();
-        let mut graph = Self::new_with_size(n);
-
         // Insert vertices
-        for vertex in vertices {
-            graph.vertices.push(vertex);
-        }
+        let mut graph = Self::from_vertices(vertices)?;
 
         // Insert edges
         for (from, to, edge) in edges {
-            let from_idx: usize = from.into();
-            let to_idx: usize = to.into();
+            let (from_idx, to_idx) = graph.edge_indices(from, to)?;
 
             graph.matrix[from_idx][to_idx] = Some(edge.clone());
             graph.matrix[to_idx][from_idx] = Some(edge);
@@ -283,6 +412,31 @@ where
         Ok(())
     }
 
+    fn remove_vertex(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Result<Self::Vertex, GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.remove_vertex_internal(vertex_id)
+    }
+
+    fn can_remove_vertices(&self, vertex_ids: &[<Self::Vertex as WithID>::IDType]) -> bool {
+        self.can_remove_vertices_internal(vertex_ids)
+    }
+
+    fn remove_edge(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<Self::Edge> {
+        let edge = self.remove_edge_internal(from_id, to_id)?;
+        self.remove_edge_internal(to_id, from_id);
+        Some(edge)
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear_edges_internal();
+    }
+
     fn is_directed(&self) -> bool {
         false
     }
@@ -309,6 +463,14 @@ where
         self.get_edge_internal(from_id, to_id)
     }
 
+    fn has_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> bool {
+        self.has_edge_internal(from_id, to_id)
+    }
+
     fn get_edge_mut(
         &mut self,
         from_id: <Self::Vertex as WithID>::IDType,
@@ -324,6 +486,13 @@ where
         self.get_all_vertices_internal()
     }
 
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.get_all_vertices_mut_internal()
+    }
+
     fn get_all_edges<'a>(
         &'a self,
     ) -> impl Iterator<
@@ -393,6 +562,15 @@ where
         self.get_adjacent_vertices_with_edges_internal(vertex_id)
     }
 
+    fn out_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        self.out_degree_internal(vertex_id)
+    }
+
+    fn in_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        // The matrix is symmetric, so the row has the same entries as the column
+        self.out_degree_internal(vertex_id)
+    }
+
     fn vertex_count(&self) -> usize {
         self.vertex_count_internal()
     }
@@ -457,22 +635,12 @@ where
     where
         Self: Sized,
     {
-        if vertices.is_empty() {
-            return Ok(Self::new());
-        }
-
-        let n = vertices.len();
-        let mut graph = Self::new_with_size(n);
-
         // Insert vertices
-        for vertex in vertices {
-            graph.vertices.push(vertex);
-        }
+        let mut graph = Self::from_vertices(vertices)?;
 
         // Insert edges
         for (from, to, edge) in edges {
-            let from_idx: usize = from.into();
-            let to_idx: usize = to.into();
+            let (from_idx, to_idx) = graph.edge_indices(from, to)?;
 
             graph.matrix[from_idx][to_idx] = Some(edge);
         }
@@ -497,8 +665,31 @@ where
         Ok(())
     }
 
+    fn remove_vertex(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Result<Self::Vertex, GraphError<<Self::Vertex as WithID>::IDType>> {
+        self.remove_vertex_internal(vertex_id)
+    }
+
+    fn can_remove_vertices(&self, vertex_ids: &[<Self::Vertex as WithID>::IDType]) -> bool {
+        self.can_remove_vertices_internal(vertex_ids)
+    }
+
+    fn remove_edge(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<Self::Edge> {
+        self.remove_edge_internal(from_id, to_id)
+    }
+
+    fn clear_edges(&mut self) {
+        self.clear_edges_internal();
+    }
+
     fn is_directed(&self) -> bool {
-        false
+        true
     }
 
     fn get_vertex_by_id(
@@ -523,6 +714,14 @@ where
         self.get_edge_internal(from_id, to_id)
     }
 
+    fn has_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> bool {
+        self.has_edge_internal(from_id, to_id)
+    }
+
     fn get_edge_mut(
         &mut self,
         from_id: <Self::Vertex as WithID>::IDType,
@@ -538,6 +737,13 @@ where
         self.get_all_vertices_internal()
     }
 
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Self::Vertex>
+    where
+        Self::Vertex: 'a,
+    {
+        self.get_all_vertices_mut_internal()
+    }
+
     fn get_all_edges<'a>(
         &'a self,
     ) -> impl Iterator<
@@ -597,6 +803,14 @@ where
         self.get_adjacent_vertices_with_edges_internal(vertex_id)
     }
 
+    fn out_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        self.out_degree_internal(vertex_id)
+    }
+
+    fn in_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize> {
+        self.in_degree_internal(vertex_id)
+    }
+
     fn vertex_count(&self) -> usize {
         self.vertex_count_internal()
     }
@@ -0,0 +1,228 @@
+use std::{
+    hash::Hash,
+    io::{Read, Write},
+};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::traits::{GraphBase, WithID},
+    GraphError,
+};
+
+use super::{Direction, EdgeWithWeight, Graph, Vertex};
+
+/// Magic bytes at the start of every binary snapshot (including the format version)
+const MAGIC: &[u8; 4] = b"GLB1";
+
+/// Encodes vertex or edge data for [`Graph::write_binary`].
+pub trait ToBytes {
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+/// Decodes vertex or edge data for [`Graph::read_binary`].
+pub trait FromBytes: Sized {
+    /// Returns `None` if the bytes do not encode a valid value.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_bytes_number {
+    ($($t:ty),*) => {
+        $(
+            impl ToBytes for $t {
+                fn to_bytes(&self) -> Vec<u8> {
+                    self.to_le_bytes().to_vec()
+                }
+            }
+
+            impl FromBytes for $t {
+                fn from_bytes(bytes: &[u8]) -> Option<Self> {
+                    Some(<$t>::from_le_bytes(bytes.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes_number!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+// `usize` is always stored with 64 bits, so snapshots are portable between platforms
+impl ToBytes for usize {
+    fn to_bytes(&self) -> Vec<u8> {
+        (*self as u64).to_bytes()
+    }
+}
+
+impl FromBytes for usize {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        u64::from_bytes(bytes)?.try_into().ok()
+    }
+}
+
+impl ToBytes for () {
+    fn to_bytes(&self) -> Vec<u8> {
+        vec![]
+    }
+}
+
+impl FromBytes for () {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.is_empty().then_some(())
+    }
+}
+
+impl ToBytes for Vertex {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.id.to_bytes()
+    }
+}
+
+impl FromBytes for Vertex {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(Vertex {
+            id: FromBytes::from_bytes(bytes)?,
+        })
+    }
+}
+
+impl ToBytes for EdgeWithWeight {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.weight.to_bytes()
+    }
+}
+
+impl FromBytes for EdgeWithWeight {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Some(EdgeWithWeight::new(FromBytes::from_bytes(bytes)?))
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Vertex: ToBytes,
+    Backend::Edge: ToBytes,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Writes a binary snapshot of the graph, which can be loaded again with [`Graph::read_binary`].
+    ///
+    /// Format (all numbers little endian):
+    /// - the magic bytes `GLB1` and one byte whether the graph is directed
+    /// - the number of vertices (`u64`), followed by each vertex as `u32` length and its bytes
+    /// - the number of edges (`u64`), followed by each edge as the indices of its endpoints in the vertex list
+    ///   (`u64` each), `u32` length and its bytes
+    ///
+    /// Undirected edges are only stored once.
+    pub fn write_binary<W: Write>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), GraphError<<Backend::Vertex as WithID>::IDType>> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[Backend::Direction::IS_DIRECTED as u8])?;
+
+        writer.write_all(&(self.vertex_count() as u64).to_le_bytes())?;
+        let mut vertex_indices = FxHashMap::default();
+        for (idx, vertex) in self.get_all_vertices().enumerate() {
+            vertex_indices.insert(vertex.get_id(), idx as u64);
+            write_chunk(writer, &vertex.to_bytes())?;
+        }
+
+        writer.write_all(&(self.edge_count() as u64).to_le_bytes())?;
+        for (from, to, edge) in self.get_all_edges() {
+            writer.write_all(&vertex_indices[&from].to_le_bytes())?;
+            writer.write_all(&vertex_indices[&to].to_le_bytes())?;
+            write_chunk(writer, &edge.to_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Vertex: FromBytes,
+    Backend::Edge: FromBytes,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Reads a binary snapshot created by [`Graph::write_binary`].
+    ///
+    /// # Errors
+    /// - `GraphError::IoError`: if reading fails (e.g. the snapshot is truncated).
+    /// - `GraphError::InvalidFormat`: if the data is not a snapshot or the direction does not match the backend.
+    /// - `GraphError::ParseError`: if a vertex or edge cannot be decoded.
+    pub fn read_binary<R: Read>(
+        reader: &mut R,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(GraphError::InvalidFormat(
+                "Missing binary graph snapshot header".to_string(),
+            ));
+        }
+        if header[4] != Backend::Direction::IS_DIRECTED as u8 {
+            return Err(GraphError::InvalidFormat(
+                "The direction of the snapshot does not match the graph type".to_string(),
+            ));
+        }
+
+        let vertex_count = read_u64(reader)?;
+        let vertices = (0..vertex_count)
+            .map(|_| {
+                Backend::Vertex::from_bytes(&read_chunk(reader)?)
+                    .ok_or_else(|| GraphError::ParseError("Cannot decode vertex".to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let edge_count = read_u64(reader)?;
+        let edges = (0..edge_count)
+            .map(|_| {
+                let mut endpoint = || {
+                    let idx = read_u64(reader)?;
+                    usize::try_from(idx)
+                        .ok()
+                        .and_then(|idx| vertices.get(idx))
+                        .map(|v| v.get_id())
+                        .ok_or_else(|| {
+                            GraphError::InvalidFormat(format!("Vertex index {idx} out of range"))
+                        })
+                };
+                let from = endpoint()?;
+                let to = endpoint()?;
+                let edge = Backend::Edge::from_bytes(&read_chunk(reader)?)
+                    .ok_or_else(|| GraphError::ParseError("Cannot decode edge".to_string()))?;
+                Ok((from, to, edge))
+            })
+            .collect::<Result<Vec<_>, GraphError<_>>>()?;
+
+        Self::from_vertices_and_edges(vertices, edges)
+    }
+}
+
+/// Writes a length-prefixed chunk of bytes
+fn write_chunk<W: Write>(writer: &mut W, bytes: &[u8]) -> std::io::Result<()> {
+    let len = u32::try_from(bytes.len()).map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Encoded vertex or edge is larger than 4 GiB",
+        )
+    })?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(bytes)
+}
+
+/// Reads a length-prefixed chunk of bytes
+fn read_chunk<R: Read>(reader: &mut R) -> std::io::Result<Vec<u8>> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+    let mut bytes = vec![0; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> std::io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
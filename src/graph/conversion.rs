@@ -0,0 +1,218 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::traits::{GraphBase, WithID},
+    GraphError,
+};
+
+use super::{EdgeWeight, EdgeWithWeight, Graph};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Vertex: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Creates a weighted copy of this graph, e.g. after loading an unweighted file with [`Graph::from_hoever_file_default`].
+    ///
+    /// The vertices are copied as they are, every edge `(from, to)` is replaced by an [`EdgeWithWeight`]
+    /// whose weight is given by `weight_fn(from, to)`.
+    pub fn to_weighted<F, OutputBackend>(
+        &self,
+        weight_fn: F,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        ) -> EdgeWeight,
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = EdgeWithWeight,
+            Direction = Backend::Direction,
+        >,
+    {
+        let vertices = self.get_all_vertices().cloned().collect();
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, _)| (from, to, EdgeWithWeight::new(weight_fn(from, to))))
+            .collect();
+
+        Graph::from_vertices_and_edges(vertices, edges)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Edge: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Creates a copy of this graph with relabeled vertices.
+    ///
+    /// `relabel` turns every vertex into a vertex of the output graph, which may use a different ID (and ID type).
+    /// All edge endpoints are remapped to the IDs of the new vertices, the edge data is copied.
+    ///
+    /// # Errors
+    /// Returns the errors of building the output graph (in terms of the new IDs),
+    /// e.g. `GraphError::DuplicateVertex` if two vertices are mapped to the same ID.
+    #[allow(clippy::type_complexity)]
+    pub fn relabel_vertices<F, OutputBackend>(
+        &self,
+        relabel: F,
+    ) -> Result<Graph<OutputBackend>, GraphError<<OutputBackend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(&Backend::Vertex) -> OutputBackend::Vertex,
+        OutputBackend: GraphBase<Edge = Backend::Edge, Direction = Backend::Direction>,
+        <OutputBackend::Vertex as WithID>::IDType: Copy,
+    {
+        self.relabel_vertices_in_order(self.get_all_vertices(), relabel)
+    }
+
+    /// Creates a copy of this graph whose vertex IDs are compacted to `0..n`, assigned in ascending order of the old IDs.
+    ///
+    /// This makes graphs with sparse IDs (e.g. after removing vertices) loadable into backends
+    /// that require sequential IDs, like the adjacency matrix.
+    /// `vertex_builder` receives the new index and the old vertex and creates the vertex for the output graph,
+    /// which must have the given index as its ID.
+    #[allow(clippy::type_complexity)]
+    pub fn compact_ids<F, OutputBackend>(
+        &self,
+        vertex_builder: F,
+    ) -> Result<Graph<OutputBackend>, GraphError<<OutputBackend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(usize, &Backend::Vertex) -> OutputBackend::Vertex,
+        OutputBackend: GraphBase<Edge = Backend::Edge, Direction = Backend::Direction>,
+        <OutputBackend::Vertex as WithID>::IDType: Copy,
+        <Backend::Vertex as WithID>::IDType: Ord,
+    {
+        let mut vertices = self.get_all_vertices().collect::<Vec<_>>();
+        vertices.sort_unstable_by_key(|v| v.get_id());
+        let index_of = vertices
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| (v.get_id(), idx))
+            .collect::<FxHashMap<_, _>>();
+
+        // Insert the vertices in the order of their new IDs, as sequential backends expect them that way
+        self.relabel_vertices_in_order(vertices.into_iter(), |v| {
+            vertex_builder(index_of[&v.get_id()], v)
+        })
+    }
+
+    /// Builds the relabeled graph, inserting the vertices in the order given by `vertices`
+    #[allow(clippy::type_complexity)]
+    fn relabel_vertices_in_order<'a, F, OutputBackend>(
+        &'a self,
+        vertices: impl Iterator<Item = &'a Backend::Vertex>,
+        relabel: F,
+    ) -> Result<Graph<OutputBackend>, GraphError<<OutputBackend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(&Backend::Vertex) -> OutputBackend::Vertex,
+        OutputBackend: GraphBase<Edge = Backend::Edge, Direction = Backend::Direction>,
+        <OutputBackend::Vertex as WithID>::IDType: Copy,
+    {
+        let mut new_ids = FxHashMap::default();
+        let vertices = vertices
+            .map(|v| {
+                let new_vertex = relabel(v);
+                new_ids.insert(v.get_id(), new_vertex.get_id());
+                new_vertex
+            })
+            .collect::<Vec<_>>();
+
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, edge)| (new_ids[&from], new_ids[&to], edge.clone()))
+            .collect();
+
+        Graph::from_vertices_and_edges(vertices, edges)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Vertex: Clone,
+    Backend::Edge: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Copies the graph into a backend that requires sequential vertex IDs, usually the adjacency matrix.
+    ///
+    /// Useful for algorithms that mostly look up edges between given vertices (like the TSP algorithms),
+    /// which the matrix answers in constant time.
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: if the vertex IDs do not form the range `0..n`.
+    ///   Use [`Graph::compact_ids`] to renumber the vertices first.
+    /// - Errors of building the output graph.
+    pub fn to_matrix<OutputBackend>(
+        &self,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+        <Backend::Vertex as WithID>::IDType: Into<usize>,
+    {
+        let vertex_count = self.vertex_count();
+        let mut seen = vec![false; vertex_count];
+        for vertex in self.get_all_vertices() {
+            let idx: usize = vertex.get_id().into();
+            match seen.get_mut(idx) {
+                Some(seen) if !*seen => *seen = true,
+                _ => {
+                    return Err(GraphError::OperationFailed(format!(
+                        "Converting to a matrix backend requires the vertex IDs 0..{vertex_count}, but found the ID {idx}"
+                    )))
+                }
+            }
+        }
+
+        self.copy_into()
+    }
+
+    /// Copies the graph into another backend without restrictions on the vertex IDs, usually the adjacency list.
+    ///
+    /// Useful for algorithms that mostly iterate over the neighbors of a vertex (like BFS or Dijkstra),
+    /// which the list answers in time proportional to the degree.
+    ///
+    /// # Errors
+    /// Returns the errors of building the output graph.
+    pub fn to_list<OutputBackend>(
+        &self,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        self.copy_into()
+    }
+
+    /// Builds a graph of the output backend with copies of all vertices and edges
+    fn copy_into<OutputBackend>(
+        &self,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        let vertices = self.get_all_vertices().cloned().collect();
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, edge)| (from, to, edge.clone()))
+            .collect();
+
+        Graph::from_vertices_and_edges(vertices, edges)
+    }
+}
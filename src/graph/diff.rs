@@ -0,0 +1,115 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashSet;
+
+use crate::graph::{Direction, Graph, GraphBase, WithID};
+
+/// The structural differences between two graphs, as returned by [`Graph::diff`].
+///
+/// "Added" refers to vertices/edges that only exist in the other graph, "removed" to those that only exist in `self`.
+/// Undirected edges are stored as `(smaller ID, larger ID)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphDiff<VId: Eq + Hash> {
+    pub added_vertices: FxHashSet<VId>,
+    pub removed_vertices: FxHashSet<VId>,
+    pub added_edges: FxHashSet<(VId, VId)>,
+    pub removed_edges: FxHashSet<(VId, VId)>,
+}
+
+impl<VId: Eq + Hash> GraphDiff<VId> {
+    /// Returns whether both graphs have the same vertices and edges.
+    pub fn is_empty(&self) -> bool {
+        self.added_vertices.is_empty()
+            && self.removed_vertices.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Compares the vertex IDs and edges of two graphs, ignoring vertex and edge data.
+    ///
+    /// Mainly intended for debugging and for comparing algorithm outputs against expected graphs.
+    pub fn diff(&self, other: &Graph<Backend>) -> GraphDiff<<Backend::Vertex as WithID>::IDType> {
+        let vertices_of = |graph: &Self| -> FxHashSet<_> {
+            graph.get_all_vertices().map(|v| v.get_id()).collect()
+        };
+        let edges_of = |graph: &Self| -> FxHashSet<_> {
+            graph
+                .get_all_edges()
+                .map(|(from, to, _)| {
+                    if Backend::Direction::IS_DIRECTED {
+                        (from, to)
+                    } else {
+                        (from.min(to), from.max(to))
+                    }
+                })
+                .collect()
+        };
+
+        let (own_vertices, other_vertices) = (vertices_of(self), vertices_of(other));
+        let (own_edges, other_edges) = (edges_of(self), edges_of(other));
+
+        GraphDiff {
+            added_vertices: other_vertices.difference(&own_vertices).copied().collect(),
+            removed_vertices: own_vertices.difference(&other_vertices).copied().collect(),
+            added_edges: other_edges.difference(&own_edges).copied().collect(),
+            removed_edges: own_edges.difference(&other_edges).copied().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashSet;
+
+    use crate::{graph::GraphBase, test_utils::MockVertex, Directed, ListGraph, Undirected};
+
+    #[test]
+    fn test_diff_undirected() {
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 3, ())],
+        )
+        .unwrap();
+        let mut other = graph.clone();
+        other.push_edge(3, 0, ()).unwrap();
+
+        assert!(graph.diff(&graph).is_empty());
+
+        let diff = graph.diff(&other);
+        assert_eq!(diff.added_edges, FxHashSet::from_iter([(0, 3)]));
+        assert!(diff.removed_edges.is_empty());
+        assert!(diff.added_vertices.is_empty());
+        assert!(diff.removed_vertices.is_empty());
+
+        let reverse = other.diff(&graph);
+        assert_eq!(reverse.removed_edges, FxHashSet::from_iter([(0, 3)]));
+        assert!(reverse.added_edges.is_empty());
+    }
+
+    #[test]
+    fn test_diff_directed_vertices_and_orientation() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ())],
+        )
+        .unwrap();
+        let other = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (1..5).map(|id| MockVertex { id }).collect(),
+            vec![(2, 1, ()), (3, 4, ())],
+        )
+        .unwrap();
+
+        let diff = graph.diff(&other);
+
+        assert_eq!(diff.added_vertices, FxHashSet::from_iter([3, 4]));
+        assert_eq!(diff.removed_vertices, FxHashSet::from_iter([0]));
+        assert_eq!(diff.added_edges, FxHashSet::from_iter([(2, 1), (3, 4)]));
+        assert_eq!(diff.removed_edges, FxHashSet::from_iter([(0, 1), (1, 2)]));
+    }
+}
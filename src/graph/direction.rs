@@ -1,10 +1,17 @@
-pub trait Direction: 'static + Sized {}
+pub trait Direction: 'static + Sized {
+    /// Whether edges of graphs with this direction are directed
+    const IS_DIRECTED: bool;
+}
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Directed;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Undirected;
 
-impl Direction for Directed {}
-impl Direction for Undirected {}
+impl Direction for Directed {
+    const IS_DIRECTED: bool = true;
+}
+impl Direction for Undirected {
+    const IS_DIRECTED: bool = false;
+}
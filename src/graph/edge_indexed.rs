@@ -0,0 +1,185 @@
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::graph::{Graph, GraphBase, WithID};
+
+/// A read-only view of a graph that numbers its edges `0..m`, as returned by [`Graph::with_edge_ids`].
+///
+/// The IDs are only stable for the lifetime of the overlay, a new overlay may number the edges differently.
+#[allow(clippy::type_complexity)]
+pub struct EdgeIndexed<'a, Backend: GraphBase> {
+    edges: Vec<(
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Vertex as WithID>::IDType,
+        &'a Backend::Edge,
+    )>,
+    incident_edges: FxHashMap<<Backend::Vertex as WithID>::IDType, Vec<usize>>,
+}
+
+impl<Backend> EdgeIndexed<'_, Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Returns the endpoints and data of the edge with the given ID, or `None` if `id >= edge_count()`.
+    #[allow(clippy::type_complexity)]
+    pub fn edge(
+        &self,
+        id: usize,
+    ) -> Option<(
+        <Backend::Vertex as WithID>::IDType,
+        <Backend::Vertex as WithID>::IDType,
+        &Backend::Edge,
+    )> {
+        self.edges
+            .get(id)
+            .map(|(from, to, edge)| (*from, *to, *edge))
+    }
+
+    /// Returns the IDs of all edges incident to the vertex (outgoing and incoming for directed graphs).
+    ///
+    /// Returns an empty slice if the vertex does not exist.
+    pub fn edges_of_vertex(&self, vertex_id: <Backend::Vertex as WithID>::IDType) -> &[usize] {
+        self.incident_edges
+            .get(&vertex_id)
+            .map_or(&[], |edge_ids| edge_ids.as_slice())
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash,
+{
+    /// Creates a read-only overlay that assigns every edge an integer ID in `0..m`.
+    ///
+    /// Undirected edges get a single ID, which is listed for both endpoints.
+    pub fn with_edge_ids(&self) -> EdgeIndexed<'_, Backend> {
+        let edges = self.get_all_edges().collect::<Vec<_>>();
+
+        let mut incident_edges = self
+            .get_all_vertices()
+            .map(|v| (v.get_id(), Vec::new()))
+            .collect::<FxHashMap<_, _>>();
+        for (id, (from, to, _)) in edges.iter().enumerate() {
+            incident_edges.entry(*from).or_default().push(id);
+            if from != to {
+                incident_edges.entry(*to).or_default().push(id);
+            }
+        }
+
+        EdgeIndexed {
+            edges,
+            incident_edges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rustc_hash::FxHashSet;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, ListGraph, Undirected,
+    };
+
+    #[rstest]
+    fn test_edge_ids_cover_all_edges_once(
+        #[values(
+            ListGraph::<MockVertex, usize, Undirected>::new(),
+            MatrixGraph::<MockVertex, usize, Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = usize, Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..5 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3)];
+        for (from, to) in edges {
+            graph.push_edge(from, to, from * 10 + to).unwrap();
+        }
+
+        let indexed = graph.with_edge_ids();
+
+        assert_eq!(indexed.edge_count(), edges.len());
+        let endpoints = (0..indexed.edge_count())
+            .map(|id| {
+                let (from, to, data) = indexed.edge(id).unwrap();
+                // Edge data belongs to the same edge
+                assert!(*data == from * 10 + to || *data == to * 10 + from);
+                (from.min(to), from.max(to))
+            })
+            .collect::<FxHashSet<_>>();
+        assert_eq!(
+            endpoints,
+            FxHashSet::from_iter([(0, 1), (1, 2), (0, 2), (2, 3)])
+        );
+        assert!(indexed.edge(edges.len()).is_none());
+
+        // Every edge is listed exactly once for each of its endpoints
+        for id in 0..indexed.edge_count() {
+            let (from, to, _) = indexed.edge(id).unwrap();
+            assert_eq!(
+                indexed
+                    .edges_of_vertex(from)
+                    .iter()
+                    .filter(|e| **e == id)
+                    .count(),
+                1
+            );
+            assert_eq!(
+                indexed
+                    .edges_of_vertex(to)
+                    .iter()
+                    .filter(|e| **e == id)
+                    .count(),
+                1
+            );
+        }
+        assert_eq!(indexed.edges_of_vertex(2).len(), 3);
+        assert!(indexed.edges_of_vertex(4).is_empty());
+        assert!(indexed.edges_of_vertex(42).is_empty());
+
+        // IDs are stable within the overlay
+        let first_pass = (0..indexed.edge_count())
+            .map(|id| indexed.edge(id))
+            .collect::<Vec<_>>();
+        let second_pass = (0..indexed.edge_count())
+            .map(|id| indexed.edge(id))
+            .collect::<Vec<_>>();
+        assert_eq!(first_pass, second_pass);
+    }
+
+    #[test]
+    fn test_directed_edge_ids() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 0, ()), (1, 2, ())],
+        )
+        .unwrap();
+
+        let indexed = graph.with_edge_ids();
+
+        assert_eq!(indexed.edge_count(), 3);
+        assert_eq!(indexed.edges_of_vertex(1).len(), 3);
+        let edges = indexed
+            .edges_of_vertex(0)
+            .iter()
+            .map(|id| {
+                let (from, to, _) = indexed.edge(*id).unwrap();
+                (from, to)
+            })
+            .collect::<FxHashSet<_>>();
+        assert_eq!(edges, FxHashSet::from_iter([(0, 1), (1, 0)]));
+    }
+}
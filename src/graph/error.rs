@@ -13,6 +13,9 @@ pub enum GraphError<VId> {
     #[error("Edge between vertices {0} and {1} already exists")]
     DuplicateEdge(VId, VId),
 
+    #[error("Edge between vertices {0} and {1} not found")]
+    EdgeNotFound(VId, VId),
+
     #[error("Invalid file format: {0}")]
     InvalidFormat(String),
 
@@ -33,4 +36,7 @@ pub enum GraphError<VId> {
 
     #[error("Algorithm error: {0}")]
     AlgorithmError(String),
+
+    #[error("Graph contains a cycle")]
+    CycleDetected,
 }
@@ -1,6 +1,6 @@
 use std::{
     fmt::{Debug, Display},
-    fs,
+    fs, io,
     str::FromStr,
 };
 
@@ -26,8 +26,9 @@ where
         vertex_builder: fn(id: <Backend::Vertex as WithID>::IDType) -> Backend::Vertex,
         edge_builder: fn(remaining: Vec<&str>) -> Backend::Edge,
     ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
-        // Open the file in read-only mode.
-        let file_contents = fs::read_to_string(path).map_err(GraphError::IoError)?;
+        // Open the file in read-only mode. The path is added to the error, as `io::Error` does not contain it.
+        let file_contents = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("Cannot read '{path}': {e}")))?;
         let mut line_iter = file_contents.lines();
 
         // Parse first line (number of vertices)
@@ -1,15 +1,19 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, hash::Hash};
 
 use crate::{
     graph::{
+        adjacency_bit_matrix::AdjacencyBitMatrixGraph,
         adjacency_list::AdjacencyListGraph,
         traits::{GraphBase, WeightedEdge, WithID},
     },
     GraphError,
 };
 use delegate::delegate;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use super::{adjacency_matrix::AdjacencyMatrixGraph, Directed, IntoDirected, Undirected};
+use super::{
+    adjacency_matrix::AdjacencyMatrixGraph, Directed, Direction, IntoDirected, Undirected,
+};
 
 #[derive(Debug, Clone)]
 pub struct Graph<Backend> {
@@ -23,6 +27,9 @@ pub type ListGraphBackend<Vertex, Edge, Dir> = AdjacencyListGraph<Vertex, Edge,
 pub type MatrixGraph<Vertex, Edge, Dir> = Graph<AdjacencyMatrixGraph<Vertex, Edge, Dir>>;
 pub type MatrixGraphBackend<Vertex, Edge, Dir> = AdjacencyMatrixGraph<Vertex, Edge, Dir>;
 
+pub type BitMatrixGraph<Vertex, Dir> = Graph<AdjacencyBitMatrixGraph<Vertex, Dir>>;
+pub type BitMatrixGraphBackend<Vertex, Dir> = AdjacencyBitMatrixGraph<Vertex, Dir>;
+
 impl<Backend> GraphBase for Graph<Backend>
 where
     Backend: GraphBase,
@@ -77,6 +84,23 @@ where
                 edge: Self::Edge,
             ) -> Result<(), GraphError<<Self::Vertex as WithID>::IDType>>;
 
+            fn remove_vertex(
+                &mut self,
+                vertex_id: <Self::Vertex as WithID>::IDType,
+            ) -> Result<Self::Vertex, GraphError<<Self::Vertex as WithID>::IDType>>;
+
+            fn can_remove_vertices(&self, vertex_ids: &[<Self::Vertex as WithID>::IDType]) -> bool
+            where
+                <Self::Vertex as WithID>::IDType: Clone;
+
+            fn remove_edge(
+                &mut self,
+                from_id: <Self::Vertex as WithID>::IDType,
+                to_id: <Self::Vertex as WithID>::IDType,
+            ) -> Option<Self::Edge>;
+
+            fn clear_edges(&mut self);
+
             fn is_directed(&self) -> bool;
 
             fn get_vertex_by_id(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<&Self::Vertex>;
@@ -92,6 +116,12 @@ where
                 to_id: <Self::Vertex as WithID>::IDType,
             ) -> Option<&Self::Edge>;
 
+            fn has_edge(
+                &self,
+                from_id: <Self::Vertex as WithID>::IDType,
+                to_id: <Self::Vertex as WithID>::IDType,
+            ) -> bool;
+
             fn get_edge_mut(
                 &mut self,
                 from_id: <Self::Vertex as WithID>::IDType,
@@ -102,6 +132,10 @@ where
             where
                 Self::Vertex: 'a;
 
+            fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Self::Vertex>
+            where
+                Self::Vertex: 'a;
+
             fn get_all_edges<'a>(
                 &'a self,
             ) -> impl Iterator<
@@ -141,6 +175,14 @@ where
                 Self::Vertex: 'a,
                 Self::Edge: 'a;
 
+            fn out_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize>
+            where
+                <Self::Vertex as WithID>::IDType: Clone;
+
+            fn in_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize>
+            where
+                <Self::Vertex as WithID>::IDType: Clone + PartialEq;
+
             fn vertex_count(&self) -> usize;
 
             fn edge_count(&self) -> usize;
@@ -161,6 +203,380 @@ where
     }
 }
 
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+{
+    /// Returns whether there is an edge from `from_id` to `to_id`.
+    ///
+    /// For undirected graphs the order of the parameters does not matter.
+    pub fn contains_edge(
+        &self,
+        from_id: <Backend::Vertex as WithID>::IDType,
+        to_id: <Backend::Vertex as WithID>::IDType,
+    ) -> bool {
+        self.has_edge(from_id, to_id)
+    }
+
+    /// Applies `f` to every vertex of the graph in place,
+    /// e.g. to annotate the vertices with the results of an algorithm.
+    ///
+    /// `f` must not change the ID of a vertex.
+    pub fn assign_vertex_data<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Backend::Vertex),
+    {
+        self.get_all_vertices_mut().for_each(f);
+    }
+
+    /// Applies `f` to every edge of the graph in place.
+    ///
+    /// This is cheaper than building a new graph when the edge type stays the same,
+    /// e.g. for scaling or perturbing weights.
+    /// For undirected graphs, `f` is called once per edge and the result is stored for both directions.
+    pub fn map_edge_weights_in_place<F>(&mut self, f: F)
+    where
+        F: Fn(&mut Backend::Edge),
+        Backend::Edge: Clone,
+        <Backend::Vertex as WithID>::IDType: Copy + PartialEq,
+    {
+        let mut mirrored = Vec::new();
+        for (from, to, edge) in self.get_all_edges_mut() {
+            f(edge);
+            // Undirected backends only yield one of the two stored directions
+            if !Backend::Direction::IS_DIRECTED && from != to {
+                mirrored.push((to, from, edge.clone()));
+            }
+        }
+
+        for (from, to, edge) in mirrored {
+            if let Some(mirrored_edge) = self.get_edge_mut(from, to) {
+                *mirrored_edge = edge;
+            }
+        }
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Edge: WeightedEdge,
+    <Backend::Vertex as WithID>::IDType: Copy,
+{
+    /// Sums the edge weights along the vertex sequence, e.g. to score a tour that is not given as a [`super::Path`].
+    ///
+    /// For a tour, the sequence has to end with its start vertex to include the closing edge.
+    /// Sequences with less than two vertices have a cost of 0.
+    ///
+    /// # Errors
+    /// - `GraphError::EdgeNotFound`: if two consecutive vertices are not connected by an edge.
+    pub fn path_cost(
+        &self,
+        sequence: &[<Backend::Vertex as WithID>::IDType],
+    ) -> Result<
+        <Backend::Edge as WeightedEdge>::WeightType,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        let mut cost = <Backend::Edge as WeightedEdge>::WeightType::default();
+        for pair in sequence.windows(2) {
+            let edge = self
+                .get_edge(pair[0], pair[1])
+                .ok_or(GraphError::EdgeNotFound(pair[0], pair[1]))?;
+            cost += edge.get_weight();
+        }
+        Ok(cost)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Eq + Hash,
+{
+    /// Returns the adjacent vertices of the given vertex like `get_adjacent_vertices`,
+    /// but yields every neighbor only once, even if it is connected by parallel edges
+    /// (see [`Graph::push_parallel_edge`]).
+    ///
+    /// For graphs without parallel edges both are equivalent.
+    pub fn distinct_neighbors(
+        &self,
+        vertex_id: <Backend::Vertex as WithID>::IDType,
+    ) -> impl Iterator<Item = &Backend::Vertex> {
+        let mut seen = FxHashSet::default();
+        self.get_adjacent_vertices(vertex_id)
+            .filter(move |v| seen.insert(v.get_id()))
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Eq + Hash + Ord,
+{
+    /// Returns the number of edges incident to the given vertex.
+    ///
+    /// For directed graphs this is the sum of the in- and out-degree. A self-loop counts twice,
+    /// as both of its ends are incident to the vertex (the same as in [`Graph::stats`]).
+    /// Returns 0 if the vertex does not exist.
+    pub fn degree(&self, vertex_id: <Backend::Vertex as WithID>::IDType) -> usize {
+        let out_degree = self.out_degree(vertex_id).unwrap_or(0);
+        if !self.is_directed() {
+            // The adjacency only contains an undirected self-loop once
+            return out_degree + usize::from(self.has_edge(vertex_id, vertex_id));
+        }
+
+        out_degree + self.in_degree(vertex_id).unwrap_or(0)
+    }
+
+    /// Returns `Some(k)` if every vertex has the [`degree`](Graph::degree) `k`, otherwise `None`.
+    ///
+    /// A graph without vertices is not considered regular.
+    pub fn is_regular(&self) -> Option<usize> {
+        let mut degrees = self.get_all_vertices().map(|v| self.degree(v.get_id()));
+        let first = degrees.next()?;
+        degrees.all(|degree| degree == first).then_some(first)
+    }
+
+    /// Returns a plain adjacency-list view of the graph, independent of the backend.
+    ///
+    /// Every vertex is a key (also vertices without edges), mapped to the IDs of its adjacent vertices in ascending order.
+    /// Mainly intended for debugging, printing and assertions in tests.
+    pub fn adjacency_repr(
+        &self,
+    ) -> FxHashMap<<Backend::Vertex as WithID>::IDType, Vec<<Backend::Vertex as WithID>::IDType>>
+    {
+        self.get_all_vertices()
+            .map(|v| {
+                let mut neighbors = self
+                    .get_adjacent_vertices(v.get_id())
+                    .map(|n| n.get_id())
+                    .collect::<Vec<_>>();
+                neighbors.sort_unstable();
+                (v.get_id(), neighbors)
+            })
+            .collect()
+    }
+
+    /// Removes all vertices with a degree of 0 and returns their IDs in ascending order.
+    ///
+    /// The vertices are removed from the highest to the lowest ID, so that backends requiring
+    /// sequential IDs (like the adjacency matrix) can drop isolated vertices at the end of the ID range.
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: when the backend cannot remove all isolated vertices
+    ///   (see [`GraphBase::can_remove_vertices`]). The graph is not modified in this case.
+    #[allow(clippy::type_complexity)]
+    pub fn remove_isolated_vertices(
+        &mut self,
+    ) -> Result<
+        Vec<<Backend::Vertex as WithID>::IDType>,
+        GraphError<<Backend::Vertex as WithID>::IDType>,
+    > {
+        // A single pass over the edges finds every vertex with a degree > 0
+        let connected = self
+            .get_all_edges()
+            .flat_map(|(from, to, _)| [from, to])
+            .collect::<FxHashSet<_>>();
+
+        let mut isolated = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .filter(|vid| !connected.contains(vid))
+            .collect::<Vec<_>>();
+        isolated.sort_unstable();
+
+        if !self.can_remove_vertices(&isolated) {
+            return Err(GraphError::OperationFailed(
+                "The backend cannot remove all isolated vertices".to_string(),
+            ));
+        }
+        for &vid in isolated.iter().rev() {
+            self.remove_vertex(vid)?;
+        }
+
+        Ok(isolated)
+    }
+
+    /// Repeatedly removes all leaves (vertices with a degree of 1) together with their edges
+    /// and returns the number of removed vertices.
+    ///
+    /// Every round removes the leaves present at its start, vertices becoming leaves are removed in the next round.
+    /// Runs `rounds` rounds, or until no leaves remain if `rounds` is `None`. Pruning until no leaves remain
+    /// leaves the cycles of the graph together with the paths between them. A tree is reduced to its center:
+    /// a single vertex, or nothing if the center consists of two adjacent vertices that become leaves
+    /// in the same round (e.g. a single edge or a path with an even number of vertices).
+    ///
+    /// Within a round, the vertices are removed from the highest to the lowest ID (see [`Graph::remove_isolated_vertices`]).
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: when the backend cannot remove one of the leaves.
+    ///   Vertices removed before the failure stay removed.
+    pub fn prune_leaves(
+        &mut self,
+        rounds: Option<usize>,
+    ) -> Result<usize, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        let mut removed = 0;
+
+        for _ in 0..rounds.unwrap_or(usize::MAX) {
+            let mut leaves = self
+                .get_all_vertices()
+                .map(|v| v.get_id())
+                .filter(|&vid| self.degree(vid) == 1)
+                .collect::<Vec<_>>();
+            if leaves.is_empty() {
+                break;
+            }
+            leaves.sort_unstable();
+
+            for &vid in leaves.iter().rev() {
+                self.remove_vertex(vid)?;
+            }
+            removed += leaves.len();
+        }
+
+        Ok(removed)
+    }
+
+    /// Merges multiple edges between the same pair of vertices into a single edge, e.g. after importing data with duplicates.
+    ///
+    /// The edges of each pair are folded with `reduce` in the order the backend stores them,
+    /// e.g. `|a, b| if a.weight <= b.weight { a.clone() } else { b.clone() }` keeps the lightest edge.
+    /// For undirected graphs `(a, b)` and `(b, a)` are the same pair.
+    /// Only the adjacency list can store parallel edges (see [`Graph::push_parallel_edge`]),
+    /// for the other backends this is a no-op.
+    ///
+    /// # Errors
+    /// - Any error of [`GraphBase::push_edge`] when re-inserting a merged edge.
+    pub fn collapse_parallel_edges<F>(
+        &mut self,
+        reduce: F,
+    ) -> Result<(), GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        F: Fn(&Backend::Edge, &Backend::Edge) -> Backend::Edge,
+    {
+        let mut edge_counts = FxHashMap::<_, usize>::default();
+        for (from, to, _) in self.get_all_edges() {
+            let key = if self.is_directed() {
+                (from, to)
+            } else {
+                (from.min(to), from.max(to))
+            };
+            *edge_counts.entry(key).or_default() += 1;
+        }
+
+        let mut parallel_pairs = edge_counts
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(pair, _)| pair)
+            .collect::<Vec<_>>();
+        parallel_pairs.sort_unstable();
+
+        for (from, to) in parallel_pairs {
+            // Every call removes one of the parallel edges
+            let mut merged = self
+                .remove_edge(from, to)
+                .expect("The pair has parallel edges");
+            while let Some(edge) = self.remove_edge(from, to) {
+                merged = reduce(&merged, &edge);
+            }
+            self.push_edge(from, to, merged)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Vertex, Edge, Dir> MatrixGraph<Vertex, Edge, Dir>
+where
+    Vertex: WithID,
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+    Dir: Direction,
+{
+    /// Returns the number of bytes taken by the cells of the adjacency matrix, without the vertices.
+    pub fn matrix_size_in_bytes(&self) -> usize {
+        self.backend.matrix_size_in_bytes()
+    }
+}
+
+impl<Vertex, Dir> BitMatrixGraph<Vertex, Dir>
+where
+    Vertex: WithID,
+    Vertex::IDType: Into<usize> + From<usize> + Copy,
+    Dir: Direction,
+{
+    /// Returns the number of bytes taken by the bit rows of the adjacency matrix, without the vertices,
+    /// e.g. to compare it with [`MatrixGraph::matrix_size_in_bytes`].
+    pub fn matrix_size_in_bytes(&self) -> usize {
+        self.backend.matrix_size_in_bytes()
+    }
+}
+
+impl<Vertex, Edge, Dir> ListGraph<Vertex, Edge, Dir>
+where
+    Vertex: WithID,
+    Vertex::IDType: Eq + Hash + PartialOrd + Copy,
+    Edge: Clone,
+    Dir: Direction,
+{
+    /// Adds an edge like [`GraphBase::push_edge`], but allows multiple edges between the same pair of vertices,
+    /// e.g. to model a multigraph. Use [`Graph::collapse_parallel_edges`] to merge them again.
+    ///
+    /// In undirected graphs, the edge is stored for both directions.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: when either the source or target vertex ID does not exist
+    /// - `GraphError::OperationFailed`: when trying to add a self-loop to an undirected graph
+    pub fn push_parallel_edge(
+        &mut self,
+        from: Vertex::IDType,
+        to: Vertex::IDType,
+        edge: Edge,
+    ) -> Result<(), GraphError<Vertex::IDType>> {
+        self.backend.push_parallel_edge(from, to, edge)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    Backend::Edge: PartialEq,
+    <Backend::Vertex as WithID>::IDType: Copy + Debug,
+{
+    /// Checks the invariant of undirected backends that every edge is stored for both orientations,
+    /// i.e. every `(a, b)` has a matching `(b, a)` with equal edge data.
+    ///
+    /// Meant for tests and debugging, e.g. after mutating an edge through [`GraphBase::get_edge_mut`],
+    /// which only changes the given orientation.
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: describing the first edge without a matching counterpart.
+    pub fn verify_undirected_consistency(
+        &self,
+    ) -> Result<(), GraphError<<Backend::Vertex as WithID>::IDType>> {
+        for vertex in self.get_all_vertices() {
+            let from = vertex.get_id();
+            for (neighbor, edge) in self.get_adjacent_vertices_with_edges(from) {
+                let to = neighbor.get_id();
+                match self.get_edge(to, from) {
+                    None => {
+                        return Err(GraphError::OperationFailed(format!(
+                            "Edge ({from:?}, {to:?}) has no matching edge ({to:?}, {from:?})"
+                        )))
+                    }
+                    Some(reverse_edge) if reverse_edge != edge => {
+                        return Err(GraphError::OperationFailed(format!(
+                            "Edges ({from:?}, {to:?}) and ({to:?}, {from:?}) have different data"
+                        )))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<BackendIn, BackendOut> IntoDirected<Graph<BackendOut>> for Graph<BackendIn>
 where
     BackendIn: GraphBase<Direction = Undirected> + IntoDirected<BackendOut>,
@@ -177,28 +593,19 @@ where
 mod tests {
     use rstest::rstest;
 
-    use crate::{Directed, Undirected};
+    use crate::{
+        test_utils::{build_graph, MockVertex},
+        Directed, Undirected,
+    };
 
     use super::*;
 
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    struct MockVertex {
-        id: usize,
-    }
-
-    impl WithID for MockVertex {
-        type IDType = usize;
-
-        fn get_id(&self) -> usize {
-            self.id
-        }
-    }
-
     #[rstest]
     fn test_push_vertex(
         #[values(
             ListGraph::<MockVertex, (), Directed>::new(),
             MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
         )]
         mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
     ) {
@@ -213,6 +620,93 @@ mod tests {
         )); // Duplicate
     }
 
+    #[rstest]
+    fn test_is_directed_for_directed_backends(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
+        )]
+        graph: Graph<impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>>,
+    ) {
+        assert!(graph.is_directed());
+    }
+
+    #[rstest]
+    fn test_is_directed_for_undirected_backends(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new(),
+            BitMatrixGraph::<MockVertex, Undirected>::new(),
+        )]
+        graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        assert!(!graph.is_directed());
+    }
+
+    #[rstest]
+    fn test_from_vertices_and_edges_rejects_out_of_range_edges(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
+        )]
+        graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+        #[values(vec![(0, 3)], vec![(3, 0)], vec![(0, 1), (1, 2), (2, 3)])] edges: Vec<(
+            usize,
+            usize,
+        )>,
+    ) {
+        // The graph value is only used to select the backend type
+        fn build<Backend>(
+            _: &Backend,
+            edges: &[(usize, usize)],
+        ) -> Result<Backend, GraphError<usize>>
+        where
+            Backend: GraphBase<Vertex = MockVertex, Edge = ()>,
+        {
+            Backend::from_vertices_and_edges(
+                (0..3).map(|id| MockVertex { id }).collect(),
+                edges.iter().map(|(from, to)| (*from, *to, ())).collect(),
+            )
+        }
+
+        assert!(matches!(
+            build(&graph, &edges),
+            Err(GraphError::VertexNotFound(3))
+        ));
+    }
+
+    #[rstest]
+    fn test_from_vertices_and_edges_accepts_any_vertex_order(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
+        )]
+        graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+    ) {
+        // The graph value is only used to select the backend type
+        fn build<Backend>(_: &Backend) -> Result<Backend, GraphError<usize>>
+        where
+            Backend: GraphBase<Vertex = MockVertex, Edge = ()>,
+        {
+            Backend::from_vertices_and_edges(
+                [2, 0, 1].map(|id| MockVertex { id }).to_vec(),
+                vec![(2, 0, ()), (0, 1, ())],
+            )
+        }
+
+        let built = build(&graph).unwrap();
+
+        assert_eq!(built.vertex_count(), 3);
+        assert!(built.has_edge(2, 0));
+        assert!(built.has_edge(0, 1));
+        assert_eq!(built.edge_count(), 2);
+    }
+
     #[rstest]
     fn test_push_edge(
         #[values(
@@ -280,6 +774,7 @@ mod tests {
         #[values(
             ListGraph::<MockVertex, (), Directed>::new(),
             MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
         )]
         mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
     ) {
@@ -301,6 +796,7 @@ mod tests {
         #[values(
             ListGraph::<MockVertex, (), Directed>::new(),
             MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
         )]
         mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
     ) {
@@ -403,6 +899,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_path_cost() {
+        let graph = ListGraph::<MockVertex, MockWeightedEdge, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, MockWeightedEdge { weight: 2 }),
+                (1, 2, MockWeightedEdge { weight: 3 }),
+                (2, 0, MockWeightedEdge { weight: 4 }),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(graph.path_cost(&[0, 1, 2, 0]).unwrap(), 9);
+        assert_eq!(graph.path_cost(&[1]).unwrap(), 0);
+        assert_eq!(graph.path_cost(&[]).unwrap(), 0);
+        // The edges are directed
+        assert!(matches!(
+            graph.path_cost(&[0, 2, 1]),
+            Err(GraphError::EdgeNotFound(0, 2))
+        ));
+    }
+
     #[rstest]
     fn test_get_edge_directed(
         #[values(
@@ -455,6 +973,31 @@ mod tests {
         assert_eq!(graph.get_edge(1, 0).unwrap().get_weight(), 42);
     }
 
+    #[rstest]
+    fn test_get_edge_unknown_vertex(
+        #[values(
+            ListGraph::<MockVertex, MockWeightedEdge, Undirected>::new(),
+            MatrixGraph::<MockVertex, MockWeightedEdge, Undirected>::new()
+        )]
+        mut graph: impl GraphBase<
+            Vertex = MockVertex,
+            Edge = MockWeightedEdge,
+            Direction = Undirected,
+        >,
+    ) {
+        for id in 0..3 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph
+            .push_edge(0, 1, MockWeightedEdge { weight: 42 })
+            .unwrap();
+
+        assert!(graph.get_edge(99, 0).is_none());
+        assert!(graph.get_edge(0, 99).is_none());
+        assert!(graph.get_edge_mut(99, 0).is_none());
+        assert!(graph.get_edge_mut(0, 99).is_none());
+    }
+
     #[rstest]
     fn test_get_total_weight_directed(
         #[values(
@@ -564,7 +1107,8 @@ mod tests {
     fn test_vertex_count(
         #[values(
             ListGraph::<MockVertex, (), Directed>::new(),
-            MatrixGraph::<MockVertex, (), Directed>::new()
+            MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new()
         )]
         mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
     ) {
@@ -630,4 +1174,649 @@ mod tests {
         assert!(graph.push_edge(0, 1, 30).is_err());
         assert_eq!(graph.edge_count(), 2);
     }
+
+    #[rstest]
+    fn test_remove_vertex(
+        #[values(
+            ListGraph::<MockVertex, i32, Directed>::new(),
+            MatrixGraph::<MockVertex, i32, Directed>::new()
+        )]
+        mut graph: impl GraphBase<Vertex = MockVertex, Edge = i32, Direction = Directed>,
+    ) {
+        for id in 0..3 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 2, 10).unwrap();
+        graph.push_edge(2, 1, 20).unwrap();
+        graph.push_edge(0, 1, 30).unwrap();
+
+        assert_eq!(graph.remove_vertex(2).unwrap(), MockVertex { id: 2 });
+        assert_eq!(graph.vertex_count(), 2);
+        // Incoming and outgoing edges of the removed vertex are gone
+        assert_eq!(graph.get_all_edges().collect::<Vec<_>>(), vec![(0, 1, &30)]);
+        assert!(matches!(
+            graph.remove_vertex(2),
+            Err(GraphError::VertexNotFound(2))
+        ));
+    }
+
+    #[rstest]
+    fn test_remove_edge_undirected(
+        #[values(
+            ListGraph::<MockVertex, i32, Undirected>::new(),
+            MatrixGraph::<MockVertex, i32, Undirected>::new()
+        )]
+        mut graph: impl GraphBase<Vertex = MockVertex, Edge = i32, Direction = Undirected>,
+    ) {
+        for id in 0..3 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 1, 10).unwrap();
+        graph.push_edge(1, 2, 20).unwrap();
+
+        assert_eq!(graph.remove_edge(1, 0), Some(10));
+        assert_eq!(graph.remove_edge(0, 1), None);
+        assert!(graph.get_edge(0, 1).is_none());
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.get_adjacent_vertices(1).count(), 1);
+    }
+
+    #[rstest]
+    fn test_clear_edges(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new(),
+            BitMatrixGraph::<MockVertex, Undirected>::new(),
+        )]
+        mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        graph.clear_edges();
+
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.get_adjacent_vertices(0).count(), 0);
+
+        // The same edges can be added again
+        graph.push_edge(0, 1, ()).unwrap();
+        graph.push_edge(3, 0, ()).unwrap();
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.get_edge(1, 0).is_some());
+    }
+
+    #[test]
+    fn test_matrix_only_removes_highest_vertex() {
+        let mut graph = MatrixGraph::<MockVertex, (), Directed>::new();
+        for id in 0..3 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+
+        assert!(matches!(
+            graph.remove_vertex(1),
+            Err(GraphError::OperationFailed(_))
+        ));
+        assert!(graph.remove_vertex(2).is_ok());
+        // The freed ID can be pushed again
+        assert!(graph.push_vertex(MockVertex { id: 2 }).is_ok());
+    }
+
+    #[test]
+    fn test_degree() {
+        let mut directed = ListGraph::<MockVertex, (), Directed>::new();
+        let mut undirected = ListGraph::<MockVertex, (), Undirected>::new();
+        for id in 0..3 {
+            directed.push_vertex(MockVertex { id }).unwrap();
+            undirected.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (2, 1)] {
+            directed.push_edge(from, to, ()).unwrap();
+            undirected.push_edge(from, to, ()).unwrap();
+        }
+
+        assert_eq!(directed.degree(1), 2);
+        assert_eq!(directed.degree(0), 1);
+        assert_eq!(undirected.degree(1), 2);
+        assert_eq!(undirected.degree(3), 0);
+    }
+
+    #[rstest]
+    fn test_degree_counts_self_loops_twice(
+        #[values(
+            BitMatrixGraph::<MockVertex, Directed>::new(),
+            BitMatrixGraph::<MockVertex, Undirected>::new(),
+        )]
+        mut graph: Graph<impl GraphBase<Vertex = MockVertex, Edge = ()>>,
+    ) {
+        for id in 0..2 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 0, ()).unwrap();
+        graph.push_edge(0, 1, ()).unwrap();
+
+        assert_eq!(graph.degree(0), 3);
+        assert_eq!(graph.degree(1), 1);
+        assert_eq!(
+            graph.stats().max_degree,
+            3,
+            "degree and stats must agree on self-loops"
+        );
+    }
+
+    #[rstest]
+    fn test_in_and_out_degree_of_directed_star(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
+        )]
+        mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+    ) {
+        // 0 points to 1, 2 and 3, which point back to 0 only from 3
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (0, 2), (0, 3), (3, 0)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        assert_eq!(graph.out_degree(0), Some(3));
+        assert_eq!(graph.in_degree(0), Some(1));
+        assert_eq!(graph.out_degree(1), Some(0));
+        assert_eq!(graph.in_degree(1), Some(1));
+        assert_eq!(graph.out_degree(3), Some(1));
+        assert_eq!(graph.in_degree(3), Some(1));
+        assert_eq!(graph.out_degree(4), None);
+        assert_eq!(graph.in_degree(4), None);
+    }
+
+    #[rstest]
+    fn test_in_and_out_degree_of_undirected_triangle(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new(),
+            BitMatrixGraph::<MockVertex, Undirected>::new(),
+        )]
+        mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+    ) {
+        // The triangle 0 - 1 - 2 and the isolated vertex 3
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        for id in 0..3 {
+            assert_eq!(graph.out_degree(id), Some(2));
+            assert_eq!(graph.in_degree(id), Some(2));
+        }
+        assert_eq!(graph.out_degree(3), Some(0));
+        assert_eq!(graph.in_degree(3), Some(0));
+        assert_eq!(graph.out_degree(4), None);
+        assert_eq!(graph.in_degree(4), None);
+    }
+
+    #[rstest]
+    #[case::cycle(4, vec![(0, 1), (1, 2), (2, 3), (3, 0)], Some(2))]
+    #[case::k4(4, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)], Some(3))]
+    #[case::star(4, vec![(0, 1), (0, 2), (0, 3)], None)]
+    #[case::no_edges(3, vec![], Some(0))]
+    #[case::empty(0, vec![], None)]
+    fn test_is_regular(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected: Option<usize>,
+    ) {
+        let graph = ListGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..n_vertices).map(|id| MockVertex { id }).collect(),
+            edges.into_iter().map(|(from, to)| (from, to, ())).collect(),
+        )
+        .unwrap();
+
+        assert_eq!(graph.is_regular(), expected);
+    }
+
+    #[rstest]
+    fn test_verify_undirected_consistency(
+        #[values(
+            ListGraph::<MockVertex, i32, Undirected>::new(),
+            MatrixGraph::<MockVertex, i32, Undirected>::new(),
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = i32, Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+            graph.push_edge(from, to, (from * 10 + to) as i32).unwrap();
+        }
+        assert!(graph.verify_undirected_consistency().is_ok());
+
+        // Only changes the stored orientation (2, 1)
+        *graph.get_edge_mut(2, 1).unwrap() = 42;
+
+        assert!(matches!(
+            graph.verify_undirected_consistency(),
+            Err(GraphError::OperationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_collapse_parallel_edges_keeps_lightest_edge() {
+        let mut graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 3.0), (1, 2, 1.0), (1, 0, 4.0)],
+        )
+        .unwrap();
+        graph.push_parallel_edge(0, 1, 2.0).unwrap();
+        graph.push_parallel_edge(0, 1, 5.0).unwrap();
+        assert_eq!(graph.edge_count(), 5);
+
+        graph.collapse_parallel_edges(|a, b| a.min(*b)).unwrap();
+
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.get_edge(0, 1), Some(&2.0));
+        assert_eq!(graph.get_edge(1, 0), Some(&4.0));
+        assert_eq!(graph.get_edge(1, 2), Some(&1.0));
+    }
+
+    #[test]
+    fn test_collapse_parallel_edges_undirected() {
+        let mut graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 3.0), (1, 2, 1.0)],
+        )
+        .unwrap();
+        graph.push_parallel_edge(1, 0, 2.0).unwrap();
+        assert_eq!(graph.edge_count(), 3);
+        assert!(matches!(
+            graph.push_parallel_edge(2, 2, 1.0),
+            Err(GraphError::OperationFailed(_))
+        ));
+
+        graph.collapse_parallel_edges(|a, b| a + b).unwrap();
+
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.get_edge(0, 1), Some(&5.0));
+        assert_eq!(graph.get_edge(1, 0), Some(&5.0));
+        assert!(graph.verify_undirected_consistency().is_ok());
+
+        // Without parallel edges nothing changes
+        let before = graph.adjacency_repr();
+        graph.collapse_parallel_edges(|a, b| a + b).unwrap();
+        assert_eq!(graph.adjacency_repr(), before);
+        assert_eq!(graph.get_edge(1, 2), Some(&1.0));
+    }
+
+    #[test]
+    fn test_distinct_neighbors_of_multigraph() {
+        let mut graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 3.0), (0, 2, 1.0)],
+        )
+        .unwrap();
+        graph.push_parallel_edge(0, 1, 2.0).unwrap();
+
+        let mut adjacent = graph
+            .get_adjacent_vertices(0)
+            .map(|v| v.id)
+            .collect::<Vec<_>>();
+        adjacent.sort_unstable();
+        let mut distinct = graph
+            .distinct_neighbors(0)
+            .map(|v| v.id)
+            .collect::<Vec<_>>();
+        distinct.sort_unstable();
+
+        assert_eq!(adjacent, vec![1, 1, 2]);
+        assert_eq!(distinct, vec![1, 2]);
+        assert_eq!(graph.distinct_neighbors(1).count(), 0);
+    }
+
+    #[rstest]
+    fn test_has_edge_matches_contains_edge(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new(),
+            BitMatrixGraph::<MockVertex, Undirected>::new(),
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..5 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 4)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        for from in 0..5 {
+            for to in 0..5 {
+                assert_eq!(graph.has_edge(from, to), graph.contains_edge(from, to));
+                assert_eq!(graph.has_edge(from, to), graph.get_edge(from, to).is_some());
+            }
+        }
+        assert!(graph.has_edge(1, 0));
+        assert!(!graph.has_edge(0, 3));
+        // Unknown vertices have no edges
+        assert!(!graph.has_edge(0, 42));
+        assert!(!graph.has_edge(42, 0));
+    }
+
+    #[rstest]
+    fn test_adjacency_repr(
+        #[values(
+            ListGraph::<MockVertex, (), Directed>::new(),
+            MatrixGraph::<MockVertex, (), Directed>::new(),
+            BitMatrixGraph::<MockVertex, Directed>::new(),
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Directed>,
+        >,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [(0, 2), (0, 1), (1, 2), (2, 0)] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        let expected =
+            FxHashMap::from_iter([(0, vec![1, 2]), (1, vec![2]), (2, vec![0]), (3, vec![])]);
+        assert_eq!(graph.adjacency_repr(), expected);
+    }
+
+    #[test]
+    fn test_remove_isolated_vertices_after_removing_all_edges() {
+        let mut graph = ListGraph::<MockVertex, (), Undirected>::new();
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 1, ()).unwrap();
+        graph.push_edge(2, 3, ()).unwrap();
+
+        graph.remove_edge(0, 1);
+        graph.remove_edge(3, 2);
+
+        assert_eq!(graph.remove_isolated_vertices().unwrap(), vec![0, 1, 2, 3]);
+        assert_eq!(graph.vertex_count(), 0);
+    }
+
+    #[test]
+    fn test_remove_isolated_vertices_keeps_connected_vertices() {
+        let mut graph = ListGraph::<MockVertex, i32, Directed>::new();
+        for id in 0..5 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 3, 10).unwrap();
+        graph.push_edge(4, 0, 20).unwrap();
+
+        assert_eq!(graph.remove_isolated_vertices().unwrap(), vec![1, 2]);
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.get_edge(0, 3), Some(&10));
+        assert_eq!(graph.get_edge(4, 0), Some(&20));
+    }
+
+    #[test]
+    fn test_remove_isolated_vertices_matrix() {
+        let mut graph = MatrixGraph::<MockVertex, i32, Undirected>::new();
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 1, 10).unwrap();
+
+        assert_eq!(graph.remove_isolated_vertices().unwrap(), vec![2, 3]);
+        assert_eq!(graph.vertex_count(), 2);
+        assert_eq!(graph.get_edge(1, 0), Some(&10));
+    }
+
+    #[test]
+    fn test_remove_isolated_vertices_matrix_fails_without_changes() {
+        // The isolated vertex 1 is not at the end of the ID range, so it cannot be removed
+        let mut graph = MatrixGraph::<MockVertex, i32, Undirected>::new();
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 2, 10).unwrap();
+
+        assert!(matches!(
+            graph.remove_isolated_vertices(),
+            Err(GraphError::OperationFailed(_))
+        ));
+        assert_eq!(graph.vertex_count(), 4);
+    }
+
+    #[test]
+    fn test_prune_leaves_spider() {
+        // Cycle 0 - 1 - 2 - 3 - 0 with legs 0 - 4 - 5 - 6, 2 - 7 and 7 - 8, 7 - 9
+        let mut graph = ListGraph::<MockVertex, (), Undirected>::new();
+        for id in 0..10 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to) in [
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (0, 4),
+            (4, 5),
+            (5, 6),
+            (2, 7),
+            (7, 8),
+            (7, 9),
+        ] {
+            graph.push_edge(from, to, ()).unwrap();
+        }
+
+        assert_eq!(graph.clone().prune_leaves(Some(1)).unwrap(), 3);
+        assert_eq!(graph.prune_leaves(None).unwrap(), 6);
+
+        let mut remaining = graph.get_all_vertices().map(|v| v.id).collect::<Vec<_>>();
+        remaining.sort_unstable();
+        assert_eq!(remaining, vec![0, 1, 2, 3]);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.prune_leaves(None).unwrap(), 0);
+    }
+
+    #[rstest]
+    #[case::single_edge(2, vec![(0, 1)], vec![])]
+    #[case::even_path(4, vec![(0, 1), (1, 2), (2, 3)], vec![])]
+    #[case::odd_path(5, vec![(0, 1), (1, 2), (2, 3), (3, 4)], vec![2])]
+    fn test_prune_leaves_tree_center(
+        #[case] n_vertices: usize,
+        #[case] edges: Vec<(usize, usize)>,
+        #[case] expected_remaining: Vec<usize>,
+    ) {
+        let mut graph: ListGraph<MockVertex, (), Undirected> = build_graph(n_vertices, &edges);
+
+        assert_eq!(
+            graph.prune_leaves(None).unwrap(),
+            n_vertices - expected_remaining.len()
+        );
+        let remaining = graph.get_all_vertices().map(|v| v.id).collect::<Vec<_>>();
+        assert_eq!(remaining, expected_remaining);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct LabeledVertex {
+        id: usize,
+        label: Option<usize>,
+    }
+
+    impl WithID for LabeledVertex {
+        type IDType = usize;
+
+        fn get_id(&self) -> usize {
+            self.id
+        }
+    }
+
+    #[rstest]
+    fn test_assign_vertex_data(
+        #[values(
+            ListGraph::<LabeledVertex, (), Undirected>::new(),
+            MatrixGraph::<LabeledVertex, (), Undirected>::new(),
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = LabeledVertex, Edge = (), Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..4 {
+            graph
+                .push_vertex(LabeledVertex { id, label: None })
+                .unwrap();
+        }
+
+        graph.assign_vertex_data(|v| v.label = Some(v.id * 10));
+
+        for id in 0..4 {
+            assert_eq!(graph.get_vertex_by_id(id).unwrap().label, Some(id * 10));
+        }
+    }
+
+    #[rstest]
+    fn test_get_all_vertices_mut(
+        #[values(
+            ListGraph::<LabeledVertex, (), Directed>::new(),
+            MatrixGraph::<LabeledVertex, (), Directed>::new(),
+            BitMatrixGraph::<LabeledVertex, Directed>::new(),
+        )]
+        mut graph: impl GraphBase<Vertex = LabeledVertex, Edge = (), Direction = Directed>,
+    ) {
+        // No edges, so no vertex is reachable from another one
+        for id in 0..4 {
+            graph
+                .push_vertex(LabeledVertex { id, label: None })
+                .unwrap();
+        }
+
+        for vertex in graph.get_all_vertices_mut() {
+            vertex.label = Some(vertex.id + 1);
+        }
+
+        assert_eq!(graph.get_all_vertices_mut().count(), 4);
+        for id in 0..4 {
+            assert_eq!(graph.get_vertex_by_id(id).unwrap().label, Some(id + 1));
+        }
+    }
+
+    #[rstest]
+    fn test_map_edge_weights_in_place_directed(
+        #[values(
+            ListGraph::<MockVertex, MockWeightedEdge, Directed>::new(),
+            MatrixGraph::<MockVertex, MockWeightedEdge, Directed>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = MockWeightedEdge, Direction = Directed>,
+        >,
+    ) {
+        for id in 0..3 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph
+            .push_edge(0, 1, MockWeightedEdge { weight: 1 })
+            .unwrap();
+        graph
+            .push_edge(1, 0, MockWeightedEdge { weight: 2 })
+            .unwrap();
+        graph
+            .push_edge(1, 2, MockWeightedEdge { weight: 4 })
+            .unwrap();
+
+        graph.map_edge_weights_in_place(|edge| edge.weight *= 2);
+
+        assert_eq!(graph.get_total_weight(), 14);
+        // Opposite directed edges keep their own weights
+        assert_eq!(graph.get_edge(0, 1).unwrap().weight, 2);
+        assert_eq!(graph.get_edge(1, 0).unwrap().weight, 4);
+    }
+
+    #[rstest]
+    fn test_map_edge_weights_in_place_undirected(
+        #[values(
+            ListGraph::<MockVertex, MockWeightedEdge, Undirected>::new(),
+            MatrixGraph::<MockVertex, MockWeightedEdge, Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = MockWeightedEdge, Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..3 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph
+            .push_edge(0, 1, MockWeightedEdge { weight: 1 })
+            .unwrap();
+        graph
+            .push_edge(2, 1, MockWeightedEdge { weight: 4 })
+            .unwrap();
+
+        graph.map_edge_weights_in_place(|edge| edge.weight *= 2);
+
+        assert_eq!(graph.get_total_weight(), 10);
+        // Both stored directions are updated
+        for (from, to, weight) in [(0, 1, 2), (1, 0, 2), (1, 2, 8), (2, 1, 8)] {
+            assert_eq!(graph.get_edge(from, to).unwrap().weight, weight);
+        }
+    }
+
+    #[rstest]
+    fn test_unit_edges_undirected(
+        #[values(
+            ListGraph::<MockVertex, (), Undirected>::new(),
+            MatrixGraph::<MockVertex, (), Undirected>::new(),
+            BitMatrixGraph::<MockVertex, Undirected>::new()
+        )]
+        mut graph: impl GraphBase<Vertex = MockVertex, Edge = (), Direction = Undirected>,
+    ) {
+        // More than 64 vertices, so that rows of the bit matrix span multiple words
+        for id in 0..130 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        graph.push_edge(0, 129, ()).unwrap();
+        graph.push_edge(64, 63, ()).unwrap();
+        graph.push_edge(64, 65, ()).unwrap();
+
+        assert!(matches!(
+            graph.push_edge(129, 0, ()),
+            Err(GraphError::DuplicateEdge(129, 0))
+        ));
+        assert!(matches!(
+            graph.push_edge(0, 130, ()),
+            Err(GraphError::VertexNotFound(130))
+        ));
+        assert_eq!(graph.get_edge(129, 0), Some(&()));
+        assert_eq!(graph.get_edge(0, 64), None);
+        assert_eq!(graph.edge_count(), 3);
+
+        let mut adjacent = graph
+            .get_adjacent_vertices(64)
+            .map(|v| v.id)
+            .collect::<Vec<_>>();
+        adjacent.sort();
+        assert_eq!(adjacent, vec![63, 65]);
+
+        let mut edges = graph
+            .get_all_edges()
+            .map(|(from, to, _)| (from, to))
+            .collect::<Vec<_>>();
+        edges.sort();
+        assert_eq!(edges, vec![(0, 129), (63, 64), (64, 65)]);
+
+        assert_eq!(graph.remove_edge(65, 64), Some(()));
+        assert_eq!(graph.get_edge(64, 65), None);
+        assert!(graph.remove_vertex(129).is_ok());
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.get_adjacent_vertices(0).count(), 0);
+    }
 }
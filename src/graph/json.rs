@@ -0,0 +1,193 @@
+use std::fmt::{self, Display, Write};
+
+use crate::graph::{Direction, Graph, GraphBase, WithID};
+
+/// A JSON value, used to control how vertices and edges are exported by [`Graph::to_json`].
+///
+/// Strings and object keys are escaped when the value is written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    /// Integers are kept exact, instead of going through `f64` (e.g. for large vertex IDs)
+    Integer(i128),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// The entries are written in the given order
+    Object(Vec<(String, JsonValue)>),
+}
+
+macro_rules! impl_json_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for JsonValue {
+                fn from(value: $t) -> Self {
+                    JsonValue::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_json_float {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for JsonValue {
+                fn from(value: $t) -> Self {
+                    JsonValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+impl_json_float!(f32, f64);
+
+impl From<bool> for JsonValue {
+    fn from(value: bool) -> Self {
+        JsonValue::Bool(value)
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(value: &str) -> Self {
+        JsonValue::String(value.to_string())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(value: String) -> Self {
+        JsonValue::String(value)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(JsonValue::Null, Into::into)
+    }
+}
+
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => f.write_str("null"),
+            JsonValue::Bool(value) => write!(f, "{value}"),
+            JsonValue::Integer(value) => write!(f, "{value}"),
+            // JSON has no representation for NaN and infinity
+            JsonValue::Number(value) if !value.is_finite() => f.write_str("null"),
+            JsonValue::Number(value) => write!(f, "{value}"),
+            JsonValue::String(value) => write_escaped(f, value),
+            JsonValue::Array(values) => {
+                f.write_char('[')?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            JsonValue::Object(entries) => {
+                f.write_char('{')?;
+                for (idx, (key, value)) in entries.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+/// Writes a quoted JSON string, escaping quotes, backslashes and control characters
+fn write_escaped(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Into<JsonValue>,
+{
+    /// Exports the graph as JSON with the schema
+    /// `{"directed":bool,"nodes":[...],"edges":[{"from":..,"to":..,"data":..}]}`.
+    ///
+    /// `vertex_json` and `edge_json` control how the vertex and edge data is exported,
+    /// so the types do not need to implement any serialization traits.
+    /// Undirected edges are only exported once.
+    pub fn to_json<FV, FE>(&self, vertex_json: FV, edge_json: FE) -> String
+    where
+        FV: Fn(&Backend::Vertex) -> JsonValue,
+        FE: Fn(&Backend::Edge) -> JsonValue,
+    {
+        let nodes = self.get_all_vertices().map(vertex_json).collect();
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, edge)| {
+                JsonValue::Object(vec![
+                    ("from".to_string(), from.into()),
+                    ("to".to_string(), to.into()),
+                    ("data".to_string(), edge_json(edge)),
+                ])
+            })
+            .collect();
+
+        JsonValue::Object(vec![
+            (
+                "directed".to_string(),
+                Backend::Direction::IS_DIRECTED.into(),
+            ),
+            ("nodes".to_string(), JsonValue::Array(nodes)),
+            ("edges".to_string(), JsonValue::Array(edges)),
+        ])
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonValue;
+
+    #[test]
+    fn test_json_value_escaping() {
+        let value = JsonValue::Object(vec![
+            ("quote\"key".to_string(), "line\nbreak\\ and \u{1}".into()),
+            (
+                "list".to_string(),
+                JsonValue::Array(vec![1.5.into(), None::<u32>.into(), true.into()]),
+            ),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"quote\"key":"line\nbreak\\ and \u0001","list":[1.5,null,true]}"#
+        );
+    }
+
+    #[test]
+    fn test_json_value_integers_are_exact() {
+        let value = JsonValue::Array(vec![u64::MAX.into(), i64::MIN.into(), 3.0_f64.into()]);
+
+        assert_eq!(
+            value.to_string(),
+            "[18446744073709551615,-9223372036854775808,3]"
+        );
+    }
+}
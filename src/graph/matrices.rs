@@ -0,0 +1,172 @@
+use crate::graph::{Direction, Graph, GraphBase, Undirected, WeightedEdge, WithID};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    <Backend::Vertex as WithID>::IDType: Copy + Into<usize>,
+{
+    /// Returns the edge list and the n×m incidence matrix of the graph.
+    ///
+    /// Column `j` of the matrix belongs to the `j`-th edge of the returned edge list, row `i` to the vertex with ID `i`.
+    /// - Directed: the tail (`from`) of an edge is marked with -1, the head (`to`) with +1 (a self-loop is all zeros).
+    /// - Undirected: both endpoints are marked with 1 (a self-loop with 2). Every edge has only one column.
+    ///
+    /// # Requirements
+    /// The vertex IDs must be compact, i.e. form the range `0..n` (as for the adjacency matrix backend).
+    ///
+    /// # Panics
+    /// Panics if a vertex ID is not smaller than the number of vertices.
+    #[allow(clippy::type_complexity)]
+    pub fn to_incidence_matrix(
+        &self,
+    ) -> (
+        Vec<(
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        )>,
+        Vec<Vec<i8>>,
+    ) {
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, _)| (from, to))
+            .collect::<Vec<_>>();
+
+        let mut matrix = vec![vec![0; edges.len()]; self.vertex_count()];
+        for (column, (from, to)) in edges.iter().enumerate() {
+            let (from, to): (usize, usize) = ((*from).into(), (*to).into());
+            if Backend::Direction::IS_DIRECTED {
+                matrix[from][column] -= 1;
+            } else {
+                matrix[from][column] += 1;
+            }
+            matrix[to][column] += 1;
+        }
+
+        (edges, matrix)
+    }
+}
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase<Direction = Undirected>,
+    Backend::Edge: WeightedEdge,
+    <Backend::Edge as WeightedEdge>::WeightType: Into<f64>,
+    <Backend::Vertex as WithID>::IDType: Copy + Into<usize>,
+{
+    /// Returns the weighted Laplacian matrix `L = D - A` of the graph.
+    ///
+    /// `L[i][j]` is the negated weight of the edge between `i` and `j` and `L[i][i]` the summed weight
+    /// of all edges of `i` (self-loops are ignored). The matrix is symmetric and every row sums up to zero.
+    ///
+    /// # Requirements
+    /// The vertex IDs must be compact, i.e. form the range `0..n` (as for the adjacency matrix backend).
+    ///
+    /// # Panics
+    /// Panics if a vertex ID is not smaller than the number of vertices.
+    pub fn laplacian_matrix(&self) -> Vec<Vec<f64>> {
+        let n = self.vertex_count();
+        let mut laplacian = vec![vec![0.0; n]; n];
+
+        for (from, to, edge) in self.get_all_edges() {
+            let (from, to): (usize, usize) = (from.into(), to.into());
+            if from == to {
+                continue;
+            }
+            let weight = edge.get_weight().into();
+            laplacian[from][to] -= weight;
+            laplacian[to][from] -= weight;
+            laplacian[from][from] += weight;
+            laplacian[to][to] += weight;
+        }
+
+        laplacian
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, Graph, ListGraph, Undirected,
+    };
+
+    #[test]
+    fn test_incidence_matrix_directed() {
+        let graph = ListGraph::<MockVertex, (), Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ()), (2, 0, ()), (2, 3, ())],
+        )
+        .unwrap();
+
+        let (edges, matrix) = graph.to_incidence_matrix();
+
+        assert_eq!(edges.len(), 4);
+        assert_eq!(matrix.len(), 4);
+        for (column, (from, to)) in edges.iter().enumerate() {
+            assert_eq!(matrix[*from][column], -1);
+            assert_eq!(matrix[*to][column], 1);
+            // Every column has exactly one tail and one head
+            assert_eq!(matrix.iter().filter(|row| row[column] != 0).count(), 2);
+        }
+        // Vertex 3 is only the head of 2 -> 3
+        assert_eq!(matrix[3].iter().sum::<i8>(), 1);
+        assert_eq!(matrix[2].iter().filter(|entry| **entry == -1).count(), 2);
+    }
+
+    #[test]
+    fn test_incidence_matrix_undirected() {
+        let graph = MatrixGraph::<MockVertex, (), Undirected>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, ()), (1, 2, ())],
+        )
+        .unwrap();
+
+        let (edges, matrix) = graph.to_incidence_matrix();
+
+        assert_eq!(edges.len(), 2);
+        for (column, (from, to)) in edges.iter().enumerate() {
+            assert_eq!(matrix[*from][column], 1);
+            assert_eq!(matrix[*to][column], 1);
+        }
+        // The degree of a vertex is the sum of its row
+        assert_eq!(
+            matrix
+                .iter()
+                .map(|row| row.iter().sum())
+                .collect::<Vec<i8>>(),
+            vec![1, 2, 1]
+        );
+    }
+
+    #[rstest]
+    fn test_laplacian_matrix(
+        #[values(
+            ListGraph::<MockVertex, f64, Undirected>::new(),
+            MatrixGraph::<MockVertex, f64, Undirected>::new()
+        )]
+        mut graph: Graph<
+            impl GraphBase<Vertex = MockVertex, Edge = f64, Direction = Undirected>,
+        >,
+    ) {
+        for id in 0..4 {
+            graph.push_vertex(MockVertex { id }).unwrap();
+        }
+        for (from, to, weight) in [(0, 1, 1.0), (1, 2, 2.5), (2, 0, 0.5), (2, 3, 4.0)] {
+            graph.push_edge(from, to, weight).unwrap();
+        }
+
+        let laplacian = graph.laplacian_matrix();
+
+        assert_eq!(laplacian[2], vec![-0.5, -2.5, 7.0, -4.0]);
+        assert_eq!(laplacian[3], vec![0.0, 0.0, -4.0, 4.0]);
+        for (i, row) in laplacian.iter().enumerate() {
+            assert_eq!(row.iter().sum::<f64>(), 0.0);
+            for (j, entry) in row.iter().enumerate() {
+                assert_eq!(*entry, laplacian[j][i]);
+            }
+        }
+    }
+}
@@ -1,17 +1,33 @@
 #[allow(clippy::module_inception)]
 mod graph;
 
+mod adjacency_bit_matrix;
 mod adjacency_list;
 mod adjacency_matrix;
+mod binary;
+mod conversion;
+mod diff;
 mod direction;
+mod edge_indexed;
 pub mod error;
 pub mod from_file;
 mod graph_structs;
+mod json;
+mod matrices;
 mod path;
+#[cfg(feature = "petgraph")]
+mod petgraph_interop;
+mod set_ops;
 mod traits;
 
+pub use binary::{FromBytes, ToBytes};
+pub use diff::GraphDiff;
 pub use direction::*;
+pub use edge_indexed::EdgeIndexed;
 pub use graph::*;
 pub use graph_structs::{EdgeWeight, EdgeWithWeight, Vertex, VertexIDType};
+pub use json::JsonValue;
 pub use path::Path;
+#[cfg(feature = "petgraph")]
+pub use petgraph_interop::{PetgraphDirection, PetgraphOf};
 pub use traits::*;
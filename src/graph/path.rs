@@ -54,6 +54,40 @@ where
     }
 }
 
+impl<VId, Edge> Path<VId, Edge>
+where
+    VId: PartialEq,
+{
+    /// Returns whether the path ends where it starts, like a TSP tour.
+    ///
+    /// An empty path is not a cycle.
+    pub fn is_cycle(&self) -> bool {
+        match (self.edges.first(), self.edges.last()) {
+            (Some((first_from, _, _)), Some((_, last_to, _))) => first_from == last_to,
+            _ => false,
+        }
+    }
+
+    /// Returns a copy of the cyclic path rotated to begin (and end) at `start`,
+    /// e.g. to compare tours that only differ in their starting vertex.
+    ///
+    /// Returns `None` if the path is not a cycle or does not visit `start`.
+    pub fn rotate_to_start(&self, start: VId) -> Option<Path<VId, Edge>>
+    where
+        VId: Clone,
+        Edge: Clone,
+    {
+        if !self.is_cycle() {
+            return None;
+        }
+
+        let start_idx = self.edges.iter().position(|(from, _, _)| *from == start)?;
+        let mut edges = self.edges.clone();
+        edges.rotate_left(start_idx);
+        Some(Path { edges })
+    }
+}
+
 impl<VId, Edge> Default for Path<VId, Edge> {
     fn default() -> Self {
         Path { edges: Vec::new() }
@@ -149,4 +183,52 @@ mod tests {
         let vertices: Vec<_> = path.vertices().cloned().collect();
         assert_eq!(vertices, vec![1, 2, 3, 4]);
     }
+
+    #[test]
+    fn test_is_cycle() {
+        let tour = Path {
+            edges: vec![
+                (1, 2, MockEdge { weight: 1 }),
+                (2, 3, MockEdge { weight: 2 }),
+                (3, 1, MockEdge { weight: 3 }),
+            ],
+        };
+        let open_path = Path {
+            edges: vec![
+                (1, 2, MockEdge { weight: 1 }),
+                (2, 3, MockEdge { weight: 2 }),
+            ],
+        };
+
+        assert!(tour.is_cycle());
+        assert!(!open_path.is_cycle());
+        assert!(!Path::<u32, MockEdge>::default().is_cycle());
+        assert_eq!(open_path.rotate_to_start(2), None);
+    }
+
+    #[test]
+    fn test_rotate_tour_to_every_start() {
+        let tour = Path {
+            edges: vec![
+                (1, 2, MockEdge { weight: 10 }),
+                (2, 3, MockEdge { weight: 20 }),
+                (3, 4, MockEdge { weight: 30 }),
+                (4, 1, MockEdge { weight: 40 }),
+            ],
+        };
+
+        for start in 1..=4 {
+            let rotated = tour.rotate_to_start(start).unwrap();
+
+            assert!(rotated.is_cycle());
+            assert_eq!(rotated.total_cost(), tour.total_cost());
+            assert_eq!(rotated.nodes().first(), Some(&start));
+            assert_eq!(rotated.len(), tour.len());
+        }
+        assert_eq!(
+            tour.rotate_to_start(3).unwrap().nodes(),
+            vec![3, 4, 1, 2, 3]
+        );
+        assert_eq!(tour.rotate_to_start(5), None);
+    }
 }
@@ -0,0 +1,143 @@
+use std::hash::Hash;
+
+use petgraph::graph::{IndexType, NodeIndex};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    graph::traits::{GraphBase, WithID},
+    GraphError,
+};
+
+use super::{Directed, Direction, Graph, Undirected};
+
+/// Maps the direction of a graph to the corresponding `petgraph` edge type.
+pub trait PetgraphDirection: Direction {
+    type EdgeType: petgraph::EdgeType;
+}
+
+impl PetgraphDirection for Directed {
+    type EdgeType = petgraph::Directed;
+}
+
+impl PetgraphDirection for Undirected {
+    type EdgeType = petgraph::Undirected;
+}
+
+/// The `petgraph` graph type a [`Graph`] with the given backend is converted to.
+pub type PetgraphOf<Backend> = petgraph::Graph<
+    <Backend as GraphBase>::Vertex,
+    <Backend as GraphBase>::Edge,
+    <<Backend as GraphBase>::Direction as PetgraphDirection>::EdgeType,
+>;
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Direction: PetgraphDirection,
+    Backend::Vertex: Clone,
+    Backend::Edge: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Converts the graph into a `petgraph::Graph` with the same directedness.
+    ///
+    /// The vertices and edges are cloned into the node and edge weights.
+    /// Nodes are added in ascending order of their vertex ID, so the node indices are deterministic.
+    pub fn to_petgraph(&self) -> PetgraphOf<Backend> {
+        let mut vertices = self.get_all_vertices().collect::<Vec<_>>();
+        vertices.sort_unstable_by_key(|v| v.get_id());
+
+        let mut petgraph = PetgraphOf::<Backend>::with_capacity(vertices.len(), self.edge_count());
+        let node_indices = vertices
+            .into_iter()
+            .map(|v| (v.get_id(), petgraph.add_node(v.clone())))
+            .collect::<FxHashMap<_, _>>();
+
+        for (from, to, edge) in self.get_all_edges() {
+            petgraph.add_edge(node_indices[&from], node_indices[&to], edge.clone());
+        }
+
+        petgraph
+    }
+
+    /// Creates a graph from a `petgraph::Graph` with the same directedness.
+    ///
+    /// The node weights become the vertices (identified by their own IDs) and the edge weights become the edges.
+    ///
+    /// # Errors
+    /// Returns the errors of building the graph, e.g. `GraphError::DuplicateEdge` for parallel edges
+    /// or `GraphError::DuplicateVertex` if two nodes have the same vertex ID.
+    pub fn from_petgraph<Ix: IndexType>(
+        petgraph: &petgraph::Graph<
+            Backend::Vertex,
+            Backend::Edge,
+            <Backend::Direction as PetgraphDirection>::EdgeType,
+            Ix,
+        >,
+    ) -> Result<Self, GraphError<<Backend::Vertex as WithID>::IDType>> {
+        let vertex_id = |index: NodeIndex<Ix>| petgraph[index].get_id();
+
+        let vertices = petgraph.node_weights().cloned().collect();
+        let edges = petgraph
+            .raw_edges()
+            .iter()
+            .map(|e| {
+                (
+                    vertex_id(e.source()),
+                    vertex_id(e.target()),
+                    e.weight.clone(),
+                )
+            })
+            .collect();
+
+        Graph::from_vertices_and_edges(vertices, edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        graph::{GraphBase, MatrixGraph},
+        test_utils::MockVertex,
+        Directed, ListGraph, Undirected,
+    };
+
+    #[test]
+    fn test_round_trip_directed() {
+        let graph = ListGraph::<MockVertex, i32, Directed>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1), (1, 0, 2), (2, 3, 3)],
+        )
+        .unwrap();
+
+        let petgraph = graph.to_petgraph();
+        assert!(petgraph.is_directed());
+        assert_eq!(petgraph.node_count(), 4);
+        assert_eq!(petgraph.edge_count(), 3);
+
+        let round_trip = ListGraph::<MockVertex, i32, Directed>::from_petgraph(&petgraph).unwrap();
+        assert_eq!(round_trip.vertex_count(), 4);
+        assert_eq!(round_trip.edge_count(), 3);
+        assert_eq!(round_trip.get_edge(1, 0), Some(&2));
+        assert_eq!(round_trip.get_edge(3, 2), None);
+    }
+
+    #[test]
+    fn test_round_trip_undirected() {
+        let graph = MatrixGraph::<MockVertex, i32, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![(0, 1, 1), (1, 2, 2), (3, 2, 3)],
+        )
+        .unwrap();
+
+        let petgraph = graph.to_petgraph();
+        assert!(!petgraph.is_directed());
+        assert_eq!(petgraph.node_count(), 4);
+        assert_eq!(petgraph.edge_count(), 3);
+
+        let round_trip =
+            ListGraph::<MockVertex, i32, Undirected>::from_petgraph(&petgraph).unwrap();
+        assert_eq!(round_trip.vertex_count(), 4);
+        assert_eq!(round_trip.edge_count(), 3);
+        assert_eq!(round_trip.get_edge(2, 3), Some(&3));
+    }
+}
@@ -0,0 +1,245 @@
+use std::hash::Hash;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    graph::{Direction, Graph, GraphBase, WithID},
+    GraphError,
+};
+
+impl<Backend> Graph<Backend>
+where
+    Backend: GraphBase,
+    Backend::Vertex: Clone,
+    Backend::Edge: Clone,
+    <Backend::Vertex as WithID>::IDType: Copy + Ord + Hash,
+{
+    /// Returns a graph over the shared vertex set containing every edge of `self` or `other`.
+    ///
+    /// Edges present in both graphs keep the edge data of `self`.
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: if the graphs have different vertex sets.
+    pub fn edge_union<OutputBackend>(
+        &self,
+        other: &Graph<Backend>,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        self.combine_edges(other, |in_self, in_other| in_self || in_other)
+    }
+
+    /// Returns a graph over the shared vertex set containing the edges present in both `self` and `other`.
+    ///
+    /// The edges keep the edge data of `self`.
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: if the graphs have different vertex sets.
+    pub fn edge_intersection<OutputBackend>(
+        &self,
+        other: &Graph<Backend>,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        self.combine_edges(other, |in_self, in_other| in_self && in_other)
+    }
+
+    /// Returns a graph over the shared vertex set containing the edges present in exactly one of `self` and `other`.
+    ///
+    /// Useful to visualize where two solutions (e.g. two spanning trees) differ.
+    ///
+    /// # Errors
+    /// - `GraphError::OperationFailed`: if the graphs have different vertex sets.
+    pub fn edge_symmetric_difference<OutputBackend>(
+        &self,
+        other: &Graph<Backend>,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        self.combine_edges(other, |in_self, in_other| in_self != in_other)
+    }
+
+    /// Builds a graph with the vertices of `self` and every edge for which `keep(in_self, in_other)` holds
+    fn combine_edges<OutputBackend>(
+        &self,
+        other: &Graph<Backend>,
+        keep: impl Fn(bool, bool) -> bool,
+    ) -> Result<Graph<OutputBackend>, GraphError<<Backend::Vertex as WithID>::IDType>>
+    where
+        OutputBackend: GraphBase<
+            Vertex = Backend::Vertex,
+            Edge = Backend::Edge,
+            Direction = Backend::Direction,
+        >,
+    {
+        let own_vertices = self
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<FxHashSet<_>>();
+        let other_vertices = other
+            .get_all_vertices()
+            .map(|v| v.get_id())
+            .collect::<FxHashSet<_>>();
+        if own_vertices != other_vertices {
+            return Err(GraphError::OperationFailed(
+                "Edge set operations require graphs with the same vertex set".to_string(),
+            ));
+        }
+
+        let (own_edges, other_edges) = (self.keyed_edges(), other.keyed_edges());
+
+        let mut edges = own_edges
+            .iter()
+            .filter(|(key, _)| keep(true, other_edges.contains_key(*key)))
+            .chain(
+                other_edges
+                    .iter()
+                    .filter(|(key, _)| !own_edges.contains_key(*key) && keep(false, true)),
+            )
+            .map(|(&(from, to), edge)| (from, to, (*edge).clone()))
+            .collect::<Vec<_>>();
+        edges.sort_unstable_by_key(|(from, to, _)| (*from, *to));
+
+        let mut vertices = self.get_all_vertices().cloned().collect::<Vec<_>>();
+        vertices.sort_unstable_by_key(|v| v.get_id());
+
+        Graph::from_vertices_and_edges(vertices, edges)
+    }
+
+    /// Maps every edge to its key, undirected edges are keyed as `(smaller ID, larger ID)`
+    #[allow(clippy::type_complexity)]
+    fn keyed_edges(
+        &self,
+    ) -> FxHashMap<
+        (
+            <Backend::Vertex as WithID>::IDType,
+            <Backend::Vertex as WithID>::IDType,
+        ),
+        &Backend::Edge,
+    > {
+        self.get_all_edges()
+            .map(|(from, to, edge)| {
+                let key = if Backend::Direction::IS_DIRECTED {
+                    (from, to)
+                } else {
+                    (from.min(to), from.max(to))
+                };
+                (key, edge)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashSet;
+
+    use crate::{
+        graph::GraphBase, test_utils::MockVertex, Directed, GraphError, ListGraph, Undirected,
+    };
+
+    fn edge_set(graph: &ListGraph<MockVertex, f64, Undirected>) -> FxHashSet<(usize, usize)> {
+        graph
+            .get_all_edges()
+            .map(|(from, to, _)| (from.min(to), from.max(to)))
+            .collect()
+    }
+
+    #[test]
+    fn test_symmetric_difference_of_msts_shows_swapped_edges() {
+        // A square 0-1-2-3 with the diagonal 0-2
+        let mut graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![
+                (0, 1, 1.0),
+                (1, 2, 2.0),
+                (2, 3, 1.0),
+                (3, 0, 4.0),
+                (0, 2, 3.0),
+            ],
+        )
+        .unwrap();
+        let mst: ListGraph<_, _, Undirected> = graph.mst_kruskal().unwrap();
+
+        // Making 1-2 more expensive swaps it for the diagonal
+        *graph.get_edge_mut(1, 2).unwrap() = 5.0;
+        let other_mst: ListGraph<_, _, Undirected> = graph.mst_kruskal().unwrap();
+
+        let difference: ListGraph<_, _, Undirected> =
+            mst.edge_symmetric_difference(&other_mst).unwrap();
+        let intersection: ListGraph<_, _, Undirected> = mst.edge_intersection(&other_mst).unwrap();
+        let union: ListGraph<_, _, Undirected> = mst.edge_union(&other_mst).unwrap();
+
+        assert_eq!(difference.vertex_count(), 4);
+        assert_eq!(
+            edge_set(&difference),
+            FxHashSet::from_iter([(1, 2), (0, 2)])
+        );
+        assert_eq!(
+            edge_set(&intersection),
+            FxHashSet::from_iter([(0, 1), (2, 3)])
+        );
+        assert_eq!(
+            edge_set(&union),
+            FxHashSet::from_iter([(0, 1), (1, 2), (2, 3), (0, 2)])
+        );
+        // Edges keep the data of the graph they come from
+        assert_eq!(union.get_edge(1, 2), Some(&2.0));
+        assert_eq!(union.get_edge(0, 2), Some(&3.0));
+    }
+
+    #[test]
+    fn test_directed_edge_set_operations() {
+        let vertices = (0..3).map(|id| MockVertex { id }).collect::<Vec<_>>();
+        let graph = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            vertices.clone(),
+            vec![(0, 1, 1.0), (1, 2, 1.0)],
+        )
+        .unwrap();
+        let other = ListGraph::<MockVertex, f64, Directed>::from_vertices_and_edges(
+            vertices,
+            vec![(1, 0, 1.0), (1, 2, 1.0)],
+        )
+        .unwrap();
+
+        let difference: ListGraph<_, _, Directed> =
+            graph.edge_symmetric_difference(&other).unwrap();
+
+        assert_eq!(difference.edge_count(), 2);
+        assert!(difference.get_edge(0, 1).is_some());
+        assert!(difference.get_edge(1, 0).is_some());
+    }
+
+    #[test]
+    fn test_edge_set_operations_require_same_vertices() {
+        let graph = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..3).map(|id| MockVertex { id }).collect(),
+            vec![],
+        )
+        .unwrap();
+        let other = ListGraph::<MockVertex, f64, Undirected>::from_vertices_and_edges(
+            (0..4).map(|id| MockVertex { id }).collect(),
+            vec![],
+        )
+        .unwrap();
+
+        let result: Result<ListGraph<_, _, Undirected>, _> = graph.edge_union(&other);
+
+        assert!(matches!(result, Err(GraphError::OperationFailed(_))));
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    cmp::Ordering,
     iter::Sum,
     ops::{Add, AddAssign, Div},
 };
@@ -23,6 +24,101 @@ pub trait WeightedEdge {
     fn get_weight(&self) -> Self::WeightType;
 }
 
+/// Implements [`WeightedEdge`] for newtypes wrapping a single weight, e.g. `struct Edge(f64)`.
+///
+/// The weight is read from the field `.0`, so the weight type must be `Copy`.
+///
+/// ```rust
+/// use graph_library::{graph::WeightedEdge, impl_weighted_edge};
+///
+/// #[derive(Clone)]
+/// struct Distance(f64);
+/// #[derive(Clone)]
+/// struct Capacity(u32);
+///
+/// impl_weighted_edge!(Distance => f64, Capacity => u32);
+///
+/// assert_eq!(Distance(2.5).get_weight(), 2.5);
+/// assert_eq!(Capacity(3).get_weight(), 3);
+/// ```
+#[macro_export]
+macro_rules! impl_weighted_edge {
+    ($($edge:ty => $weight:ty),+ $(,)?) => {
+        $(
+            impl $crate::graph::WeightedEdge for $edge {
+                type WeightType = $weight;
+
+                fn get_weight(&self) -> Self::WeightType {
+                    self.0
+                }
+            }
+        )+
+    };
+}
+
+/// Bare numbers can be used as edges directly, the edge is its own weight.
+macro_rules! impl_weighted_edge_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl WeightedEdge for $t {
+                type WeightType = $t;
+
+                fn get_weight(&self) -> Self::WeightType {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+// `i8` is missing, as it does not implement `From<u8>`
+impl_weighted_edge_primitive!(f32, f64, u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Weight types that can be ordered inside the priority queues of Prim's and Dijkstra's algorithm.
+///
+/// Floats are compared via `partial_cmp` and panic on NaN values, integers use their total order.
+/// This trait is sealed and implemented for all primitive number types, so the algorithms requiring it
+/// only accept edges whose [`WeightedEdge::WeightType`] is a primitive number.
+pub trait HeapWeight: sealed::Sealed {
+    fn heap_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_heap_weight_float {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl HeapWeight for $t {
+                fn heap_cmp(&self, other: &Self) -> Ordering {
+                    self.partial_cmp(other)
+                        .expect("Graph weights must not contain NaN values")
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_heap_weight_int {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl HeapWeight for $t {
+                fn heap_cmp(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+            }
+        )*
+    };
+}
+
+impl_heap_weight_float!(f32, f64);
+impl_heap_weight_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 pub type EdgeTuple<VId, Edge> = (VId, VId, Edge);
 pub trait GraphBase: Default {
     type Vertex: WithID;
@@ -69,6 +165,56 @@ pub trait GraphBase: Default {
         edge: Self::Edge,
     ) -> Result<(), GraphError<<Self::Vertex as WithID>::IDType>>;
 
+    /// Removes a vertex and all edges incident to it.
+    ///
+    /// Returns the removed vertex data.
+    ///
+    /// # Errors
+    /// - `GraphError::VertexNotFound`: when the vertex does not exist
+    /// - `GraphError::OperationFailed`: when the backend cannot remove the vertex
+    ///   (the adjacency matrix can only remove the vertex with the highest ID, as IDs must stay sequential)
+    fn remove_vertex(
+        &mut self,
+        vertex_id: <Self::Vertex as WithID>::IDType,
+    ) -> Result<Self::Vertex, GraphError<<Self::Vertex as WithID>::IDType>>;
+
+    /// Returns whether all given vertices can be removed with [`remove_vertex`](GraphBase::remove_vertex),
+    /// when they are removed from the highest to the lowest ID.
+    ///
+    /// Allows checking a batch removal up front, instead of failing after some of the vertices were removed.
+    /// By default, this only checks that all vertices exist.
+    fn can_remove_vertices(&self, vertex_ids: &[<Self::Vertex as WithID>::IDType]) -> bool
+    where
+        <Self::Vertex as WithID>::IDType: Clone,
+    {
+        vertex_ids
+            .iter()
+            .all(|vid| self.get_vertex_by_id(vid.clone()).is_some())
+    }
+
+    /// Removes the edge between two vertices.
+    /// In undirected graphs, the edge is removed in both directions.
+    ///
+    /// Returns the removed edge data or None if the edge does not exist.
+    fn remove_edge(
+        &mut self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> Option<Self::Edge>;
+
+    /// Removes all edges, but keeps the vertices.
+    ///
+    /// By default, the edges are removed one by one. The backends override this to clear their storage at once.
+    fn clear_edges(&mut self) {
+        let edges = self
+            .get_all_edges()
+            .map(|(from, to, _)| (from, to))
+            .collect::<Vec<_>>();
+        for (from, to) in edges {
+            self.remove_edge(from, to);
+        }
+    }
+
     // --- Graph queries ---
 
     /// Returns whether the graph is a directed (true) or undirected (false) graph.
@@ -100,6 +246,18 @@ pub trait GraphBase: Default {
         to_id: <Self::Vertex as WithID>::IDType,
     ) -> Option<&Self::Edge>;
 
+    /// Returns whether there is an edge between two vertices.
+    ///
+    /// Backends that can answer this without materializing a reference to the edge data (like the adjacency matrix)
+    /// override this, so it is preferable to `get_edge(..).is_some()` in hot loops.
+    fn has_edge(
+        &self,
+        from_id: <Self::Vertex as WithID>::IDType,
+        to_id: <Self::Vertex as WithID>::IDType,
+    ) -> bool {
+        self.get_edge(from_id, to_id).is_some()
+    }
+
     /// Gets a mutable reference to the edge data between two vertices
     fn get_edge_mut(
         &mut self,
@@ -112,6 +270,13 @@ pub trait GraphBase: Default {
     where
         Self::Vertex: 'a;
 
+    /// Get all vertices in the graph as an iterator of mutable references, e.g. for bulk updates of vertex data.
+    ///
+    /// The IDs of the vertices must not be changed.
+    fn get_all_vertices_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut Self::Vertex>
+    where
+        Self::Vertex: 'a;
+
     /// Get all edges in the graph as an iterator.
     fn get_all_edges<'a>(
         &'a self,
@@ -155,6 +320,38 @@ pub trait GraphBase: Default {
         Self::Vertex: 'a,
         Self::Edge: 'a;
 
+    /// Returns the number of edges leaving the given vertex, or None if the vertex does not exist.
+    ///
+    /// In undirected graphs, this is the number of edges incident to the vertex.
+    /// By default, the adjacent vertices are counted.
+    fn out_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize>
+    where
+        <Self::Vertex as WithID>::IDType: Clone,
+    {
+        self.get_vertex_by_id(vertex_id.clone())?;
+        Some(self.get_adjacent_vertices(vertex_id).count())
+    }
+
+    /// Returns the number of edges pointing to the given vertex, or None if the vertex does not exist.
+    ///
+    /// In undirected graphs, this equals the [`out_degree`](GraphBase::out_degree).
+    /// By default, all edges are scanned (in both directions for undirected graphs),
+    /// the adjacency list does the same for directed graphs.
+    fn in_degree(&self, vertex_id: <Self::Vertex as WithID>::IDType) -> Option<usize>
+    where
+        <Self::Vertex as WithID>::IDType: Clone + PartialEq,
+    {
+        self.get_vertex_by_id(vertex_id.clone())?;
+        if !Self::Direction::IS_DIRECTED {
+            return self.out_degree(vertex_id);
+        }
+        Some(
+            self.get_all_edges()
+                .filter(|(_, to, _)| *to == vertex_id)
+                .count(),
+        )
+    }
+
     /// Returns the number of vertices in the graph.
     fn vertex_count(&self) -> usize;
 
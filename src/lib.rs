@@ -72,6 +72,8 @@
 
 pub mod algorithms;
 pub mod graph;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 // Re-export main types and traits
 pub use crate::graph::error::GraphError;
@@ -0,0 +1,45 @@
+use crate::{
+    graph::{GraphBase, WithID},
+    Graph,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MockVertex {
+    pub(crate) id: usize,
+}
+
+impl WithID for MockVertex {
+    type IDType = usize;
+
+    fn get_id(&self) -> usize {
+        self.id
+    }
+}
+
+/// Builds a graph with the vertices `0..n_vertices` and the given edges without edge data
+pub(crate) fn build_graph<Backend>(n_vertices: usize, edges: &[(usize, usize)]) -> Graph<Backend>
+where
+    Backend: GraphBase<Vertex = MockVertex, Edge = ()>,
+{
+    build_graph_with_edge(n_vertices, edges, ())
+}
+
+/// Builds a graph with the vertices `0..n_vertices` and the given edges, which all carry a copy of `edge`
+pub(crate) fn build_graph_with_edge<Backend>(
+    n_vertices: usize,
+    edges: &[(usize, usize)],
+    edge: Backend::Edge,
+) -> Graph<Backend>
+where
+    Backend: GraphBase<Vertex = MockVertex>,
+    Backend::Edge: Clone,
+{
+    Graph::from_vertices_and_edges(
+        (0..n_vertices).map(|id| MockVertex { id }).collect(),
+        edges
+            .iter()
+            .map(|(from, to)| (*from, *to, edge.clone()))
+            .collect(),
+    )
+    .unwrap()
+}
@@ -1,5 +1,5 @@
 use graph_library::algorithms::iter::TraversalType;
-use graph_library::graph::EdgeWithWeight;
+use graph_library::graph::{EdgeWithWeight, GraphBase};
 use graph_library::{Directed, ListGraph, Undirected};
 use rstest::rstest;
 
@@ -33,6 +33,25 @@ fn count_connected_subgraphs(
     );
 }
 
+#[rstest]
+#[case("resources/test_graphs/undirected/Graph1.txt", 2)]
+#[case("resources/test_graphs/undirected/Graph2.txt", 4)]
+#[case("resources/test_graphs/undirected/Graph3.txt", 4)]
+#[case("resources/test_graphs/undirected/Graph_gross.txt", 222)]
+#[case("resources/test_graphs/undirected/Graph_ganzgross.txt", 9560)]
+#[case("resources/test_graphs/undirected/Graph_ganzganzgross.txt", 306)]
+fn count_connected_components_union_find(#[case] input_path: &str, #[case] expected_count: u32) {
+    let graph = ListGraph::<_, _, Undirected>::from_hoever_file_default(input_path)
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    assert_eq!(
+        graph.count_connected_components_uf(),
+        expected_count,
+        "Wrong component count for graph {}",
+        input_path
+    );
+}
+
 #[rstest]
 #[case("resources/test_graphs/undirected_weighted/G_1_2.txt", 1)]
 #[case("resources/test_graphs/undirected_weighted/G_1_20.txt", 1)]
@@ -69,3 +88,31 @@ fn count_connected_subgraphs_p02_undirected_weights(
         input_path, traversal_type, expected_count, count
     );
 }
+
+#[rstest]
+#[case("resources/test_graphs/undirected/Graph1.txt")]
+#[case("resources/test_graphs/undirected/Graph2.txt")]
+#[case("resources/test_graphs/undirected/Graph3.txt")]
+#[case("resources/test_graphs/undirected/Graph_gross.txt")]
+#[case("resources/test_graphs/undirected/Graph_ganzgross.txt")]
+#[case("resources/test_graphs/undirected/Graph_ganzganzgross.txt")]
+fn analyze_components_matches_count(#[case] input_path: &str) {
+    let graph = ListGraph::<_, _, Undirected>::from_hoever_file_default(input_path)
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let analysis = graph.analyze_components();
+    let count = graph
+        .count_connected_subgraphs_with_default_traversal()
+        .unwrap_or_else(|e| panic!("Failed to count connected subgraphs: {:?}", e));
+
+    assert_eq!(analysis.count(), count as usize);
+    assert_eq!(
+        (0..analysis.count())
+            .map(|idx| analysis.members(idx).len())
+            .sum::<usize>(),
+        graph.vertex_count()
+    );
+    for (from, to, _) in graph.get_all_edges() {
+        assert_eq!(analysis.component_of(from), analysis.component_of(to));
+    }
+}
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use graph_library::graph::GraphBase;
 use graph_library::graph::ListGraphBackend;
 use graph_library::Directed;
+use graph_library::GraphError;
 use graph_library::ListGraph;
 use rstest::rstest;
 
@@ -67,3 +70,119 @@ fn finds_max_flow(
         outgoing_flow
     );
 }
+
+#[rstest]
+fn same_start_and_target_keeps_flows() {
+    let mut graph = ListGraph::<_, _, Directed>::from_hoever_file_with_weights(
+        "resources/test_graphs/directed_flow/Fluss1.txt",
+        |remaining| FlowEdge {
+            max_flow: remaining[0]
+                .parse()
+                .expect("Graph file value must be a float"),
+            flow: f64::default(),
+        },
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+    // Non-zero flows, so that resetting them would be noticed
+    graph
+        .get_all_edges_mut()
+        .for_each(|(_, _, edge)| edge.flow = edge.max_flow / 2.0);
+
+    graph
+        .edmonds_karp::<ListGraphBackend<_, _, Directed>, _, _, _>(
+            3,
+            3,
+            |e| &mut e.flow,
+            |e| &e.max_flow,
+        )
+        .expect("Error running algorithm");
+
+    assert!(graph
+        .get_all_edges()
+        .all(|(_, _, edge)| edge.flow == edge.max_flow / 2.0 && edge.flow != 0.0));
+    assert!(matches!(
+        graph.edmonds_karp::<ListGraphBackend<_, _, Directed>, _, _, _>(
+            42,
+            42,
+            |e| &mut e.flow,
+            |e| &e.max_flow,
+        ),
+        Err(GraphError::VertexNotFound(42))
+    ));
+}
+
+#[rstest]
+fn same_start_and_target_returns_zero_flows() {
+    let graph = ListGraph::<_, _, Directed>::from_hoever_file_with_weights(
+        "resources/test_graphs/directed_flow/Fluss1.txt",
+        |remaining| {
+            remaining[0]
+                .parse::<f64>()
+                .expect("Graph file value must be a float")
+        },
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let flows = graph
+        .edmonds_karp_with_flows::<ListGraphBackend<_, _, Directed>, _, _>(3, 3, |e| e)
+        .expect("Error running algorithm");
+
+    assert_eq!(flows.len(), graph.edge_count());
+    assert!(flows.values().all(|flow| *flow == 0.0));
+    assert!(matches!(
+        graph.edmonds_karp_with_flows::<ListGraphBackend<_, _, Directed>, _, _>(42, 42, |e| e),
+        Err(GraphError::VertexNotFound(42))
+    ));
+}
+
+#[rstest]
+#[case("resources/test_graphs/directed_flow/Fluss1.txt", 0, 7, 4.0)]
+#[case("resources/test_graphs/directed_flow/Fluss2.txt", 0, 7, 5.0)]
+fn returns_flows_satisfying_conservation(
+    #[case] input_path: &str,
+    #[case] start: u32,
+    #[case] target: u32,
+    #[case] expected_max_flow: f64,
+) {
+    let graph =
+        ListGraph::<_, _, Directed>::from_hoever_file_with_weights(input_path, |remaining| {
+            remaining[0]
+                .parse::<f64>()
+                .expect("Graph file value must be a float")
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let flows = graph
+        .edmonds_karp_with_flows::<ListGraphBackend<_, _, Directed>, _, _>(start, target, |e| e)
+        .expect("Error running algorithm");
+
+    assert_eq!(flows.len(), graph.edge_count());
+    for ((from, to), flow) in &flows {
+        let capacity = *graph.get_edge(*from, *to).unwrap();
+        assert!(*flow >= -1e-9 && *flow <= capacity + 1e-9);
+    }
+
+    // Incoming minus outgoing flow of every vertex
+    let mut balance = HashMap::<u32, f64>::new();
+    for ((from, to), flow) in &flows {
+        *balance.entry(*from).or_default() -= flow;
+        *balance.entry(*to).or_default() += flow;
+    }
+
+    for (vertex, net_flow) in &balance {
+        let expected = if *vertex == start {
+            -expected_max_flow
+        } else if *vertex == target {
+            expected_max_flow
+        } else {
+            0.0
+        };
+        assert!(
+            (net_flow - expected).abs() < 1e-5,
+            "Vertex {} has a net flow of {}, expected {}",
+            vertex,
+            net_flow,
+            expected
+        );
+    }
+}
@@ -1,9 +1,10 @@
-use graph_library::graph::{WeightedEdge, WithID};
+use graph_library::{graph::WithID, impl_weighted_edge};
 
 pub mod count_connected_subgraphs;
 pub mod maximum_flow;
 pub mod mst;
 pub mod shortest_path;
+pub mod stats;
 pub mod tsp;
 
 /// Vertex representation for testing, implements the required traits
@@ -22,10 +23,4 @@ impl WithID for TestVertex {
 #[derive(Debug, Clone, PartialEq)]
 pub struct TestEdge(pub f64);
 
-impl WeightedEdge for TestEdge {
-    type WeightType = f64;
-
-    fn get_weight(&self) -> Self::WeightType {
-        self.0
-    }
-}
+impl_weighted_edge!(TestEdge => f64);
@@ -50,3 +50,98 @@ fn mst(
         total_weight
     );
 }
+
+#[test]
+fn mst_prim_integer_weights() {
+    use graph_library::{
+        graph::{GraphBase, WithID},
+        ListGraph, Undirected,
+    };
+
+    #[derive(Debug, Clone)]
+    struct IntVertex(usize);
+
+    impl WithID for IntVertex {
+        type IDType = usize;
+
+        fn get_id(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct IntEdge(u32);
+
+    graph_library::impl_weighted_edge!(IntEdge => u32);
+
+    let graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+        (0..5).map(IntVertex).collect(),
+        vec![
+            (0, 1, IntEdge(4)),
+            (0, 2, IntEdge(1)),
+            (1, 2, IntEdge(2)),
+            (1, 3, IntEdge(5)),
+            (2, 3, IntEdge(8)),
+            (3, 4, IntEdge(3)),
+            (2, 4, IntEdge(9)),
+        ],
+    )
+    .unwrap();
+
+    let mst = graph
+        .mst_prim::<ListGraphBackend<_, _, Undirected>>(Some(0))
+        .unwrap();
+
+    assert_eq!(mst.vertex_count(), 5);
+    assert_eq!(mst.edge_count(), 4);
+    assert_eq!(mst.get_total_weight(), 1 + 2 + 5 + 3);
+}
+
+#[test]
+fn mst_add_edge_matches_recomputed_mst() {
+    use graph_library::{graph::GraphBase, ListGraph, Undirected};
+
+    use super::{TestEdge, TestVertex};
+
+    // Two components: a path 0-1-2-3 and the edge 4-5
+    let mut graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+        (0..6).map(TestVertex).collect(),
+        vec![
+            (0, 1, TestEdge(4.0)),
+            (1, 2, TestEdge(7.0)),
+            (2, 3, TestEdge(2.0)),
+            (4, 5, TestEdge(3.0)),
+        ],
+    )
+    .unwrap();
+    let mut mst = graph
+        .mst_kruskal::<ListGraphBackend<_, _, Undirected>>()
+        .unwrap();
+
+    let insertions = [
+        (0, 2, 5.0, true),  // replaces 1-2 (7.0)
+        (0, 3, 9.0, false), // heavier than every edge on the cycle
+        (3, 4, 6.0, true),  // connects both components
+        (1, 5, 1.0, true),  // replaces 3-4 (6.0)
+        (3, 5, 0.5, true),  // replaces 0-2 (5.0)
+    ];
+
+    for (from, to, weight, expect_change) in insertions {
+        graph.push_edge(from, to, TestEdge(weight)).unwrap();
+        let changed = graph
+            .mst_add_edge(&mut mst, from, to, TestEdge(weight))
+            .unwrap();
+        assert_eq!(changed, expect_change, "Inserting edge {from}-{to}");
+
+        let expected = graph
+            .mst_kruskal::<ListGraphBackend<_, _, Undirected>>()
+            .unwrap();
+        assert_eq!(mst.edge_count(), expected.edge_count());
+        assert!(
+            (mst.get_total_weight() - expected.get_total_weight()).abs() < 1e-9,
+            "After inserting {from}-{to}, expected MST weight {}, but got {}",
+            expected.get_total_weight(),
+            mst.get_total_weight()
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use graph_library::{
-    graph::{EdgeWithWeight, IntoDirected},
+    graph::{EdgeWithWeight, GraphBase, IntoDirected},
     ListGraph,
 };
 use graph_library::{Directed, Undirected};
@@ -198,3 +198,352 @@ fn directed_negative_weights(
         }
     }
 }
+
+#[rstest]
+#[case("resources/test_graphs/undirected_weighted/G_1_2.txt", 4)]
+#[case("resources/test_graphs/undirected_weighted/G_10_20.txt", 8)]
+fn alt_matches_dijkstra(#[case] input_path: &str, #[case] n_landmarks: usize) {
+    let graph =
+        ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(input_path, |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let alt_index = graph.alt_index(n_landmarks);
+    assert_eq!(alt_index.landmarks().len(), n_landmarks);
+
+    for start in [0, 7, 99] {
+        let dijkstra = graph.dijkstra(start, None);
+        for goal in (0..graph.vertex_count() as u32).step_by(13) {
+            let alt_cost = alt_index.shortest_path(start, goal).map(|(cost, _)| cost);
+            match (dijkstra.get_cost(goal), alt_cost) {
+                (Some(expected), Some(actual)) => assert!(
+                    (expected - actual).abs() < 1e-9,
+                    "For graph {}, expected shortest path from {} to {} to be {}, but got {}",
+                    input_path,
+                    start,
+                    goal,
+                    expected,
+                    actual
+                ),
+                (expected, actual) => assert_eq!(expected, actual),
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case("resources/test_graphs/directed_weighted/Wege1.txt", 2, 0)]
+#[case("resources/test_graphs/undirected_weighted/G_1_2.txt", 0, 1)]
+fn shortest_path_edges_connect_start_to_target(
+    #[case] input_path: &str,
+    #[case] from: u32,
+    #[case] to: u32,
+) {
+    use graph_library::graph::WeightedEdge;
+
+    let graph =
+        ListGraph::<_, _, Directed>::from_hoever_file_with_weights(input_path, |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let shortest_path = graph.dijkstra(from, None);
+    let edges = shortest_path.get_path_edges(&graph, to);
+
+    assert!(!edges.is_empty());
+    assert_eq!(edges.first().unwrap().0, from);
+    assert_eq!(edges.last().unwrap().1, to);
+    for pair in edges.windows(2) {
+        assert_eq!(pair[0].1, pair[1].0);
+    }
+
+    let total_weight: f64 = edges.iter().map(|(_, _, e)| e.get_weight()).sum();
+    assert!((total_weight - shortest_path.get_cost(to).unwrap()).abs() < 1e-9);
+}
+
+#[rstest]
+#[case("resources/test_graphs/undirected_weighted/G_1_20.txt", 0, vec![1, 17, 42, 500])]
+#[case("resources/test_graphs/undirected_weighted/G_1_20.txt", 3, vec![3])]
+fn dijkstra_multi_target_matches_full_dijkstra(
+    #[case] input_path: &str,
+    #[case] start: u32,
+    #[case] targets: Vec<u32>,
+) {
+    let graph =
+        ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(input_path, |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let full = graph.dijkstra(start, None);
+    let multi_target = graph.dijkstra_multi_target(start, &targets);
+
+    for target in targets {
+        assert_eq!(
+            multi_target.get_cost(target),
+            full.get_cost(target),
+            "For graph {}, expected the same cost from {} to {}",
+            input_path,
+            start,
+            target
+        );
+        assert_eq!(multi_target.get_path(target).first(), Some(&start));
+        assert_eq!(multi_target.get_path(target).last(), Some(&target));
+    }
+}
+
+#[test]
+fn nearest_of_returns_closest_candidate_by_cost() {
+    use graph_library::graph::WithID;
+
+    #[derive(Debug, Clone)]
+    struct IdVertex(u32);
+
+    impl WithID for IdVertex {
+        type IDType = u32;
+
+        fn get_id(&self) -> u32 {
+            self.0
+        }
+    }
+
+    // Vertex 1 has the lowest ID, but is only reachable through an expensive edge
+    let graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+        (0..5).map(IdVertex).collect(),
+        vec![
+            (0, 1, EdgeWithWeight::new(10.0)),
+            (0, 2, EdgeWithWeight::new(1.0)),
+            (2, 3, EdgeWithWeight::new(2.0)),
+            (3, 4, EdgeWithWeight::new(1.0)),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(graph.nearest_of(0, &[1, 4]), Some((4, 4.0)));
+    assert_eq!(graph.nearest_of(0, &[1, 3, 4]), Some((3, 3.0)));
+    assert_eq!(graph.nearest_of(4, &[4, 0]), Some((4, 0.0)));
+    assert_eq!(graph.nearest_of(0, &[]), None);
+}
+
+#[test]
+fn dijkstra_with_bare_float_edges() {
+    use super::TestVertex;
+
+    // The edge type is `f64` itself, no wrapper type is needed
+    let graph = ListGraph::<TestVertex, f64, Directed>::from_vertices_and_edges(
+        (0..4).map(TestVertex).collect(),
+        vec![(0, 1, 1.5), (1, 2, 2.0), (0, 2, 4.0), (2, 3, 0.5)],
+    )
+    .unwrap();
+
+    let shortest_paths = graph.dijkstra(0, None);
+
+    assert_eq!(shortest_paths.get_cost(2), Some(3.5));
+    assert_eq!(shortest_paths.get_cost(3), Some(4.0));
+    assert_eq!(shortest_paths.get_path(3), vec![0, 1, 2, 3]);
+}
+
+#[rstest]
+#[case("resources/test_graphs/undirected_weighted/G_1_2.txt", 0)]
+#[case("resources/test_graphs/undirected_weighted/G_1_2.txt", 7)]
+fn bellman_ford_undirected_matches_dijkstra(#[case] input_path: &str, #[case] from: u32) {
+    let graph =
+        ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(input_path, |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let bellman_ford = graph
+        .bellman_ford_undirected(from)
+        .into_spt()
+        .expect("A graph with positive weights has no negative cycle");
+    let dijkstra = graph.dijkstra(from, None);
+
+    for v in graph.get_all_vertices() {
+        let (bf_cost, dijkstra_cost) = (bellman_ford.get_cost(v.id), dijkstra.get_cost(v.id));
+        assert!(
+            (bf_cost.unwrap() - dijkstra_cost.unwrap()).abs() < 1e-9,
+            "Cost from {} to {} differs: {:?} vs {:?}",
+            from,
+            v.id,
+            bf_cost,
+            dijkstra_cost
+        );
+    }
+}
+
+#[test]
+fn bellman_ford_undirected_flags_negative_edge() {
+    use super::TestVertex;
+
+    let graph = ListGraph::<TestVertex, f64, Undirected>::from_vertices_and_edges(
+        (0..5).map(TestVertex).collect(),
+        vec![(0, 1, 2.0), (1, 2, -1.0), (2, 3, 1.0)],
+    )
+    .unwrap();
+
+    let mut negative_cycle = graph
+        .bellman_ford_undirected(0)
+        .into_negative_cycle()
+        .expect("A negative undirected edge forms a negative cycle");
+    negative_cycle.sort_unstable();
+    assert_eq!(negative_cycle, vec![1, 2]);
+
+    // The negative edge cannot be reached from the isolated vertex 4
+    let shortest_paths = graph
+        .bellman_ford_undirected(4)
+        .into_spt()
+        .expect("No negative edge is reachable");
+    assert_eq!(shortest_paths.get_cost(4), Some(0.0));
+    assert_eq!(shortest_paths.get_cost(0), None);
+}
+
+#[test]
+fn dijkstra_with_edge_penalty_avoids_penalized_edge() {
+    use super::TestVertex;
+
+    // The shortest route 0 -> 1 -> 3 costs 2, the detour 0 -> 2 -> 3 costs 5
+    let graph = ListGraph::<TestVertex, f64, Directed>::from_vertices_and_edges(
+        (0..4).map(TestVertex).collect(),
+        vec![(0, 1, 1.0), (1, 3, 1.0), (0, 2, 2.0), (2, 3, 3.0)],
+    )
+    .unwrap();
+
+    let unpenalized = graph.dijkstra_with_edge_penalty(0, Some(3), |_, _, _| 0.0);
+    assert_eq!(
+        unpenalized.get_path(3),
+        graph.dijkstra(0, Some(3)).get_path(3)
+    );
+    assert_eq!(unpenalized.get_path(3), vec![0, 1, 3]);
+
+    let penalized = graph.dijkstra_with_edge_penalty(0, Some(3), |from, to, _| {
+        if (from, to) == (1, 3) {
+            100.0
+        } else {
+            0.0
+        }
+    });
+    assert_eq!(penalized.get_path(3), vec![0, 2, 3]);
+    assert_eq!(penalized.get_cost(3), Some(5.0));
+}
+
+#[rstest]
+#[case(
+    "resources/test_graphs/directed_weighted/Wege1.txt",
+    Algorithms::Dijkstra
+)]
+#[case(
+    "resources/test_graphs/directed_weighted/Wege1.txt",
+    Algorithms::BellmanFord
+)]
+#[case(
+    "resources/test_graphs/directed_weighted/Wege2.txt",
+    Algorithms::BellmanFord
+)]
+fn floyd_warshall_matches_single_source(#[case] input_path: &str, #[case] algorithm: Algorithms) {
+    let graph =
+        ListGraph::<_, _, Directed>::from_hoever_file_with_weights(input_path, |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let all_pairs = graph.floyd_warshall();
+
+    for start in 0..graph.vertex_count() as u32 {
+        let single_source = match algorithm {
+            Algorithms::Dijkstra => graph.dijkstra(start, None),
+            Algorithms::BellmanFord => graph
+                .bellman_ford(start)
+                .into_spt()
+                .unwrap_or_else(|_e| panic!("Algorithm did not return a valid result")),
+        };
+
+        for target in 0..graph.vertex_count() as u32 {
+            match (
+                all_pairs.get_cost(start, target),
+                single_source.get_cost(target),
+            ) {
+                (Some(expected), Some(actual)) => assert!(
+                    (expected - actual).abs() < 1e-9,
+                    "For graph {}, expected shortest path from {} to {} to be {}, but got {}",
+                    input_path,
+                    start,
+                    target,
+                    actual,
+                    expected
+                ),
+                (expected, actual) => assert_eq!(expected, actual),
+            }
+
+            let path = all_pairs.get_path(start, target);
+            if all_pairs.get_cost(start, target).is_some() {
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&target));
+            } else {
+                assert!(path.is_empty());
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case("resources/test_graphs/undirected_weighted/G_1_2.txt")]
+#[case("resources/test_graphs/undirected_weighted/G_1_20.txt")]
+fn contraction_hierarchies_match_dijkstra(#[case] input_path: &str) {
+    let graph =
+        ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(input_path, |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let hierarchy = graph.contraction_hierarchies();
+
+    for start in [0, 17, 500] {
+        let shortest_paths = graph.dijkstra(start, None);
+        for goal in (0..graph.vertex_count() as u32).step_by(7) {
+            match (
+                hierarchy.ch_query(start, goal),
+                shortest_paths.get_cost(goal),
+            ) {
+                (Some((cost, path)), Some(expected)) => {
+                    assert!(
+                        (cost - expected).abs() < 1e-9,
+                        "For graph {}, expected shortest path from {} to {} to be {}, but got {}",
+                        input_path,
+                        start,
+                        goal,
+                        expected,
+                        cost
+                    );
+                    assert!((graph.path_cost(&path).unwrap() - cost).abs() < 1e-9);
+                }
+                (result, expected) => assert_eq!(result.map(|(cost, _)| cost), expected),
+            }
+        }
+    }
+}
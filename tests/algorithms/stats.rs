@@ -0,0 +1,47 @@
+use graph_library::{Directed, ListGraph, Undirected};
+
+#[test]
+fn stats_of_graph1() {
+    let graph = ListGraph::<_, _, Undirected>::from_hoever_file_default(
+        "resources/test_graphs/undirected/Graph1.txt",
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let stats = graph.stats();
+
+    assert_eq!(stats.vertex_count, 15);
+    assert_eq!(stats.edge_count, 17);
+    assert_eq!(stats.component_count, 2);
+    assert_eq!(stats.min_degree, 1);
+    assert_eq!(stats.max_degree, 3);
+    assert!((stats.avg_degree - 34.0 / 15.0).abs() < 1e-9);
+    assert!((stats.density - 17.0 / 105.0).abs() < 1e-9);
+    assert_eq!(stats.is_forest, Some(false));
+    assert_eq!(stats.is_tree, Some(false));
+    assert_eq!(
+        stats.to_string(),
+        "Vertices:   15\n\
+         Edges:      17\n\
+         Density:    0.16190\n\
+         Degree:     min 1, max 3, avg 2.27\n\
+         Components: 2\n\
+         Forest:     false\n\
+         Tree:       false"
+    );
+}
+
+#[test]
+fn stats_of_directed_graph() {
+    let graph = ListGraph::<_, _, Directed>::from_hoever_file_default(
+        "resources/test_graphs/undirected/Graph1.txt",
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let stats = graph.stats();
+
+    assert_eq!(stats.edge_count, 17);
+    assert_eq!(stats.component_count, 2);
+    assert!((stats.density - 17.0 / 210.0).abs() < 1e-9);
+    assert_eq!(stats.is_forest, None);
+    assert_eq!(stats.is_tree, None);
+}
@@ -1,5 +1,5 @@
 use graph_library::graph::{GraphBase, MatrixGraph};
-use graph_library::Undirected;
+use graph_library::{ListGraph, Undirected};
 use itertools::Itertools;
 use rstest::rstest;
 
@@ -107,6 +107,40 @@ fn tsp_optimal_solution_branch_and_bound(
     );
 }
 
+#[rstest]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10.txt", 38.41)]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10e.txt", 27.26)]
+#[case("resources/test_graphs/complete_undirected_weighted/K_12.txt", 45.19)]
+#[case("resources/test_graphs/complete_undirected_weighted/K_12e.txt", 36.13)]
+#[case("resources/test_graphs/complete_undirected_weighted/K_15.txt", 53.21)]
+// The two-cheapest-edges bound ignores the edges back to the visited vertices and is not admissible here,
+// so `tsp_branch_and_bound` misses this optimum and returns 30.54
+#[case("resources/test_graphs/complete_undirected_weighted/K_15e.txt", 30.13)]
+fn tsp_optimal_solution_branch_and_bound_one_tree(
+    #[case] input_path: &str,
+    #[case] expected_optimal_cost: f64,
+) {
+    let graph = create_test_graph(input_path);
+
+    let optimal_path = graph
+        .tsp_branch_and_bound_one_tree(None)
+        .unwrap_or_else(|e| panic!("Could not compute tsp solution: {:?}", e));
+
+    assert_eq!(graph.vertex_count(), optimal_path.len());
+    assert_eq!(
+        optimal_path.edges().map(|(_, to, _)| to).unique().count(),
+        graph.vertex_count(),
+        "Path should visit each vertex exactly once"
+    );
+    assert!(
+        (optimal_path.total_cost() - expected_optimal_cost).abs() < 1e-2,
+        "For graph {}, expected optimal TSP cost to be {}, but got {}",
+        input_path,
+        expected_optimal_cost,
+        optimal_path.total_cost()
+    );
+}
+
 /// Enumeration of TSP algorithms for parametrized tests
 #[derive(Debug)]
 enum ApproximationTspAlgorithm {
@@ -196,3 +230,129 @@ fn tsp_finds_solution(
         )
     }
 }
+
+#[rstest]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_12e.txt")]
+fn tsp_default_start_vertex_is_deterministic(
+    #[case] input_path: &str,
+    #[values(
+        ApproximationTspAlgorithm::NearestNeighbor,
+        ApproximationTspAlgorithm::DoubleTree
+    )]
+    algorithm: ApproximationTspAlgorithm,
+) {
+    let graph =
+        ListGraph::<_, _, Undirected>::from_hoever_file(input_path, TestVertex, |remaining| {
+            TestEdge(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    // Rebuild the same graph with a different insertion order of the vertices
+    let mut vertices = graph.get_all_vertices().cloned().collect::<Vec<_>>();
+    vertices.reverse();
+    let edges = graph
+        .get_all_edges()
+        .map(|(from, to, edge)| (from, to, edge.clone()))
+        .collect();
+    let reordered_graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(vertices, edges)
+        .unwrap_or_else(|e| panic!("Graph could not be rebuilt: {:?}", e));
+
+    let (first_tour, second_tour) = match algorithm {
+        ApproximationTspAlgorithm::NearestNeighbor => (
+            graph.tsp_nearest_neighbor(None),
+            reordered_graph.tsp_nearest_neighbor(None),
+        ),
+        ApproximationTspAlgorithm::DoubleTree => (
+            graph.tsp_double_tree(None),
+            reordered_graph.tsp_double_tree(None),
+        ),
+    };
+    let first_tour =
+        first_tour.unwrap_or_else(|e| panic!("Could not compute tsp solution: {:?}", e));
+    let second_tour =
+        second_tour.unwrap_or_else(|e| panic!("Could not compute tsp solution: {:?}", e));
+
+    // The tour must start at the smallest vertex ID and be identical for both runs
+    assert_eq!(first_tour.nodes().first(), Some(&0));
+    assert_eq!(first_tour, second_tour);
+}
+
+#[test]
+fn tsp_nearest_neighbor_breaks_ties_by_id() {
+    // All edges weigh the same, so every step is a tie
+    let edges = (0..5)
+        .tuple_combinations()
+        .map(|(from, to)| (from, to, TestEdge(1.0)))
+        .collect::<Vec<_>>();
+    for vertices in [(0..5).collect::<Vec<_>>(), (0..5).rev().collect()] {
+        let graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+            vertices.into_iter().map(TestVertex).collect(),
+            edges.clone(),
+        )
+        .unwrap_or_else(|e| panic!("Graph could not be constructed: {:?}", e));
+
+        let tour = graph
+            .tsp_nearest_neighbor(None)
+            .unwrap_or_else(|e| panic!("Could not compute tsp solution: {:?}", e));
+
+        assert_eq!(tour.nodes(), vec![0, 1, 2, 3, 4, 0]);
+    }
+}
+
+#[rstest]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10e.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_12.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_12e.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_15.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_15e.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_20.txt")]
+fn tsp_double_tree_mst_bound(#[case] input_path: &str) {
+    let graph = create_test_graph(input_path);
+
+    let (tour, mst_weight) = graph
+        .tsp_double_tree_with_bound(None)
+        .unwrap_or_else(|e| panic!("Could not compute tsp solution: {:?}", e));
+    let total_cost = tour.total_cost();
+
+    assert_eq!(tour, graph.tsp_double_tree(None).unwrap());
+    assert!(
+        mst_weight > 0.0 && mst_weight <= total_cost,
+        "For graph {}, expected the MST weight {} to be a lower bound for the tour cost {}",
+        input_path,
+        mst_weight,
+        total_cost
+    );
+    assert!(total_cost / mst_weight <= 2.0 + 1e-9);
+}
+
+#[rstest]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_12e.txt")]
+fn path_cost_of_tour_sequence_matches_total_cost(#[case] input_path: &str) {
+    let graph = create_test_graph(input_path);
+
+    for tour in [
+        graph.tsp_nearest_neighbor(None).unwrap(),
+        graph.tsp_double_tree(None).unwrap(),
+    ] {
+        let sequence = tour.nodes();
+        assert_eq!(sequence.first(), sequence.last());
+
+        let cost = graph
+            .path_cost(&sequence)
+            .unwrap_or_else(|e| panic!("Could not compute the tour cost: {:?}", e));
+        assert!(
+            (cost - tour.total_cost()).abs() < 1e-9,
+            "For graph {}, the path cost {} does not match the tour cost {}",
+            input_path,
+            cost,
+            tour.total_cost()
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use graph_library::{
+    graph::{EdgeWithWeight, GraphBase, Vertex},
+    Directed, GraphError, ListGraph, Undirected,
+};
+
+#[test]
+fn binary_round_trip_preserves_structure() {
+    let graph = ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(
+        "resources/test_graphs/undirected_weighted/G_1_20.txt",
+        |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        },
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let mut bytes = Vec::new();
+    graph.write_binary(&mut bytes).unwrap();
+    let loaded =
+        ListGraph::<Vertex, EdgeWithWeight, Undirected>::read_binary(&mut bytes.as_slice())
+            .unwrap_or_else(|e| panic!("Snapshot could not be read: {:?}", e));
+
+    assert_eq!(loaded.vertex_count(), graph.vertex_count());
+    assert_eq!(loaded.edge_count(), graph.edge_count());
+    for (from, to, edge) in graph.get_all_edges() {
+        let loaded_edge = loaded
+            .get_edge(from, to)
+            .unwrap_or_else(|| panic!("Edge {from} - {to} is missing after loading"));
+        assert_eq!(loaded_edge.weight, edge.weight);
+    }
+}
+
+#[test]
+fn binary_snapshot_rejects_other_direction() {
+    let graph = ListGraph::<_, _, Directed>::from_hoever_file_default(
+        "resources/test_graphs/undirected/Graph1.txt",
+    )
+    .unwrap();
+
+    let mut bytes = Vec::new();
+    graph.write_binary(&mut bytes).unwrap();
+
+    assert!(matches!(
+        ListGraph::<Vertex, (), Undirected>::read_binary(&mut bytes.as_slice()),
+        Err(GraphError::InvalidFormat(_))
+    ));
+    // A truncated snapshot fails with an I/O error instead of panicking
+    assert!(matches!(
+        ListGraph::<Vertex, (), Directed>::read_binary(&mut &bytes[..bytes.len() - 3]),
+        Err(GraphError::IoError(_))
+    ));
+}
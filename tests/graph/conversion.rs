@@ -0,0 +1,158 @@
+use std::collections::VecDeque;
+
+use graph_library::graph::{GraphBase, ListGraphBackend, WithID};
+use graph_library::{ListGraph, Undirected};
+use rstest::rstest;
+use rustc_hash::FxHashMap;
+
+#[rstest]
+#[case("resources/test_graphs/undirected/Graph1.txt", 0)]
+#[case("resources/test_graphs/undirected/Graph2.txt", 0)]
+fn to_weighted_unit_weights_match_bfs_hop_counts(#[case] input_path: &str, #[case] start: u32) {
+    let graph = ListGraph::<_, _, Undirected>::from_hoever_file_default(input_path)
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let weighted_graph = graph
+        .to_weighted::<_, ListGraphBackend<_, _, Undirected>>(|_, _| 1.0)
+        .unwrap_or_else(|e| panic!("Could not assign weights: {:?}", e));
+    assert_eq!(weighted_graph.vertex_count(), graph.vertex_count());
+    assert_eq!(weighted_graph.edge_count(), graph.edge_count());
+
+    // Compute the hop counts with a plain BFS
+    let mut hops = FxHashMap::default();
+    hops.insert(start, 0_usize);
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        for neighbor in graph.get_adjacent_vertices(current) {
+            if !hops.contains_key(&neighbor.get_id()) {
+                hops.insert(neighbor.get_id(), hops[&current] + 1);
+                queue.push_back(neighbor.get_id());
+            }
+        }
+    }
+
+    let shortest_paths = weighted_graph.dijkstra(start, None);
+    for vertex in graph.get_all_vertices() {
+        let expected = hops.get(&vertex.get_id()).map(|hops| *hops as f64);
+        assert_eq!(
+            shortest_paths.get_cost(vertex.get_id()),
+            expected,
+            "For graph {}, the cost to vertex {} does not match the hop count",
+            input_path,
+            vertex.get_id()
+        );
+    }
+}
+
+#[test]
+fn compact_ids_makes_sparse_graph_loadable_into_matrix() {
+    use graph_library::graph::{EdgeWithWeight, MatrixGraphBackend, Vertex};
+    use graph_library::Directed;
+
+    use crate::algorithms::TestVertex;
+
+    let graph = ListGraph::<_, _, Directed>::from_vertices_and_edges(
+        vec![Vertex { id: 20 }, Vertex { id: 10 }, Vertex { id: 30 }],
+        vec![
+            (10, 20, EdgeWithWeight::new(1.5)),
+            (20, 30, EdgeWithWeight::new(2.5)),
+            (30, 10, EdgeWithWeight::new(3.5)),
+        ],
+    )
+    .unwrap();
+
+    let compacted = graph
+        .compact_ids::<_, MatrixGraphBackend<_, _, Directed>>(|idx, _| TestVertex(idx))
+        .unwrap_or_else(|e| panic!("Could not compact IDs: {:?}", e));
+
+    let mut vertex_ids = compacted
+        .get_all_vertices()
+        .map(|v| v.get_id())
+        .collect::<Vec<_>>();
+    vertex_ids.sort();
+    assert_eq!(vertex_ids, vec![0, 1, 2]);
+    for id in 0..3 {
+        assert_eq!(compacted.get_vertex_by_id(id), Some(&TestVertex(id)));
+    }
+
+    let mut edges = compacted
+        .get_all_edges()
+        .map(|(from, to, edge)| (from, to, edge.weight))
+        .collect::<Vec<_>>();
+    edges.sort_by_key(|(from, to, _)| (*from, *to));
+    assert_eq!(edges, vec![(0, 1, 1.5), (1, 2, 2.5), (2, 0, 3.5)]);
+}
+
+#[test]
+fn relabel_vertices_rejects_duplicate_ids() {
+    use graph_library::graph::Vertex;
+    use graph_library::GraphError;
+
+    let graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+        vec![Vertex { id: 1 }, Vertex { id: 2 }],
+        vec![(1, 2, ())],
+    )
+    .unwrap();
+
+    let result =
+        graph.relabel_vertices::<_, ListGraphBackend<_, _, Undirected>>(|_| Vertex { id: 0 });
+    assert!(matches!(result, Err(GraphError::DuplicateVertex(0))));
+}
+
+#[rstest]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10.txt")]
+#[case("resources/test_graphs/complete_undirected_weighted/K_10e.txt")]
+fn to_matrix_and_back_keeps_tsp_cost(#[case] input_path: &str) {
+    use graph_library::graph::MatrixGraphBackend;
+
+    use crate::algorithms::{TestEdge, TestVertex};
+
+    let graph =
+        ListGraph::<_, _, Undirected>::from_hoever_file(input_path, TestVertex, |remaining| {
+            TestEdge(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        })
+        .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let matrix_graph = graph
+        .to_matrix::<MatrixGraphBackend<_, _, Undirected>>()
+        .unwrap_or_else(|e| panic!("Could not convert to a matrix: {:?}", e));
+    let list_graph = matrix_graph
+        .to_list::<ListGraphBackend<_, _, Undirected>>()
+        .unwrap_or_else(|e| panic!("Could not convert to a list: {:?}", e));
+    assert_eq!(matrix_graph.vertex_count(), graph.vertex_count());
+    assert_eq!(matrix_graph.edge_count(), graph.edge_count());
+    assert_eq!(list_graph.edge_count(), graph.edge_count());
+
+    let expected_cost = graph.tsp_branch_and_bound(Some(0)).unwrap().total_cost();
+    let matrix_cost = matrix_graph
+        .tsp_branch_and_bound(Some(0))
+        .unwrap()
+        .total_cost();
+    let list_cost = list_graph
+        .tsp_branch_and_bound(Some(0))
+        .unwrap()
+        .total_cost();
+    assert!((matrix_cost - expected_cost).abs() < 1e-9);
+    assert!((list_cost - expected_cost).abs() < 1e-9);
+}
+
+#[test]
+fn to_matrix_rejects_non_sequential_ids() {
+    use graph_library::graph::MatrixGraphBackend;
+    use graph_library::GraphError;
+
+    use crate::algorithms::TestVertex;
+
+    let graph = ListGraph::<_, _, Undirected>::from_vertices_and_edges(
+        vec![TestVertex(0), TestVertex(1), TestVertex(3)],
+        vec![(0, 1, ()), (1, 3, ())],
+    )
+    .unwrap();
+
+    let result = graph.to_matrix::<MatrixGraphBackend<_, _, Undirected>>();
+    assert!(matches!(result, Err(GraphError::OperationFailed(_))));
+}
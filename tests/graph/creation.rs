@@ -41,3 +41,74 @@ fn create_from_file_with_weights_creates_all_vertices(
     let vertices = graph.get_all_vertices().collect::<Vec<_>>();
     assert_eq!(vertices.len(), expected_vertices);
 }
+
+#[test]
+fn create_from_missing_file_returns_readable_io_error() {
+    use graph_library::GraphError;
+
+    let path = "resources/test_graphs/does_not_exist.txt";
+    let error = ListGraph::<_, _, Undirected>::from_hoever_file_default(path)
+        .expect_err("Loading a missing file must fail");
+
+    assert!(
+        matches!(&error, GraphError::IoError(e) if e.kind() == std::io::ErrorKind::NotFound),
+        "Expected a not found I/O error, but got {:?}",
+        error
+    );
+    let message = error.to_string();
+    assert!(message.starts_with("I/O error: "), "{message}");
+    assert!(message.contains(path), "{message}");
+}
+
+#[test]
+fn create_matrix_from_file_with_isolated_high_id_vertices() {
+    use crate::algorithms::TestVertex;
+    use graph_library::{graph::MatrixGraph, Directed};
+
+    // The file declares 8 vertices, but only 0, 1 and 2 have edges
+    let path = "resources/test_graphs/undirected/Graph_isolated_high_ids.txt";
+    let mut matrix_graph =
+        MatrixGraph::<_, _, Undirected>::from_hoever_file(path, TestVertex, |_| ()).unwrap();
+    let list_graph = ListGraph::<_, _, Undirected>::from_hoever_file_default(path).unwrap();
+
+    assert_eq!(matrix_graph.vertex_count(), 8);
+    assert_eq!(matrix_graph.edge_count(), list_graph.edge_count());
+    assert_eq!(matrix_graph.get_adjacent_vertices(7).count(), 0);
+    assert!(matrix_graph.get_edge(2, 0).is_some());
+
+    // The matrix must stay square, so growing the graph afterwards still works
+    matrix_graph.push_vertex(TestVertex(8)).unwrap();
+    matrix_graph.push_edge(8, 7, ()).unwrap();
+    assert_eq!(matrix_graph.get_adjacent_vertices(7).count(), 1);
+
+    // A graph created with a preallocated size must not contain any rows before vertices are pushed
+    let mut sized_graph = MatrixGraph::<TestVertex, (), Directed>::new_with_size(4);
+    for id in 0..4 {
+        sized_graph.push_vertex(TestVertex(id)).unwrap();
+    }
+    sized_graph.push_edge(3, 0, ()).unwrap();
+    assert_eq!(sized_graph.edge_count(), 1);
+    assert_eq!(sized_graph.get_all_edges().count(), 1);
+}
+
+#[rstest]
+#[case("resources/test_graphs/undirected/Graph1.txt", 15)]
+#[case("resources/test_graphs/undirected/Graph2.txt", 1000)]
+#[case("resources/test_graphs/undirected/Graph3.txt", 1000)]
+fn bit_matrix_needs_less_memory_than_matrix(#[case] input_path: &str, #[case] n_vertices: usize) {
+    use crate::algorithms::TestVertex;
+    use graph_library::graph::{BitMatrixGraph, MatrixGraph};
+
+    let matrix_graph =
+        MatrixGraph::<_, _, Undirected>::from_hoever_file(input_path, TestVertex, |_| ()).unwrap();
+    let bit_matrix_graph =
+        BitMatrixGraph::<_, Undirected>::from_hoever_file(input_path, TestVertex, |_| ()).unwrap();
+
+    // One `Option<()>` byte per cell against one bit per cell, padded to full `u64` words per row
+    assert_eq!(matrix_graph.matrix_size_in_bytes(), n_vertices * n_vertices);
+    assert_eq!(
+        bit_matrix_graph.matrix_size_in_bytes(),
+        n_vertices * n_vertices.div_ceil(64) * 8
+    );
+    assert!(bit_matrix_graph.matrix_size_in_bytes() < matrix_graph.matrix_size_in_bytes());
+}
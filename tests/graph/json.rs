@@ -0,0 +1,70 @@
+use graph_library::{
+    graph::{EdgeWithWeight, GraphBase, JsonValue},
+    Directed, ListGraph, Undirected,
+};
+
+#[test]
+fn json_export_parses_and_round_trips_counts() {
+    let graph = ListGraph::<_, _, Undirected>::from_hoever_file_with_weights(
+        "resources/test_graphs/undirected_weighted/G_1_20.txt",
+        |remaining| {
+            EdgeWithWeight::new(
+                remaining[0]
+                    .parse()
+                    .expect("Graph file value must be a float"),
+            )
+        },
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let json = graph.to_json(
+        |v| {
+            JsonValue::Object(vec![
+                ("id".to_string(), v.id.into()),
+                ("label".to_string(), format!("Vertex \"{}\"", v.id).into()),
+            ])
+        },
+        |e| e.weight.into(),
+    );
+    let parsed: serde_json::Value =
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("Invalid JSON: {e}"));
+
+    assert_eq!(parsed["directed"], false);
+    let nodes = parsed["nodes"].as_array().unwrap();
+    let edges = parsed["edges"].as_array().unwrap();
+    assert_eq!(nodes.len(), graph.vertex_count());
+    assert_eq!(edges.len(), graph.edge_count());
+
+    assert!(nodes
+        .iter()
+        .all(|node| node["label"] == format!("Vertex \"{}\"", node["id"])));
+    for edge in edges {
+        // Fails on fractional or out-of-range IDs, instead of truncating them
+        let from = u32::try_from(edge["from"].as_u64().expect("IDs must be integers")).unwrap();
+        let to = u32::try_from(edge["to"].as_u64().expect("IDs must be integers")).unwrap();
+        let weight = graph.get_edge(from, to).unwrap().weight;
+        assert!((edge["data"].as_f64().unwrap() - weight).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn json_export_directed_without_data() {
+    let graph = ListGraph::<_, _, Directed>::from_hoever_file_default(
+        "resources/test_graphs/undirected/Graph1.txt",
+    )
+    .unwrap_or_else(|e| panic!("Graph could not be constructed from file: {:?}", e));
+
+    let json = graph.to_json(|v| v.id.into(), |_| JsonValue::Null);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed["directed"], true);
+    assert_eq!(
+        parsed["nodes"].as_array().unwrap().len(),
+        graph.vertex_count()
+    );
+    assert_eq!(
+        parsed["edges"].as_array().unwrap().len(),
+        graph.edge_count()
+    );
+    assert!(parsed["edges"][0]["data"].is_null());
+}
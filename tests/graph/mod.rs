@@ -1 +1,4 @@
+pub mod binary;
+pub mod conversion;
 pub mod creation;
+pub mod json;